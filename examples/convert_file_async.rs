@@ -12,7 +12,7 @@
 use std::time::Duration;
 
 use docling_rs::models::requests::ConvertDocumentsRequestOptions;
-use docling_rs::{DoclingClient, OutputFormat};
+use docling_rs::{DoclingClient, OutputFormat, TaskStatus};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -71,9 +71,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             );
         }
 
-        match status.task_status.as_str() {
-            "SUCCESS" => break,
-            "FAILURE" => {
+        match status.task_status {
+            TaskStatus::Success => break,
+            TaskStatus::Failure => {
                 eprintln!("Task failed!");
                 return Ok(());
             }
@@ -0,0 +1,48 @@
+//! Reading ZIP target results in memory.
+//!
+//! Requires the `zip` feature.
+
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+
+use crate::error::DoclingError;
+
+/// Read every file in a ZIP archive into memory, keyed by its path inside
+/// the archive (e.g. `doc.md`, `doc.json`).
+///
+/// Use it on the bytes returned by
+/// [`convert_file_zip`](crate::DoclingClient::convert_file_zip) or
+/// [`convert_source_zip`](crate::DoclingClient::convert_source_zip).
+/// Directory entries are skipped and an empty archive yields an empty map.
+/// If the same path appears more than once, the last entry wins. A corrupt
+/// archive returns [`DoclingError::Io`].
+///
+/// ```rust,no_run
+/// # async fn example() -> Result<(), docling_rs::DoclingError> {
+/// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+/// let archive = client.convert_file_zip(&["./report.pdf"], None).await?;
+/// let files = docling_rs::unpack_zip(&archive)?;
+/// if let Some(md) = files.get("report.md") {
+///     println!("{}", String::from_utf8_lossy(md));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn unpack_zip(bytes: &[u8]) -> Result<HashMap<String, Vec<u8>>, DoclingError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).map_err(zip_error)?;
+    let mut files = HashMap::with_capacity(archive.len());
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).map_err(zip_error)?;
+        if entry.is_dir() {
+            continue;
+        }
+        let mut contents = Vec::with_capacity(entry.size() as usize);
+        entry.read_to_end(&mut contents)?;
+        files.insert(entry.name().to_string(), contents);
+    }
+    Ok(files)
+}
+
+fn zip_error(err: zip::result::ZipError) -> DoclingError {
+    DoclingError::Io(err.into())
+}
@@ -0,0 +1,190 @@
+//! Credentials for deployments that put Docling Serve behind authentication.
+
+use std::pin::Pin;
+
+use crate::error::DoclingError;
+
+/// The future returned by [`TokenProvider::token`].
+pub type TokenFuture<'a> = Pin<Box<dyn Future<Output = Result<String, DoclingError>> + Send + 'a>>;
+
+/// A source of bearer tokens, asked for a token before every request to a
+/// secured endpoint.
+///
+/// Use it when tokens expire, e.g. with an OAuth client-credentials flow
+/// behind a gateway: the provider fetches a fresh token or returns a cached
+/// one that is still valid. The client sends the result as
+/// `Authorization: Bearer <token>` and stays `Send + Sync`, so clones can
+/// share one provider across tasks. An error from the provider is returned
+/// from the call that needed the token, without sending anything.
+///
+/// Set it with
+/// [`DoclingClientBuilder::token_provider`](crate::DoclingClientBuilder::token_provider).
+///
+/// ```rust
+/// use std::sync::Mutex;
+/// use std::time::{Duration, Instant};
+/// use docling_rs::{DoclingClient, DoclingError, TokenFuture, TokenProvider};
+///
+/// struct Gateway {
+///     cached: Mutex<Option<(String, Instant)>>,
+/// }
+///
+/// impl TokenProvider for Gateway {
+///     fn token(&self) -> TokenFuture<'_> {
+///         Box::pin(async move {
+///             if let Some((token, expires)) = &*self.cached.lock().unwrap() {
+///                 if Instant::now() < *expires {
+///                     return Ok(token.clone());
+///                 }
+///             }
+///             let token = "fresh-token".to_string(); // fetch from the gateway here
+///             let expires = Instant::now() + Duration::from_secs(14 * 60);
+///             *self.cached.lock().unwrap() = Some((token.clone(), expires));
+///             Ok(token)
+///         })
+///     }
+/// }
+///
+/// let client = DoclingClient::builder("http://127.0.0.1:5001")
+///     .token_provider(Gateway { cached: Mutex::new(None) })
+///     .build();
+/// ```
+pub trait TokenProvider: Send + Sync {
+    /// Return the token to send with the next request.
+    fn token(&self) -> TokenFuture<'_>;
+}
+
+impl std::fmt::Debug for dyn TokenProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TokenProvider")
+    }
+}
+
+/// A [`TokenProvider`] that always returns the same token.
+///
+/// Equivalent to [`DoclingClientBuilder::api_key`](crate::DoclingClientBuilder::api_key);
+/// useful where code expects a provider, e.g. in tests.
+#[derive(Clone)]
+pub struct StaticToken(String);
+
+impl StaticToken {
+    /// Wrap a fixed token.
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+}
+
+impl TokenProvider for StaticToken {
+    fn token(&self) -> TokenFuture<'_> {
+        Box::pin(async move { Ok(self.0.clone()) })
+    }
+}
+
+impl std::fmt::Debug for StaticToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("StaticToken").field(&"[REDACTED]").finish()
+    }
+}
+
+/// Static credentials sent on requests to secured endpoints.
+///
+/// Set with [`DoclingClientBuilder::auth`](crate::DoclingClientBuilder::auth)
+/// or [`DoclingClient::with_auth`](crate::DoclingClient::with_auth).
+/// [`DoclingClientBuilder::api_key`](crate::DoclingClientBuilder::api_key)
+/// is a shortcut for [`Auth::Bearer`]. `Debug` output hides the secret.
+///
+/// ```rust
+/// use docling_rs::{Auth, DoclingClient};
+///
+/// let client = DoclingClient::builder("https://docling.example.com")
+///     .auth(Auth::header("X-Api-Key", "secret"))
+///     .build();
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub enum Auth {
+    /// `Authorization: Bearer <token>`.
+    Bearer(String),
+    /// HTTP Basic auth: `Authorization: Basic <base64(user:pass)>`.
+    Basic { user: String, pass: String },
+    /// A custom header carrying the credential, e.g. `X-Api-Key: <value>`.
+    /// An invalid header name or value fails each secured call with
+    /// [`DoclingError::InvalidOptions`].
+    Header { name: String, value: String },
+}
+
+impl Auth {
+    /// Bearer token auth.
+    pub fn bearer(token: impl Into<String>) -> Self {
+        Self::Bearer(token.into())
+    }
+
+    /// HTTP Basic auth.
+    pub fn basic(user: impl Into<String>, pass: impl Into<String>) -> Self {
+        Self::Basic {
+            user: user.into(),
+            pass: pass.into(),
+        }
+    }
+
+    /// A custom header.
+    pub fn header(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::Header {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+
+    /// The secret part of the credentials, redacted from logged bodies.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn secret(&self) -> &str {
+        match self {
+            Auth::Bearer(token) => token,
+            Auth::Basic { pass, .. } => pass,
+            Auth::Header { value, .. } => value,
+        }
+    }
+
+    /// Add the credentials to a request.
+    pub(crate) fn apply(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<reqwest::RequestBuilder, DoclingError> {
+        match self {
+            Auth::Bearer(token) => Ok(req.bearer_auth(token)),
+            Auth::Basic { user, pass } => Ok(req.basic_auth(user, Some(pass))),
+            Auth::Header { name, value } => {
+                let name =
+                    reqwest::header::HeaderName::from_bytes(name.as_bytes()).map_err(|_| {
+                        DoclingError::InvalidOptions {
+                            reason: format!("invalid auth header name: {:?}", name),
+                        }
+                    })?;
+                let mut value = reqwest::header::HeaderValue::from_str(value).map_err(|_| {
+                    DoclingError::InvalidOptions {
+                        reason: format!("invalid value for auth header {}", name),
+                    }
+                })?;
+                value.set_sensitive(true);
+                Ok(req.header(name, value))
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Auth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Auth::Bearer(_) => f.debug_tuple("Bearer").field(&"[REDACTED]").finish(),
+            Auth::Basic { user, .. } => f
+                .debug_struct("Basic")
+                .field("user", user)
+                .field("pass", &"[REDACTED]")
+                .finish(),
+            Auth::Header { name, .. } => f
+                .debug_struct("Header")
+                .field("name", name)
+                .field("value", &"[REDACTED]")
+                .finish(),
+        }
+    }
+}
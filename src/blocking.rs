@@ -18,23 +18,102 @@
 //! ```
 
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 
+use tokio_util::sync::CancellationToken;
+
 use crate::error::DoclingError;
 use crate::models::enums::TargetName;
-use crate::models::requests::{ConvertDocumentsRequest, ConvertDocumentsRequestOptions};
-use crate::models::responses::{ConvertDocumentResponse, HealthCheckResponse, TaskStatusResponse};
+use crate::models::requests::{
+    ChunkDocumentsRequest, ChunkingOptions, ConvertDocumentsRequest,
+    ConvertDocumentsRequestOptions, Input, S3Target, Source, UploadMode,
+};
+use crate::models::responses::{
+    AdaptiveOutcome, ChunkDocumentResponse, ConvertDocumentResponse, ConvertDocumentsResponse,
+    HealthCheckResponse, PresignedUrlConvertDocumentResponse, ResultRange, TaskStatusResponse,
+    VersionResponse,
+};
+use crate::task::RestartOutcome;
+use crate::wait::{WaitConfig, WaitStats};
 
 /// Synchronous HTTP client for Docling Serve.
 ///
 /// This is a blocking wrapper around the async [`crate::client::DoclingClient`].
-/// It uses an internal Tokio runtime to execute async operations synchronously.
+/// It uses an internal Tokio runtime to execute async operations synchronously,
+/// or an existing one passed to [`from_handle`](Self::from_handle).
+///
+/// The client also works when called from inside an async context, e.g. a
+/// `#[tokio::main]` application that still has some synchronous code: each
+/// call then runs on a short-lived helper thread instead of panicking with
+/// "Cannot start a runtime from within a runtime". The calling thread is
+/// still blocked until the call returns, so prefer the async client in
+/// async code.
+///
+/// Cloning is cheap: clones share the runtime, connection pool, and
+/// settings, so one client can be handed to several threads without
+/// starting another thread pool for each.
+#[derive(Clone)]
 pub struct DoclingClient {
-    runtime: tokio::runtime::Runtime,
+    runtime: Arc<BlockingRuntime>,
     inner: crate::client::DoclingClient,
 }
 
+/// The runtime a blocking client drives its futures on.
+struct BlockingRuntime {
+    /// Owned runtime, `None` when built from a caller's handle.
+    owned: Option<tokio::runtime::Runtime>,
+    handle: tokio::runtime::Handle,
+}
+
+impl BlockingRuntime {
+    fn new() -> Self {
+        let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+        Self {
+            handle: runtime.handle().clone(),
+            owned: Some(runtime),
+        }
+    }
+
+    fn from_handle(handle: tokio::runtime::Handle) -> Self {
+        Self {
+            owned: None,
+            handle,
+        }
+    }
+
+    /// Run `future` to completion. Blocking on a runtime from inside an
+    /// async context panics, so there the future runs on a scoped helper
+    /// thread instead.
+    fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future + Send,
+        F::Output: Send,
+    {
+        if tokio::runtime::Handle::try_current().is_err() {
+            return self.handle.block_on(future);
+        }
+        std::thread::scope(|scope| {
+            scope
+                .spawn(|| self.handle.block_on(future))
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        })
+    }
+}
+
+impl Drop for BlockingRuntime {
+    fn drop(&mut self) {
+        // Dropping a runtime inside an async context panics as well.
+        if let Some(runtime) = self.owned.take() {
+            if tokio::runtime::Handle::try_current().is_ok() {
+                runtime.shutdown_background();
+            }
+        }
+    }
+}
+
 impl DoclingClient {
     /// Create a new blocking client pointing at the given Docling Serve base URL.
     ///
@@ -44,7 +123,7 @@ impl DoclingClient {
     /// let client = DoclingClient::new("http://127.0.0.1:5001");
     /// ```
     pub fn new(base_url: impl Into<String>) -> Self {
-        let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+        let runtime = Arc::new(BlockingRuntime::new());
         let inner = crate::client::DoclingClient::new(base_url);
         Self { runtime, inner }
     }
@@ -54,11 +133,63 @@ impl DoclingClient {
     /// The key is sent as `Authorization: Bearer <key>` on every request
     /// to secured endpoints.
     pub fn with_api_key(base_url: impl Into<String>, api_key: impl Into<String>) -> Self {
-        let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+        let runtime = Arc::new(BlockingRuntime::new());
         let inner = crate::client::DoclingClient::with_api_key(base_url, api_key);
         Self { runtime, inner }
     }
 
+    /// Create a new blocking client that sends the given credentials, e.g.
+    /// HTTP Basic auth, to secured endpoints.
+    ///
+    /// See [`crate::client::DoclingClient::with_auth`].
+    pub fn with_auth(base_url: impl Into<String>, auth: crate::auth::Auth) -> Self {
+        let runtime = Arc::new(BlockingRuntime::new());
+        let inner = crate::client::DoclingClient::with_auth(base_url, auth);
+        Self { runtime, inner }
+    }
+
+    /// Create a new blocking client that sends requests through an existing
+    /// [`reqwest::Client`].
+    pub fn with_http_client(base_url: impl Into<String>, http: reqwest::Client) -> Self {
+        let runtime = Arc::new(BlockingRuntime::new());
+        let inner = crate::client::DoclingClient::with_http_client(base_url, http);
+        Self { runtime, inner }
+    }
+
+    /// Create a new blocking client with API key authentication that sends
+    /// requests through an existing [`reqwest::Client`].
+    pub fn with_http_client_and_key(
+        base_url: impl Into<String>,
+        http: reqwest::Client,
+        api_key: impl Into<String>,
+    ) -> Self {
+        let runtime = Arc::new(BlockingRuntime::new());
+        let inner = crate::client::DoclingClient::with_http_client_and_key(base_url, http, api_key);
+        Self { runtime, inner }
+    }
+
+    /// Create a new blocking client that runs its requests on an existing
+    /// Tokio runtime instead of starting its own.
+    ///
+    /// `handle` must belong to a multi-threaded runtime: a current-thread
+    /// runtime only makes progress while its own thread drives it, so
+    /// blocking that thread on a call would deadlock.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use docling_rs::blocking::DoclingClient;
+    ///
+    /// let runtime = tokio::runtime::Runtime::new()?;
+    /// let client = DoclingClient::from_handle(runtime.handle().clone(), "http://127.0.0.1:5001");
+    /// let health = client.health()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_handle(handle: tokio::runtime::Handle, base_url: impl Into<String>) -> Self {
+        let runtime = Arc::new(BlockingRuntime::from_handle(handle));
+        let inner = crate::client::DoclingClient::new(base_url);
+        Self { runtime, inner }
+    }
+
     /// Check if the Docling Serve instance is healthy.
     ///
     /// `GET /health`
@@ -66,6 +197,18 @@ impl DoclingClient {
         self.runtime.block_on(self.inner.health())
     }
 
+    /// Poll `/health` until the server reports `ok` or `timeout` elapses.
+    ///
+    /// See [`crate::client::DoclingClient::wait_until_healthy`].
+    pub fn wait_until_healthy(
+        &self,
+        timeout: Duration,
+        interval: Duration,
+    ) -> Result<(), DoclingError> {
+        self.runtime
+            .block_on(self.inner.wait_until_healthy(timeout, interval))
+    }
+
     /// Get version information from the Docling Serve instance.
     ///
     /// `GET /version`
@@ -73,6 +216,13 @@ impl DoclingClient {
         self.runtime.block_on(self.inner.version())
     }
 
+    /// Get version information as a typed [`VersionResponse`].
+    ///
+    /// `GET /version`
+    pub fn version_typed(&self) -> Result<VersionResponse, DoclingError> {
+        self.runtime.block_on(self.inner.version_typed())
+    }
+
     /// Convert a document from a URL (synchronous).
     ///
     /// `POST /v1/convert/source`
@@ -89,6 +239,99 @@ impl DoclingClient {
             .block_on(self.inner.convert_source(url, options))
     }
 
+    /// Convert a document from a URL and return only its Markdown.
+    ///
+    /// See [`crate::client::DoclingClient::to_markdown`].
+    pub fn to_markdown(&self, url: &str) -> Result<String, DoclingError> {
+        self.runtime.block_on(self.inner.to_markdown(url))
+    }
+
+    /// Convert a document from a URL and return only its plain text.
+    pub fn to_text(&self, url: &str) -> Result<String, DoclingError> {
+        self.runtime.block_on(self.inner.to_text(url))
+    }
+
+    /// Convert a document from a URL and return only its HTML.
+    pub fn to_html(&self, url: &str) -> Result<String, DoclingError> {
+        self.runtime.block_on(self.inner.to_html(url))
+    }
+
+    /// Convert a document from a URL and return only its `DoclingDocument`
+    /// JSON.
+    pub fn to_json(&self, url: &str) -> Result<serde_json::Value, DoclingError> {
+        self.runtime.block_on(self.inner.to_json(url))
+    }
+
+    /// Convert a document from a URL and return the unparsed JSON response.
+    ///
+    /// `POST /v1/convert/source`
+    ///
+    /// Escape hatch for fields the typed [`ConvertDocumentResponse`] does not
+    /// model yet.
+    pub fn convert_source_raw(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+    ) -> Result<serde_json::Value, DoclingError> {
+        self.runtime
+            .block_on(self.inner.convert_source_raw(url, options))
+    }
+
+    /// Convert a document from a URL and also return the response headers.
+    ///
+    /// See [`crate::client::DoclingClient::convert_source_with_headers`].
+    pub fn convert_source_with_headers(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+    ) -> Result<(ConvertDocumentResponse, reqwest::header::HeaderMap), DoclingError> {
+        self.runtime
+            .block_on(self.inner.convert_source_with_headers(url, options))
+    }
+
+    /// Convert a document from a URL and write its Markdown to `writer` as
+    /// the response arrives.
+    ///
+    /// See [`crate::client::DoclingClient::convert_source_to_writer`].
+    pub fn convert_source_to_writer(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+        writer: impl std::io::Write + Send,
+    ) -> Result<u64, DoclingError> {
+        self.runtime
+            .block_on(self.inner.convert_source_to_writer(url, options, writer))
+    }
+
+    /// Convert a document from a URL, reusing an earlier result for an
+    /// identical request.
+    ///
+    /// See [`crate::client::DoclingClient::convert_source_memoized`] for the
+    /// cache key and eviction policy. Requires the `cache` feature.
+    #[cfg(feature = "cache")]
+    pub fn convert_source_memoized(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        self.runtime
+            .block_on(self.inner.convert_source_memoized(url, options))
+    }
+
+    /// Convert a list of URLs with bounded concurrency, returning results in
+    /// input order.
+    ///
+    /// See [`crate::client::DoclingClient::convert_many`].
+    pub fn convert_many(
+        &self,
+        urls: &[String],
+        options: Option<ConvertDocumentsRequestOptions>,
+        max_concurrency: usize,
+    ) -> Vec<Result<ConvertDocumentResponse, DoclingError>> {
+        self.runtime
+            .block_on(self.inner.convert_many(urls, options, max_concurrency))
+    }
+
     /// Convert documents from multiple sources (synchronous).
     ///
     /// `POST /v1/convert/source`
@@ -101,6 +344,100 @@ impl DoclingClient {
         self.runtime.block_on(self.inner.convert(request))
     }
 
+    /// Convert documents from multiple sources and return a result for each.
+    ///
+    /// See [`crate::client::DoclingClient::convert_documents`].
+    pub fn convert_documents(
+        &self,
+        request: &ConvertDocumentsRequest,
+    ) -> Result<ConvertDocumentsResponse, DoclingError> {
+        self.runtime.block_on(self.inner.convert_documents(request))
+    }
+
+    /// The JSON body [`convert`](Self::convert) would post for `request`,
+    /// without sending anything.
+    ///
+    /// See [`crate::client::DoclingClient::build_convert_body`].
+    pub fn build_convert_body(
+        &self,
+        request: &ConvertDocumentsRequest,
+    ) -> Result<serde_json::Value, DoclingError> {
+        self.inner.build_convert_body(request)
+    }
+
+    /// The text fields [`convert_file`](Self::convert_file) would send
+    /// alongside the uploaded files.
+    ///
+    /// See [`crate::client::DoclingClient::build_file_form_fields`].
+    pub fn build_file_form_fields(
+        &self,
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<Vec<(String, String)>, DoclingError> {
+        self.inner.build_file_form_fields(options, target_type)
+    }
+
+    /// Convert documents from several URLs in one request.
+    ///
+    /// See [`crate::client::DoclingClient::convert_sources`] for the
+    /// single-document limitation.
+    pub fn convert_sources(
+        &self,
+        urls: &[&str],
+        options: Option<ConvertDocumentsRequestOptions>,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        self.runtime
+            .block_on(self.inner.convert_sources(urls, options))
+    }
+
+    /// Convert documents and return the results as a ZIP archive.
+    ///
+    /// See [`crate::client::DoclingClient::convert_zip`].
+    pub fn convert_zip(&self, request: &ConvertDocumentsRequest) -> Result<Vec<u8>, DoclingError> {
+        self.runtime.block_on(self.inner.convert_zip(request))
+    }
+
+    /// Convert a document from a URL and return the results as a ZIP archive.
+    ///
+    /// See [`crate::client::DoclingClient::convert_source_zip`].
+    pub fn convert_source_zip(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+    ) -> Result<Vec<u8>, DoclingError> {
+        self.runtime
+            .block_on(self.inner.convert_source_zip(url, options))
+    }
+
+    /// Convert documents and upload the results to an S3-compatible bucket.
+    ///
+    /// See [`crate::client::DoclingClient::convert_to_s3`].
+    pub fn convert_to_s3(
+        &self,
+        request: &ConvertDocumentsRequest,
+        target: S3Target,
+    ) -> Result<PresignedUrlConvertDocumentResponse, DoclingError> {
+        self.runtime
+            .block_on(self.inner.convert_to_s3(request, target))
+    }
+
+    /// Convert a document synchronously, falling back to async if it is slow.
+    ///
+    /// Returns [`AdaptiveOutcome::Completed`] if the conversion finishes within
+    /// `sync_budget`, otherwise submits it asynchronously and returns
+    /// [`AdaptiveOutcome::Pending`] with the task to poll.
+    pub fn convert_source_adaptive(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+        sync_budget: Duration,
+    ) -> Result<AdaptiveOutcome, DoclingError> {
+        self.runtime.block_on(
+            self.inner
+                .convert_source_adaptive(url, options, sync_budget),
+        )
+    }
+
     /// Submit a document for asynchronous conversion.
     ///
     /// Returns a `TaskStatusResponse` containing the `task_id` which can be
@@ -150,10 +487,62 @@ impl DoclingClient {
     ///
     /// This should only be called after `poll_task_status` indicates the
     /// task has completed (status = "SUCCESS").
+    ///
+    /// Results are all-or-nothing: Docling Serve does not expose per-document
+    /// partial results while a multi-source task is still running, so there
+    /// is no way to fetch the documents that finished early. Split large
+    /// batches into several tasks to process completed documents sooner.
     pub fn get_task_result(&self, task_id: &str) -> Result<ConvertDocumentResponse, DoclingError> {
         self.runtime.block_on(self.inner.get_task_result(task_id))
     }
 
+    /// Write the Markdown of a completed conversion task to `writer` as the
+    /// result downloads.
+    ///
+    /// See [`crate::client::DoclingClient::get_task_result_to_writer`].
+    pub fn get_task_result_to_writer(
+        &self,
+        task_id: &str,
+        writer: impl std::io::Write + Send,
+    ) -> Result<u64, DoclingError> {
+        self.runtime
+            .block_on(self.inner.get_task_result_to_writer(task_id, writer))
+    }
+
+    /// Download part of a task's raw result body.
+    ///
+    /// `GET /v1/result/{task_id}` with a `Range` header. See
+    /// [`crate::client::DoclingClient::get_task_result_range`].
+    pub fn get_task_result_range(
+        &self,
+        task_id: &str,
+        range: std::ops::Range<u64>,
+    ) -> Result<ResultRange, DoclingError> {
+        self.runtime
+            .block_on(self.inner.get_task_result_range(task_id, range))
+    }
+
+    /// Ask the server to cancel an async task.
+    ///
+    /// Not part of Docling Serve's API; see
+    /// [`crate::client::DoclingClient::cancel_task`].
+    pub fn cancel_task(&self, task_id: &str) -> Result<(), DoclingError> {
+        self.runtime.block_on(self.inner.cancel_task(task_id))
+    }
+
+    /// Cancel a stuck task and submit the same request again.
+    ///
+    /// Depends on [`cancel_task`](Self::cancel_task); a `404`/`405` from the
+    /// cancel call is reported in [`RestartOutcome::old_cancelled`].
+    pub fn restart_task(
+        &self,
+        task_id: &str,
+        original_request: &ConvertDocumentsRequest,
+    ) -> Result<RestartOutcome, DoclingError> {
+        self.runtime
+            .block_on(self.inner.restart_task(task_id, original_request))
+    }
+
     /// Submit an async conversion and wait for it to complete.
     ///
     /// This is a convenience method that combines `convert_source_async`,
@@ -180,6 +569,56 @@ impl DoclingClient {
         ))
     }
 
+    /// Like [`wait_for_conversion`](Self::wait_for_conversion), but stops
+    /// waiting with [`DoclingError::Cancelled`] once `cancel` is triggered,
+    /// e.g. from another thread. The task keeps running on the server.
+    ///
+    /// See [`crate::client::DoclingClient::wait_for_conversion_cancellable`].
+    pub fn wait_for_conversion_cancellable(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+        timeout: Duration,
+        poll_interval_secs: Option<f64>,
+        cancel: CancellationToken,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        self.runtime
+            .block_on(self.inner.wait_for_conversion_cancellable(
+                url,
+                options,
+                timeout,
+                poll_interval_secs,
+                cancel,
+            ))
+    }
+
+    /// Submit an async conversion and wait for it using a [`WaitConfig`].
+    pub fn wait_for_conversion_with(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+        config: &WaitConfig,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        self.runtime
+            .block_on(self.inner.wait_for_conversion_with(url, options, config))
+    }
+
+    /// Like [`wait_for_conversion_with`](Self::wait_for_conversion_with), also
+    /// returning [`WaitStats`].
+    ///
+    /// See [`crate::client::DoclingClient::wait_for_conversion_detailed`].
+    pub fn wait_for_conversion_detailed(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+        config: &WaitConfig,
+    ) -> Result<(ConvertDocumentResponse, WaitStats), DoclingError> {
+        self.runtime.block_on(
+            self.inner
+                .wait_for_conversion_detailed(url, options, config),
+        )
+    }
+
     /// Convert one or more local files (synchronous).
     ///
     /// Reads each file from disk and uploads via `multipart/form-data`.
@@ -197,8 +636,74 @@ impl DoclingClient {
         options: Option<&ConvertDocumentsRequestOptions>,
         target_type: Option<&TargetName>,
     ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let file_paths = owned_paths(file_paths);
         self.runtime
-            .block_on(self.inner.convert_file(file_paths, options, target_type))
+            .block_on(self.inner.convert_file(&file_paths, options, target_type))
+    }
+
+    /// Convert local files and also return the response headers.
+    ///
+    /// See [`crate::client::DoclingClient::convert_file_with_headers`].
+    pub fn convert_file_with_headers(
+        &self,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<(ConvertDocumentResponse, reqwest::header::HeaderMap), DoclingError> {
+        let file_paths = owned_paths(file_paths);
+        self.runtime.block_on(self.inner.convert_file_with_headers(
+            &file_paths,
+            options,
+            target_type,
+        ))
+    }
+
+    /// Convert local files with an explicit MIME type for each.
+    ///
+    /// See [`crate::client::DoclingClient::convert_file_with_mimes`].
+    pub fn convert_file_with_mimes(
+        &self,
+        files: &[(PathBuf, &str)],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        self.runtime.block_on(
+            self.inner
+                .convert_file_with_mimes(files, options, target_type),
+        )
+    }
+
+    /// Convert local files and return the results as a ZIP archive.
+    ///
+    /// See [`crate::client::DoclingClient::convert_file_zip`].
+    pub fn convert_file_zip(
+        &self,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+    ) -> Result<Vec<u8>, DoclingError> {
+        let file_paths = owned_paths(file_paths);
+        self.runtime
+            .block_on(self.inner.convert_file_zip(&file_paths, options))
+    }
+
+    /// Convert local files, reporting upload progress as
+    /// `on_upload_progress(sent, total)`.
+    ///
+    /// See [`crate::client::DoclingClient::convert_file_with_progress`].
+    pub fn convert_file_with_progress(
+        &self,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+        on_upload_progress: impl FnMut(u64, Option<u64>) + Send + 'static,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let file_paths = owned_paths(file_paths);
+        self.runtime.block_on(self.inner.convert_file_with_progress(
+            &file_paths,
+            options,
+            target_type,
+            on_upload_progress,
+        ))
     }
 
     /// Submit one or more local files for asynchronous conversion.
@@ -217,13 +722,71 @@ impl DoclingClient {
         file_paths: &[impl AsRef<Path>],
         options: Option<&ConvertDocumentsRequestOptions>,
         target_type: Option<&TargetName>,
+    ) -> Result<TaskStatusResponse, DoclingError> {
+        let file_paths = owned_paths(file_paths);
+        self.runtime.block_on(
+            self.inner
+                .convert_file_async(&file_paths, options, target_type),
+        )
+    }
+
+    /// Convert a document held in memory (synchronous).
+    ///
+    /// See [`crate::client::DoclingClient::convert_bytes`].
+    pub fn convert_bytes(
+        &self,
+        filename: &str,
+        bytes: Vec<u8>,
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        self.runtime.block_on(
+            self.inner
+                .convert_bytes(filename, bytes, options, target_type),
+        )
+    }
+
+    /// Submit a document held in memory for asynchronous conversion.
+    ///
+    /// See [`crate::client::DoclingClient::convert_bytes_async`].
+    pub fn convert_bytes_async(
+        &self,
+        filename: &str,
+        bytes: Vec<u8>,
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
     ) -> Result<TaskStatusResponse, DoclingError> {
         self.runtime.block_on(
             self.inner
-                .convert_file_async(file_paths, options, target_type),
+                .convert_bytes_async(filename, bytes, options, target_type),
         )
     }
 
+    /// Convert any mix of URLs, local files, and in-memory documents.
+    ///
+    /// See [`crate::client::DoclingClient::convert_input`].
+    pub fn convert_input(
+        &self,
+        inputs: Vec<Input>,
+        options: Option<ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        self.runtime
+            .block_on(self.inner.convert_input(inputs, options, target_type))
+    }
+
+    /// Recommend inline (base64 JSON) or multipart upload for a local file.
+    ///
+    /// See [`crate::client::DoclingClient::recommended_upload_mode`].
+    pub fn recommended_upload_mode(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<UploadMode, DoclingError> {
+        let path = path.as_ref().to_path_buf();
+        self.runtime
+            .block_on(self.inner.recommended_upload_mode(path))
+    }
+
     /// Submit local files for async conversion and wait for completion.
     ///
     /// Convenience method that combines `convert_file_async`, polling via
@@ -244,14 +807,106 @@ impl DoclingClient {
         timeout: Duration,
         poll_interval_secs: Option<f64>,
     ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let file_paths = owned_paths(file_paths);
         self.runtime.block_on(self.inner.wait_for_file_conversion(
-            file_paths,
+            &file_paths,
             options,
             target_type,
             timeout,
             poll_interval_secs,
         ))
     }
+
+    /// Submit local files for async conversion and wait using a [`WaitConfig`].
+    pub fn wait_for_file_conversion_with(
+        &self,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+        config: &WaitConfig,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let file_paths = owned_paths(file_paths);
+        self.runtime
+            .block_on(self.inner.wait_for_file_conversion_with(
+                &file_paths,
+                options,
+                target_type,
+                config,
+            ))
+    }
+
+    /// Convert a document and split it into chunks with the hybrid chunker.
+    ///
+    /// See [`crate::client::DoclingClient::chunk_hybrid`].
+    pub fn chunk_hybrid(
+        &self,
+        source: Source,
+        options: ChunkingOptions,
+    ) -> Result<ChunkDocumentResponse, DoclingError> {
+        self.runtime
+            .block_on(self.inner.chunk_hybrid(source, options))
+    }
+
+    /// Submit a document for asynchronous hybrid chunking.
+    ///
+    /// See [`crate::client::DoclingClient::chunk_hybrid_async`].
+    pub fn chunk_hybrid_async(
+        &self,
+        source: Source,
+        options: ChunkingOptions,
+    ) -> Result<TaskStatusResponse, DoclingError> {
+        self.runtime
+            .block_on(self.inner.chunk_hybrid_async(source, options))
+    }
+
+    /// Convert and chunk documents from a full request.
+    ///
+    /// See [`crate::client::DoclingClient::chunk`].
+    pub fn chunk(
+        &self,
+        request: &ChunkDocumentsRequest,
+    ) -> Result<ChunkDocumentResponse, DoclingError> {
+        self.runtime.block_on(self.inner.chunk(request))
+    }
+
+    /// Submit a full chunking request for asynchronous processing.
+    ///
+    /// See [`crate::client::DoclingClient::chunk_async`].
+    pub fn chunk_async(
+        &self,
+        request: &ChunkDocumentsRequest,
+    ) -> Result<TaskStatusResponse, DoclingError> {
+        self.runtime.block_on(self.inner.chunk_async(request))
+    }
+
+    /// Retrieve the result of a completed chunking task.
+    ///
+    /// See [`crate::client::DoclingClient::get_chunk_result`].
+    pub fn get_chunk_result(&self, task_id: &str) -> Result<ChunkDocumentResponse, DoclingError> {
+        self.runtime.block_on(self.inner.get_chunk_result(task_id))
+    }
+
+    /// Submit a document for hybrid chunking and wait for the chunks.
+    ///
+    /// See [`crate::client::DoclingClient::wait_for_chunking`].
+    pub fn wait_for_chunking(
+        &self,
+        source: Source,
+        options: ChunkingOptions,
+        config: &WaitConfig,
+    ) -> Result<ChunkDocumentResponse, DoclingError> {
+        self.runtime
+            .block_on(self.inner.wait_for_chunking(source, options, config))
+    }
+}
+
+/// Copy caller paths into owned `PathBuf`s, so the futures built from them
+/// are `Send` for [`BlockingRuntime::block_on`] whatever the path type is.
+fn owned_paths(paths: &[impl AsRef<Path>]) -> Vec<PathBuf> {
+    paths
+        .iter()
+        .map(|path| path.as_ref().to_path_buf())
+        .collect()
 }
 
 #[cfg(test)]
@@ -262,13 +917,11 @@ mod tests {
     fn blocking_client_new() {
         let _client = DoclingClient::new("http://localhost:5001");
         // Just verify it creates without panicking
-        assert!(true);
     }
 
     #[test]
     fn blocking_client_with_api_key() {
         let _client = DoclingClient::with_api_key("http://localhost:5001", "test-key");
         // Just verify it creates without panicking
-        assert!(true);
     }
 }
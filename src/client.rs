@@ -1,34 +1,99 @@
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use futures::stream::{self, Stream, StreamExt};
 use reqwest::multipart::{Form, Part};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio_util::io::ReaderStream;
+use tokio_util::sync::CancellationToken;
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
 
+use crate::auth::{Auth, TokenProvider};
 use crate::error::DoclingError;
+use crate::extract::StringFieldExtractor;
 use crate::models::*;
+use crate::retry::{RetryPolicy, is_retryable_status};
+use crate::sniff::{SNIFF_BYTES, content_mime};
+use crate::task::{RestartOutcome, TaskHandle};
+use crate::wait::{WaitConfig, WaitStats};
+
+/// Maximum number of body bytes included in a single debug log event.
+#[cfg(feature = "tracing")]
+const MAX_LOGGED_BODY_BYTES: usize = 4096;
+
+/// Shortest time between the starts of two status polls in the `wait_for_*`
+/// methods and `watch_task`, so a poll that returns immediately does not
+/// cause a tight loop.
+const MIN_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// `User-Agent` sent by the client's own `reqwest` client, so server logs can
+/// tell SDK traffic and versions apart.
+const DEFAULT_USER_AGENT: &str = concat!("docling-rs/", env!("CARGO_PKG_VERSION"));
+
+/// Default largest base64 payload recommended for inline upload (10 MiB).
+const DEFAULT_INLINE_UPLOAD_THRESHOLD: u64 = 10 * 1024 * 1024;
+
+/// Extra time allowed on top of a long-poll's `wait` before the HTTP request
+/// times out.
+const LONG_POLL_TIMEOUT_SLACK: Duration = Duration::from_secs(10);
+
+/// Default number of entries kept by the memoization cache.
+#[cfg(feature = "cache")]
+const DEFAULT_CACHE_CAPACITY: usize = 64;
+
+/// In-client LRU cache of conversion results, keyed by the serialized
+/// request.
+#[cfg(feature = "cache")]
+type ResponseCache = std::sync::Mutex<lru::LruCache<String, ConvertDocumentResponse>>;
+
+#[cfg(feature = "cache")]
+fn new_response_cache(capacity: usize) -> ResponseCache {
+    let capacity = std::num::NonZeroUsize::new(capacity).unwrap_or(std::num::NonZeroUsize::MIN);
+    std::sync::Mutex::new(lru::LruCache::new(capacity))
+}
 
 /// Async HTTP client for Docling Serve.
+///
+/// Cloning is cheap: clones share the connection pool, the concurrency limit
+/// set with [`DoclingClientBuilder::max_concurrent_requests`], and the
+/// memoization cache.
+#[derive(Clone)]
 pub struct DoclingClient {
     base_url: String,
-    api_key: Option<String>,
+    credentials: Option<Auth>,
+    token_provider: Option<Arc<dyn TokenProvider>>,
     http: reqwest::Client,
+    log_bodies: bool,
+    compress_requests: bool,
+    inline_upload_threshold: u64,
+    limiter: Option<Arc<tokio::sync::Semaphore>>,
+    request_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    default_headers: reqwest::header::HeaderMap,
+    retry_policy: Option<RetryPolicy>,
+    #[cfg(feature = "cache")]
+    cache: Arc<ResponseCache>,
 }
 
 impl DoclingClient {
     /// Create a new client pointing at the given Docling Serve base URL.
     ///
+    /// The base URL may include a path prefix, e.g.
+    /// `https://host/docling/` behind a path-routing reverse proxy; endpoint
+    /// paths are appended to it.
+    ///
     /// ```rust
     /// use docling_rs::DoclingClient;
     /// let client = DoclingClient::new("http://127.0.0.1:5001");
     /// ```
     pub fn new(base_url: impl Into<String>) -> Self {
-        let base_url = base_url.into();
-        let base_url = base_url.trim_end_matches('/').to_string();
-        Self {
-            base_url,
-            api_key: None,
-            http: reqwest::Client::new(),
-        }
+        Self::builder(base_url).build()
     }
 
     /// Create a new client with API key authentication.
@@ -36,34 +101,247 @@ impl DoclingClient {
     /// The key is sent as `Authorization: Bearer <key>` on every request
     /// to secured endpoints.
     pub fn with_api_key(base_url: impl Into<String>, api_key: impl Into<String>) -> Self {
-        let base_url = base_url.into();
-        let base_url = base_url.trim_end_matches('/').to_string();
-        Self {
-            base_url,
-            api_key: Some(api_key.into()),
-            http: reqwest::Client::new(),
-        }
+        Self::builder(base_url).api_key(api_key).build()
+    }
+
+    /// Create a new client that sends the given credentials, e.g. HTTP
+    /// Basic auth, to secured endpoints.
+    ///
+    /// ```rust
+    /// use docling_rs::{Auth, DoclingClient};
+    /// let client = DoclingClient::with_auth("http://127.0.0.1:5001", Auth::basic("user", "pass"));
+    /// ```
+    pub fn with_auth(base_url: impl Into<String>, auth: Auth) -> Self {
+        Self::builder(base_url).auth(auth).build()
+    }
+
+    /// Create a client that sends requests through an existing
+    /// [`reqwest::Client`].
+    ///
+    /// Use this to share one connection pool across a service, or to apply
+    /// timeouts, proxies, or TLS settings configured on the `reqwest` client.
+    ///
+    /// ```rust
+    /// use docling_rs::DoclingClient;
+    /// let http = reqwest::Client::builder()
+    ///     .pool_max_idle_per_host(4)
+    ///     .build()
+    ///     .unwrap();
+    /// let client = DoclingClient::with_http_client("http://127.0.0.1:5001", http);
+    /// ```
+    pub fn with_http_client(base_url: impl Into<String>, http: reqwest::Client) -> Self {
+        Self::builder(base_url).http_client(http).build()
+    }
+
+    /// Create a client with API key authentication that sends requests
+    /// through an existing [`reqwest::Client`].
+    ///
+    /// The key is applied per request, so it does not need to be configured
+    /// on the `reqwest` client.
+    pub fn with_http_client_and_key(
+        base_url: impl Into<String>,
+        http: reqwest::Client,
+        api_key: impl Into<String>,
+    ) -> Self {
+        Self::builder(base_url)
+            .http_client(http)
+            .api_key(api_key)
+            .build()
+    }
+
+    /// Start building a client with additional configuration.
+    ///
+    /// ```rust
+    /// use docling_rs::DoclingClient;
+    /// let client = DoclingClient::builder("http://127.0.0.1:5001")
+    ///     .api_key("secret")
+    ///     .log_bodies(true)
+    ///     .build();
+    /// ```
+    pub fn builder(base_url: impl Into<String>) -> DoclingClientBuilder {
+        DoclingClientBuilder::new(base_url)
     }
 
     // ========================================================================
     // Internal helpers
     // ========================================================================
 
-    /// Build a full URL from a path.
+    /// Build a full URL from a path, keeping any path prefix of the base URL.
     fn url(&self, path: &str) -> String {
         format!("{}{}", self.base_url, path)
     }
 
-    /// Apply authorization header if an API key is configured.
-    fn auth(&self, req: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
-        match &self.api_key {
-            Some(key) => req.bearer_auth(key),
+    /// Apply the authorization header: a token from the token provider if
+    /// one is set, otherwise the configured [`Auth`] credentials, if any.
+    async fn auth(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<reqwest::RequestBuilder, DoclingError> {
+        if let Some(provider) = &self.token_provider {
+            let token = provider.token().await?;
+            return Ok(req.header(reqwest::header::AUTHORIZATION, bearer_header(&token)?));
+        }
+        match &self.credentials {
+            Some(credentials) => credentials.apply(req),
+            None => Ok(req),
+        }
+    }
+
+    /// Attach a JSON body to a request, logging it first if `log_bodies` is on
+    /// and gzip-compressing it if `compress_requests` is on.
+    fn json_body<B: Serialize + ?Sized>(
+        &self,
+        req: reqwest::RequestBuilder,
+        body: &B,
+    ) -> reqwest::RequestBuilder {
+        if cfg!(feature = "tracing") && self.log_bodies {
+            if let Ok(bytes) = serde_json::to_vec(body) {
+                self.log_body("request", &bytes);
+            }
+        }
+        if self.compress_requests {
+            if let Ok(bytes) = serde_json::to_vec(body) {
+                return req
+                    .header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .header(reqwest::header::CONTENT_ENCODING, "gzip")
+                    .body(gzip(&bytes));
+            }
+        }
+        req.json(body)
+    }
+
+    /// Read a successful response body and deserialize it as JSON.
+    async fn read_json<T: DeserializeOwned>(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<T, DoclingError> {
+        let bytes = response.bytes().await?;
+        self.log_body("response", &bytes);
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    /// Log a request or response body at `debug` level when `log_bodies` is
+    /// enabled. The snippet is capped at [`MAX_LOGGED_BODY_BYTES`] and any
+    /// occurrence of the API key is redacted.
+    #[cfg(feature = "tracing")]
+    fn log_body(&self, direction: &str, bytes: &[u8]) {
+        if !self.log_bodies {
+            return;
+        }
+        let snippet = body_snippet(bytes, self.credentials.as_ref().map(Auth::secret));
+        tracing::debug!(direction, total_bytes = bytes.len(), body = %snippet, "docling body");
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    fn log_body(&self, _direction: &str, _bytes: &[u8]) {}
+
+    /// Send a request with the configured request timeout, first waiting for
+    /// a slot if `max_concurrent_requests` is set. The slot is released once
+    /// the response headers have arrived.
+    ///
+    /// Never retried: synchronous conversions are slow and not idempotent,
+    /// so replaying one would make the server do the work again.
+    async fn send(&self, req: reqwest::RequestBuilder) -> Result<reqwest::Response, DoclingError> {
+        self.send_with_timeout(req, self.request_timeout, false)
+            .await
+    }
+
+    /// [`send`](Self::send) for idempotent `GET`s and the async submit
+    /// endpoints, retried according to the [`RetryPolicy`] if one is set.
+    async fn send_retrying(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, DoclingError> {
+        self.send_with_timeout(req, self.request_timeout, true)
+            .await
+    }
+
+    /// [`send`](Self::send) with an explicit per-request timeout.
+    ///
+    /// With `retryable` and a [`RetryPolicy`], responses with a transient
+    /// status are retried as long as the request body can be replayed.
+    /// `timeout` then bounds all attempts together, including the delays
+    /// between them. A `Retry-After` longer than the policy's `max_delay`
+    /// or the remaining time ends the retries with that response.
+    async fn send_with_timeout(
+        &self,
+        mut req: reqwest::RequestBuilder,
+        timeout: Option<Duration>,
+        retryable: bool,
+    ) -> Result<reqwest::Response, DoclingError> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut retry = 0;
+        loop {
+            let next = match &self.retry_policy {
+                Some(policy) if retryable && retry < policy.max_retries => req.try_clone(),
+                _ => None,
+            };
+            let remaining = deadline.map(|d| d.saturating_duration_since(Instant::now()));
+            let resp = self.send_once(req, remaining).await?;
+
+            let (Some(next), Some(policy)) = (next, &self.retry_policy) else {
+                return Ok(resp);
+            };
+            if !is_retryable_status(resp.status()) {
+                return Ok(resp);
+            }
+            let Some(delay) = policy.delay(retry, retry_after(resp.headers())) else {
+                return Ok(resp);
+            };
+            if deadline.is_some_and(|d| Instant::now() + delay >= d) {
+                return Ok(resp);
+            }
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                retry = retry + 1,
+                status_code = resp.status().as_u16(),
+                delay_ms = delay.as_millis() as u64,
+                "retrying docling request"
+            );
+            drop(resp);
+            tokio::time::sleep(delay).await;
+            req = next;
+            retry += 1;
+        }
+    }
+
+    /// Send a single attempt, holding a concurrency slot until the response
+    /// headers arrive.
+    async fn send_once(
+        &self,
+        req: reqwest::RequestBuilder,
+        timeout: Option<Duration>,
+    ) -> Result<reqwest::Response, DoclingError> {
+        let req = match timeout {
+            Some(timeout) => req.timeout(timeout),
+            None => req,
+        };
+        let req = match &self.user_agent {
+            Some(user_agent) => req.header(reqwest::header::USER_AGENT, user_agent),
             None => req,
+        };
+        let (http, request) = req.build_split();
+        let mut request = request?;
+        // Defaults fill in headers the request does not set itself, so they
+        // never replace the bearer token or a per-request header.
+        for name in self.default_headers.keys() {
+            if !request.headers().contains_key(name) {
+                for value in self.default_headers.get_all(name) {
+                    request.headers_mut().append(name, value.clone());
+                }
+            }
         }
+        let _permit = match &self.limiter {
+            Some(limiter) => Some(limiter.acquire().await.expect("semaphore is never closed")),
+            None => None,
+        };
+        execute(&http, request).await
     }
 
     /// Send a request and handle non-success status codes by reading the
-    /// body and returning a structured `DoclingError::Api`.
+    /// body and returning a structured `DoclingError::Api`, or
+    /// `DoclingError::Validation` for a 422 whose body parses as
+    /// [`HttpValidationError`].
     async fn handle_response(
         &self,
         response: reqwest::Response,
@@ -73,7 +351,19 @@ impl DoclingClient {
             Ok(response)
         } else {
             let status_code = status.as_u16();
+            let retry_after = retry_after(response.headers());
             let body = response.text().await.unwrap_or_default();
+            self.log_body("response", body.as_bytes());
+            if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                return Err(DoclingError::RateLimited { retry_after });
+            }
+            if status == reqwest::StatusCode::UNPROCESSABLE_ENTITY {
+                if let Ok(validation) = serde_json::from_str::<HttpValidationError>(&body) {
+                    if !validation.detail.is_empty() {
+                        return Err(DoclingError::Validation(validation));
+                    }
+                }
+            }
             Err(DoclingError::Api { status_code, body })
         }
     }
@@ -82,37 +372,153 @@ impl DoclingClient {
     ///
     /// This is the shared implementation used by both [`wait_for_conversion`]
     /// and [`wait_for_file_conversion`] to avoid duplicated polling logic.
-    async fn poll_until_complete(
+    pub(crate) async fn poll_until_complete(
         &self,
         task_id: &str,
-        timeout: Duration,
-        poll_interval_secs: Option<f64>,
+        config: &WaitConfig,
     ) -> Result<ConvertDocumentResponse, DoclingError> {
-        let poll_wait = poll_interval_secs.unwrap_or(5.0);
+        self.poll_until_complete_detailed(task_id, config)
+            .await
+            .map(|(response, _)| response)
+    }
+
+    /// [`poll_until_complete`](Self::poll_until_complete), also reporting
+    /// how many polls were sent and how long the wait took.
+    async fn poll_until_complete_detailed(
+        &self,
+        task_id: &str,
+        config: &WaitConfig,
+    ) -> Result<(ConvertDocumentResponse, WaitStats), DoclingError> {
+        self.poll_until_finished(task_id, TaskType::Convert, config, None, || {
+            self.get_task_result(task_id)
+        })
+        .await
+    }
+
+    /// Poll a task of the given type until it finishes, then fetch its
+    /// result with `fetch`.
+    ///
+    /// A task the server reports with a different `task_type` is rejected
+    /// with [`DoclingError::InvalidOptions`], since its result would not
+    /// parse as the expected response. Triggering `cancel` ends the wait
+    /// with [`DoclingError::Cancelled`], interrupting an in-flight poll.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "docling.wait", skip_all, fields(task_id = %task_id))
+    )]
+    async fn poll_until_finished<T, Fut>(
+        &self,
+        task_id: &str,
+        task_type: TaskType,
+        config: &WaitConfig,
+        cancel: Option<&CancellationToken>,
+        fetch: impl FnOnce() -> Fut,
+    ) -> Result<(T, WaitStats), DoclingError>
+    where
+        Fut: Future<Output = Result<T, DoclingError>>,
+    {
         let start = Instant::now();
+        let mut polls: u32 = 0;
+        let mut consecutive_errors: u32 = 0;
+        let mut last_seen: Option<(TaskStatus, Option<i64>)> = None;
+
+        let cancelled = || DoclingError::Cancelled {
+            task_id: task_id.to_string(),
+        };
 
         loop {
-            if start.elapsed() > timeout {
+            if cancel.is_some_and(|cancel| cancel.is_cancelled()) {
+                return Err(cancelled());
+            }
+
+            let max_polls_reached = config.max_polls.is_some_and(|max| polls >= max);
+            if start.elapsed() > config.timeout || max_polls_reached {
+                let (last_status, last_position) = match last_seen {
+                    Some((status, position)) => (Some(status), position),
+                    None => (None, None),
+                };
                 return Err(DoclingError::Timeout {
                     task_id: task_id.to_string(),
                     elapsed_secs: start.elapsed().as_secs_f64(),
+                    last_status,
+                    last_position,
                 });
             }
 
-            let status = self.poll_task_status(task_id, Some(poll_wait)).await?;
+            let poll_wait = config.poll.wait_for(polls);
+            polls += 1;
+
+            let poll_started = Instant::now();
+            let poll = self.poll_task_status(task_id, Some(poll_wait.as_secs_f64()));
+            let Some(result) = unless_cancelled(cancel, poll).await else {
+                return Err(cancelled());
+            };
+
+            // A poll that came back early (wait of zero, or a server that
+            // ignores it) must not turn this loop into back-to-back requests.
+            let pace = || async {
+                let elapsed = poll_started.elapsed();
+                if elapsed < MIN_POLL_INTERVAL {
+                    let pause = poll_wait.max(MIN_POLL_INTERVAL) - elapsed;
+                    let remaining = config.timeout.saturating_sub(start.elapsed());
+                    unless_cancelled(cancel, tokio::time::sleep(pause.min(remaining))).await;
+                }
+            };
+
+            let status = match result {
+                Ok(status) if status.task_type != task_type => {
+                    return Err(DoclingError::InvalidOptions {
+                        reason: format!(
+                            "task {} is a {} task, not a {} task",
+                            task_id, status.task_type, task_type
+                        ),
+                    });
+                }
+                Ok(status) => {
+                    consecutive_errors = 0;
+                    status
+                }
+                Err(err) if err.is_retryable() && consecutive_errors < config.max_poll_errors => {
+                    consecutive_errors += 1;
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(poll = polls, error = %err, "docling status poll failed, retrying");
+                    pace().await;
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                poll = polls,
+                task_status = %status.task_status,
+                task_position = ?status.task_position,
+                "docling status poll"
+            );
 
-            match status.task_status.as_str() {
-                "SUCCESS" => {
-                    return self.get_task_result(task_id).await;
+            match status.task_status {
+                // A partial success still has a result; its `errors` say
+                // which documents failed.
+                TaskStatus::Success | TaskStatus::PartialSuccess => {
+                    let response = fetch().await?;
+                    let stats = WaitStats {
+                        polls,
+                        waited: start.elapsed(),
+                    };
+                    return Ok((response, stats));
                 }
-                "FAILURE" => {
+                // A skipped task has nothing to fetch.
+                TaskStatus::Failure | TaskStatus::Skipped => {
                     return Err(DoclingError::TaskFailed {
                         task_id: task_id.to_string(),
-                        status: "FAILURE".to_string(),
+                        status: status.task_status.to_string(),
                     });
                 }
                 // PENDING, STARTED, or any other status — keep polling
-                _ => continue,
+                TaskStatus::Pending | TaskStatus::Started | TaskStatus::Other(_) => {
+                    last_seen = Some((status.task_status, status.task_position));
+                    pace().await
+                }
             }
         }
     }
@@ -125,22 +531,90 @@ impl DoclingClient {
     ///
     /// `GET /health`
     pub async fn health(&self) -> Result<HealthCheckResponse, DoclingError> {
-        let resp = self.http.get(self.url("/health")).send().await?;
+        let resp = self
+            .send_retrying(self.http.get(self.url("/health")))
+            .await?;
         let resp = self.handle_response(resp).await?;
-        let body = resp.json::<HealthCheckResponse>().await?;
+        let body = self.read_json::<HealthCheckResponse>(resp).await?;
         Ok(body)
     }
 
+    /// Poll `/health` until the server reports `ok`, for use right after
+    /// starting Docling Serve.
+    ///
+    /// Connection errors, request timeouts, `5xx` responses, and a status
+    /// other than `ok` mean the server is still starting and are retried
+    /// every `interval`; any other error is returned immediately. If the
+    /// server is not healthy within `timeout`, returns
+    /// [`DoclingError::Timeout`] with the task id `"health"`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # async fn example() -> Result<(), docling_rs::DoclingError> {
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// client
+    ///     .wait_until_healthy(Duration::from_secs(60), Duration::from_secs(1))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_until_healthy(
+        &self,
+        timeout: Duration,
+        interval: Duration,
+    ) -> Result<(), DoclingError> {
+        let start = Instant::now();
+        loop {
+            let remaining = timeout.saturating_sub(start.elapsed());
+            match tokio::time::timeout(remaining, self.health()).await {
+                Ok(Ok(health)) if health.status.eq_ignore_ascii_case("ok") => return Ok(()),
+                Ok(Ok(_)) | Err(_) => {}
+                Ok(Err(DoclingError::Http(e))) if e.is_connect() || e.is_timeout() => {}
+                Ok(Err(DoclingError::Api { status_code, .. })) if status_code >= 500 => {}
+                Ok(Err(e)) => return Err(e),
+            }
+
+            let remaining = timeout.saturating_sub(start.elapsed());
+            if remaining.is_zero() {
+                return Err(DoclingError::Timeout {
+                    task_id: "health".to_string(),
+                    elapsed_secs: start.elapsed().as_secs_f64(),
+                    last_status: None,
+                    last_position: None,
+                });
+            }
+            tokio::time::sleep(interval.min(remaining)).await;
+        }
+    }
+
     /// Get version information from the Docling Serve instance.
     ///
     /// `GET /version`
     pub async fn version(&self) -> Result<HashMap<String, serde_json::Value>, DoclingError> {
-        let resp = self.http.get(self.url("/version")).send().await?;
+        let resp = self
+            .send_retrying(self.http.get(self.url("/version")))
+            .await?;
         let resp = self.handle_response(resp).await?;
-        let body = resp.json::<HashMap<String, serde_json::Value>>().await?;
+        let body = self
+            .read_json::<HashMap<String, serde_json::Value>>(resp)
+            .await?;
         Ok(body)
     }
 
+    /// Get version information as a typed [`VersionResponse`].
+    ///
+    /// `GET /version`
+    ///
+    /// [`version`](Self::version) returns the same data as an untyped map.
+    pub async fn version_typed(&self) -> Result<VersionResponse, DoclingError> {
+        let resp = self
+            .send_retrying(self.http.get(self.url("/version")))
+            .await?;
+        let resp = self.handle_response(resp).await?;
+        self.read_json::<VersionResponse>(resp).await
+    }
+
     // ========================================================================
     // Synchronous URL conversion
     // ========================================================================
@@ -153,7 +627,10 @@ impl DoclingClient {
     /// `POST /v1/convert/source`
     ///
     /// # Arguments
-    /// * `url` — The HTTP URL of the document to convert.
+    /// * `url` — The HTTP URL of the document to convert. Anything but an
+    ///   absolute `http`/`https` URL, such as a local path, is rejected with
+    ///   [`DoclingError::InvalidOptions`]; use
+    ///   [`convert_file`](Self::convert_file) for local files.
     /// * `options` — Optional conversion options. Pass `None` for server defaults.
     ///
     /// # Example
@@ -174,167 +651,1869 @@ impl DoclingClient {
         url: &str,
         options: Option<ConvertDocumentsRequestOptions>,
     ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let resp = self.send_convert_source(url, options).await?;
+        let body = self.read_json::<ConvertDocumentResponse>(resp).await?;
+        Ok(body)
+    }
+
+    /// Convert a document from a URL and also return the response headers.
+    ///
+    /// `POST /v1/convert/source`
+    ///
+    /// Behaves like [`convert_source`](Self::convert_source). The headers
+    /// help correlate a conversion with server or proxy logs, e.g. through a
+    /// request id, timing, or rate-limit header set by the deployment.
+    ///
+    /// ```rust,no_run
+    /// # async fn example() -> Result<(), docling_rs::DoclingError> {
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let (result, headers) = client
+    ///     .convert_source_with_headers("https://arxiv.org/pdf/2206.01062", None)
+    ///     .await?;
+    /// if let Some(id) = headers.get("x-request-id") {
+    ///     println!("request id: {:?}", id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_source_with_headers(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+    ) -> Result<(ConvertDocumentResponse, reqwest::header::HeaderMap), DoclingError> {
+        let resp = self.send_convert_source(url, options).await?;
+        let headers = resp.headers().clone();
+        let body = self.read_json::<ConvertDocumentResponse>(resp).await?;
+        Ok((body, headers))
+    }
+
+    /// Send `POST /v1/convert/source` for one URL and return the successful
+    /// response, before its body is read.
+    async fn send_convert_source(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+    ) -> Result<reqwest::Response, DoclingError> {
+        validate_options(options.as_ref())?;
         let request_body = ConvertDocumentsRequest {
-            sources: vec![Source::Http {
-                url: url.to_string(),
-                headers: None,
-            }],
+            sources: vec![Source::from_http_url(url)?],
             options,
             target: None, // defaults to InBody
         };
 
-        let req = self.auth(
-            self.http
-                .post(self.url("/v1/convert/source"))
-                .json(&request_body),
-        );
+        let req = self
+            .auth(self.json_body(
+                self.http.post(self.url("/v1/convert/source")),
+                &request_body,
+            ))
+            .await?;
 
-        let resp = req.send().await?;
-        let resp = self.handle_response(resp).await?;
-        let body = resp.json::<ConvertDocumentResponse>().await?;
-        Ok(body)
+        let resp = self.send(req).await?;
+        self.handle_response(resp).await
     }
 
-    /// Convert documents from multiple sources (synchronous).
+    /// Convert a document from a URL and return only its Markdown.
     ///
-    /// `POST /v1/convert/source`
+    /// `POST /v1/convert/source` with `to_formats = ["md"]` and otherwise
+    /// default options. Returns [`DoclingError::MissingContent`] if the
+    /// response has no Markdown.
     ///
-    /// Use this when you need full control over sources, options, and target.
-    pub async fn convert(
-        &self,
-        request: &ConvertDocumentsRequest,
-    ) -> Result<ConvertDocumentResponse, DoclingError> {
-        let req = self.auth(
-            self.http
-                .post(self.url("/v1/convert/source"))
-                .json(request),
-        );
+    /// ```rust,no_run
+    /// # async fn example() -> Result<(), docling_rs::DoclingError> {
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let md = client.to_markdown("https://arxiv.org/pdf/2206.01062").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn to_markdown(&self, url: &str) -> Result<String, DoclingError> {
+        self.convert_to_text_format(url, OutputFormat::Md).await
+    }
 
-        let resp = req.send().await?;
-        let resp = self.handle_response(resp).await?;
-        let body = resp.json::<ConvertDocumentResponse>().await?;
-        Ok(body)
+    /// Convert a document from a URL and return only its plain text.
+    ///
+    /// Like [`to_markdown`](Self::to_markdown) with `to_formats = ["text"]`.
+    pub async fn to_text(&self, url: &str) -> Result<String, DoclingError> {
+        self.convert_to_text_format(url, OutputFormat::Text).await
     }
 
-    // ========================================================================
-    // Async URL conversion
-    // ========================================================================
+    /// Convert a document from a URL and return only its HTML.
+    ///
+    /// Like [`to_markdown`](Self::to_markdown) with `to_formats = ["html"]`.
+    pub async fn to_html(&self, url: &str) -> Result<String, DoclingError> {
+        self.convert_to_text_format(url, OutputFormat::Html).await
+    }
 
-    /// Submit a document for asynchronous conversion.
+    /// Convert a document from a URL and return only its `DoclingDocument`
+    /// JSON.
     ///
-    /// Returns a `TaskStatusResponse` containing the `task_id` which can be
-    /// used to poll for status and retrieve results.
+    /// Like [`to_markdown`](Self::to_markdown) with `to_formats = ["json"]`.
+    /// Use [`ExportDocumentResponse::parse_json`] on a full response for a
+    /// typed document instead.
+    pub async fn to_json(&self, url: &str) -> Result<serde_json::Value, DoclingError> {
+        self.convert_to_format(url, OutputFormat::Json)
+            .await?
+            .json_content
+            .ok_or(DoclingError::MissingContent {
+                format: OutputFormat::Json,
+            })
+    }
+
+    /// Convert a URL requesting a single output format.
+    async fn convert_to_format(
+        &self,
+        url: &str,
+        format: OutputFormat,
+    ) -> Result<ExportDocumentResponse, DoclingError> {
+        let options = ConvertDocumentsRequestOptions {
+            to_formats: Some(vec![format]),
+            ..Default::default()
+        };
+        Ok(self.convert_source(url, Some(options)).await?.document)
+    }
+
+    /// Convert a URL to a single textual format and return that content.
+    async fn convert_to_text_format(
+        &self,
+        url: &str,
+        format: OutputFormat,
+    ) -> Result<String, DoclingError> {
+        let document = self.convert_to_format(url, format.clone()).await?;
+        match document.content(format.clone()) {
+            Some(content) => Ok(content.to_string()),
+            None => Err(DoclingError::MissingContent { format }),
+        }
+    }
+
+    /// Convert a document from a URL and return the unparsed JSON response.
     ///
-    /// `POST /v1/convert/source/async`
-    pub async fn convert_source_async(
+    /// `POST /v1/convert/source`
+    ///
+    /// An escape hatch alongside [`convert_source`](Self::convert_source):
+    /// the response is returned as a [`serde_json::Value`] instead of a
+    /// [`ConvertDocumentResponse`], so fields the SDK does not model yet are
+    /// kept. Non-2xx responses still return [`DoclingError::Api`].
+    pub async fn convert_source_raw(
         &self,
         url: &str,
         options: Option<ConvertDocumentsRequestOptions>,
-    ) -> Result<TaskStatusResponse, DoclingError> {
+    ) -> Result<serde_json::Value, DoclingError> {
+        validate_options(options.as_ref())?;
         let request_body = ConvertDocumentsRequest {
-            sources: vec![Source::Http {
-                url: url.to_string(),
-                headers: None,
-            }],
+            sources: vec![Source::from_http_url(url)?],
             options,
             target: None,
         };
 
-        let req = self.auth(
-            self.http
-                .post(self.url("/v1/convert/source/async"))
-                .json(&request_body),
-        );
+        let req = self
+            .auth(self.json_body(
+                self.http.post(self.url("/v1/convert/source")),
+                &request_body,
+            ))
+            .await?;
 
-        let resp = req.send().await?;
+        let resp = self.send(req).await?;
         let resp = self.handle_response(resp).await?;
-        let body = resp.json::<TaskStatusResponse>().await?;
+        let body = self.read_json::<serde_json::Value>(resp).await?;
         Ok(body)
     }
 
-    /// Submit a full request for asynchronous conversion.
+    /// Convert a document from a URL and write its Markdown to `writer` as
+    /// the response arrives.
     ///
-    /// `POST /v1/convert/source/async`
-    pub async fn convert_async(
+    /// `POST /v1/convert/source`
+    ///
+    /// Unlike [`convert_source`](Self::convert_source), the response is
+    /// never held in memory: `document.md_content` is decoded from the body
+    /// chunk by chunk and everything else is skipped, so peak memory stays
+    /// small even for book-sized documents. Request Markdown only (e.g. with
+    /// [`ConvertDocumentsRequestOptions::markdown_only`]) to keep the server
+    /// from producing other formats that would just be discarded.
+    ///
+    /// Returns the number of bytes written. If the response has no Markdown,
+    /// returns [`DoclingError::MissingContent`]; anything already written
+    /// stays in `writer`.
+    ///
+    /// ```rust,no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let file = std::io::BufWriter::new(std::fs::File::create("book.md")?);
+    /// let options = docling_rs::ConvertDocumentsRequestOptions::markdown_only();
+    /// client
+    ///     .convert_source_to_writer("https://example.com/book.pdf", Some(options), file)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_source_to_writer(
         &self,
-        request: &ConvertDocumentsRequest,
-    ) -> Result<TaskStatusResponse, DoclingError> {
-        let req = self.auth(
-            self.http
-                .post(self.url("/v1/convert/source/async"))
-                .json(request),
-        );
-
-        let resp = req.send().await?;
-        let resp = self.handle_response(resp).await?;
-        let body = resp.json::<TaskStatusResponse>().await?;
-        Ok(body)
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+        writer: impl std::io::Write,
+    ) -> Result<u64, DoclingError> {
+        let resp = self.send_convert_source(url, options).await?;
+        write_markdown(resp, writer).await
     }
 
-    // ========================================================================
-    // Task polling & result retrieval
-    // ========================================================================
-
-    /// Poll the status of an async task.
+    /// Convert a document from a URL, reusing an earlier result for an
+    /// identical request.
     ///
-    /// `GET /v1/status/poll/{task_id}?wait=N`
+    /// The cache key is the URL together with the JSON-serialized `options`,
+    /// so two calls hit the server once only if both are identical. Only
+    /// responses whose [`status`](ConvertDocumentResponse::status) is
+    /// [`ConversionStatus::Success`] are stored; errors, failures and partial
+    /// successes are fetched again on the next call. The cache lives in the
+    /// client (and is shared by its clones), holds 64 entries by default (see
+    /// [`DoclingClientBuilder::cache_capacity`]), and evicts the least
+    /// recently used entry when full.
     ///
-    /// # Arguments
-    /// * `task_id` — The task ID from `convert_source_async`.
-    /// * `wait_secs` — Optional long-poll duration. The server will hold the
-    ///   connection open for up to this many seconds waiting for completion.
-    ///   Pass `None` or `Some(0.0)` for an immediate status check.
-    pub async fn poll_task_status(
+    /// Requires the `cache` feature.
+    #[cfg(feature = "cache")]
+    pub async fn convert_source_memoized(
         &self,
-        task_id: &str,
-        wait_secs: Option<f64>,
-    ) -> Result<TaskStatusResponse, DoclingError> {
-        let mut url = self.url(&format!("/v1/status/poll/{}", task_id));
-        if let Some(w) = wait_secs {
-            url = format!("{}?wait={}", url, w);
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let key = memo_key(url, options.as_ref())?;
+        if let Some(hit) = self.cache.lock().unwrap().get(&key) {
+            return Ok(hit.clone());
+        }
+
+        let response = self.convert_source(url, options).await?;
+        if response.status == ConversionStatus::Success {
+            self.cache.lock().unwrap().put(key, response.clone());
+        }
+        Ok(response)
+    }
+
+    /// Convert documents from multiple sources (synchronous).
+    ///
+    /// `POST /v1/convert/source`
+    ///
+    /// Use this when you need full control over sources, options, and target.
+    /// A request with no sources, or with a [`Target::Zip`] or
+    /// [`Target::S3`] target (use [`convert_zip`](Self::convert_zip) or
+    /// [`convert_to_s3`](Self::convert_to_s3)), is rejected locally with
+    /// [`DoclingError::InvalidOptions`].
+    pub async fn convert(
+        &self,
+        request: &ConvertDocumentsRequest,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let resp = self.send_convert(request).await?;
+        let body = self.read_json::<ConvertDocumentResponse>(resp).await?;
+        Ok(body)
+    }
+
+    /// Convert documents from multiple sources and return a result for each.
+    ///
+    /// `POST /v1/convert/source`
+    ///
+    /// Like [`convert`](Self::convert), but the response is a
+    /// [`ConvertDocumentsResponse`] with one entry in `documents` per
+    /// converted document. A server that answers with a single `document`
+    /// yields a one-element list. A ZIP response, which some servers send
+    /// for several in-body documents, returns
+    /// [`DoclingError::UnexpectedResponse`]; use
+    /// [`convert_zip`](Self::convert_zip) for those.
+    pub async fn convert_documents(
+        &self,
+        request: &ConvertDocumentsRequest,
+    ) -> Result<ConvertDocumentsResponse, DoclingError> {
+        let resp = self.send_convert(request).await?;
+        if is_zip_response(&resp) {
+            return Err(DoclingError::UnexpectedResponse {
+                reason: "server returned a ZIP archive; use convert_zip to receive it".to_string(),
+            });
+        }
+        let body = self.read_json::<serde_json::Value>(resp).await?;
+        if body.get("documents").is_some() {
+            return Ok(serde_json::from_value(body)?);
+        }
+        let single: ConvertDocumentResponse = serde_json::from_value(body)?;
+        Ok(single.into())
+    }
+
+    /// The JSON body [`convert`](Self::convert) would post for `request`,
+    /// without sending anything.
+    ///
+    /// Useful for logging or asserting on the outgoing payload, e.g. when
+    /// diagnosing a `422`. The same local checks as `convert` apply, so a
+    /// request with no sources returns [`DoclingError::InvalidOptions`].
+    pub fn build_convert_body(
+        &self,
+        request: &ConvertDocumentsRequest,
+    ) -> Result<serde_json::Value, DoclingError> {
+        ensure_valid_request(request)?;
+        Ok(serde_json::to_value(request)?)
+    }
+
+    /// Send a full in-body conversion request and return the successful
+    /// response, before its body is read.
+    async fn send_convert(
+        &self,
+        request: &ConvertDocumentsRequest,
+    ) -> Result<reqwest::Response, DoclingError> {
+        ensure_valid_request(request)?;
+        match request.target {
+            Some(Target::Zip) => return Err(target_mismatch("zip", "convert_zip")),
+            Some(Target::S3(_)) => return Err(target_mismatch("s3", "convert_to_s3")),
+            Some(Target::InBody) | None => {}
+        }
+        let req = self
+            .auth(self.json_body(self.http.post(self.url("/v1/convert/source")), request))
+            .await?;
+
+        let resp = self.send(req).await?;
+        self.handle_response(resp).await
+    }
+
+    /// Convert documents from several URLs in one request.
+    ///
+    /// `POST /v1/convert/source` with one [`Source::Http`] per URL
+    ///
+    /// [`ConvertDocumentResponse`] holds a single document, so this suits
+    /// calls that produce one: if the server answers with a ZIP archive of
+    /// several documents instead, [`DoclingError::UnexpectedResponse`] is
+    /// returned. Use [`convert_zip`](Self::convert_zip) to receive every
+    /// document, or [`convert_many`](Self::convert_many) to convert each URL
+    /// separately. No URLs, or a URL that is not `http`/`https`, is rejected
+    /// locally.
+    pub async fn convert_sources(
+        &self,
+        urls: &[&str],
+        options: Option<ConvertDocumentsRequestOptions>,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let request = ConvertDocumentsRequest {
+            sources: urls
+                .iter()
+                .map(|url| Source::from_http_url(url))
+                .collect::<Result<_, _>>()?,
+            options,
+            target: None,
+        };
+        ensure_valid_request(&request)?;
+        let req = self
+            .auth(self.json_body(self.http.post(self.url("/v1/convert/source")), &request))
+            .await?;
+
+        let resp = self.send(req).await?;
+        let resp = self.handle_response(resp).await?;
+        if is_zip_response(&resp) {
+            return Err(DoclingError::UnexpectedResponse {
+                reason: format!(
+                    "server returned several documents for {} sources; use convert_zip to receive them",
+                    urls.len()
+                ),
+            });
+        }
+        self.read_json::<ConvertDocumentResponse>(resp).await
+    }
+
+    /// Convert documents with a [`Target::Zip`] target and return the raw
+    /// ZIP archive.
+    ///
+    /// `POST /v1/convert/source`
+    ///
+    /// The request's target is overridden to [`Target::Zip`]. The archive
+    /// holds one file per requested output format and document.
+    pub async fn convert_zip(
+        &self,
+        request: &ConvertDocumentsRequest,
+    ) -> Result<Vec<u8>, DoclingError> {
+        ensure_valid_request(request)?;
+        let request = ConvertDocumentsRequest {
+            target: Some(Target::Zip),
+            ..request.clone()
+        };
+        let req = self
+            .auth(self.json_body(self.http.post(self.url("/v1/convert/source")), &request))
+            .await?;
+
+        let resp = self.send(req).await?;
+        let resp = self.handle_response(resp).await?;
+        Ok(resp.bytes().await?.to_vec())
+    }
+
+    /// Convert a document from a URL and return the results as a ZIP
+    /// archive. See [`convert_zip`](Self::convert_zip).
+    ///
+    /// `POST /v1/convert/source`
+    pub async fn convert_source_zip(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+    ) -> Result<Vec<u8>, DoclingError> {
+        let request = ConvertDocumentsRequest {
+            sources: vec![Source::from_http_url(url)?],
+            options,
+            target: Some(Target::Zip),
+        };
+        self.convert_zip(&request).await
+    }
+
+    /// Convert documents and have Docling Serve upload the results to an
+    /// S3-compatible bucket.
+    ///
+    /// `POST /v1/convert/source`
+    ///
+    /// The request's target is overridden to `Target::S3(target)`. The
+    /// response only reports how many documents were converted; the results
+    /// themselves are in the bucket under `target.key_prefix`.
+    pub async fn convert_to_s3(
+        &self,
+        request: &ConvertDocumentsRequest,
+        target: S3Target,
+    ) -> Result<PresignedUrlConvertDocumentResponse, DoclingError> {
+        ensure_valid_request(request)?;
+        let request = ConvertDocumentsRequest {
+            target: Some(Target::S3(target)),
+            ..request.clone()
+        };
+        let req = self
+            .auth(self.json_body(self.http.post(self.url("/v1/convert/source")), &request))
+            .await?;
+
+        let resp = self.send(req).await?;
+        let resp = self.handle_response(resp).await?;
+        let body = self
+            .read_json::<PresignedUrlConvertDocumentResponse>(resp)
+            .await?;
+        Ok(body)
+    }
+
+    /// Convert a document synchronously, falling back to async if it is slow.
+    ///
+    /// Attempts [`convert_source`](Self::convert_source) for at most
+    /// `sync_budget`. If the conversion finishes in time the result is
+    /// returned as [`AdaptiveOutcome::Completed`]. Otherwise the synchronous
+    /// request is abandoned and the document is submitted via
+    /// [`convert_source_async`](Self::convert_source_async), returning
+    /// [`AdaptiveOutcome::Pending`] with the task to poll.
+    ///
+    /// Note that abandoning the synchronous request closes the connection, but
+    /// the server may still finish that conversion in the background.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// use docling_rs::AdaptiveOutcome;
+    ///
+    /// let client = docling_rs::client::DoclingClient::new("http://127.0.0.1:5001");
+    /// match client
+    ///     .convert_source_adaptive("https://arxiv.org/pdf/2206.01062", None, Duration::from_secs(10))
+    ///     .await?
+    /// {
+    ///     AdaptiveOutcome::Completed(result) => println!("Done: {:?}", result.status),
+    ///     AdaptiveOutcome::Pending(task) => println!("Poll task {}", task.task_id),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_source_adaptive(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+        sync_budget: Duration,
+    ) -> Result<AdaptiveOutcome, DoclingError> {
+        match tokio::time::timeout(sync_budget, self.convert_source(url, options.clone())).await {
+            Ok(result) => result.map(AdaptiveOutcome::Completed),
+            Err(_) => {
+                let task = self.convert_source_async(url, options).await?;
+                Ok(AdaptiveOutcome::Pending(task))
+            }
+        }
+    }
+
+    /// Convert documents from multiple sources, yielding results as they arrive.
+    ///
+    /// `POST /v1/convert/source` with `Accept: application/x-ndjson`
+    ///
+    /// If the server answers with newline-delimited JSON (one
+    /// `ConvertDocumentResponse` per line), each line is parsed and yielded as
+    /// soon as it is received, so early documents can be processed while later
+    /// ones are still converting. A line that fails to parse yields an `Err`
+    /// without ending the stream, and a final line without a trailing newline
+    /// is still parsed.
+    ///
+    /// Docling Serve deployments that do not stream answer with a single
+    /// buffered JSON body; in that case the stream yields that one response.
+    /// A request or HTTP error is yielded as the only item.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example(request: docling_rs::ConvertDocumentsRequest) {
+    /// use futures::StreamExt;
+    ///
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let mut results = std::pin::pin!(client.convert_batch_stream(&request));
+    /// while let Some(result) = results.next().await {
+    ///     match result {
+    ///         Ok(doc) => println!("Converted {}", doc.document.filename),
+    ///         Err(e) => eprintln!("Failed: {}", e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn convert_batch_stream<'a>(
+        &'a self,
+        request: &'a ConvertDocumentsRequest,
+    ) -> impl Stream<Item = Result<ConvertDocumentResponse, DoclingError>> + 'a {
+        enum State<'a> {
+            Start,
+            Streaming {
+                body: futures::stream::BoxStream<'a, reqwest::Result<bytes::Bytes>>,
+                buf: Vec<u8>,
+                ready: VecDeque<Result<ConvertDocumentResponse, DoclingError>>,
+            },
+            Draining(VecDeque<Result<ConvertDocumentResponse, DoclingError>>),
+        }
+
+        fn parse_line(line: &[u8]) -> Option<Result<ConvertDocumentResponse, DoclingError>> {
+            let line = line.trim_ascii();
+            if line.is_empty() {
+                return None;
+            }
+            Some(serde_json::from_slice(line).map_err(DoclingError::from))
+        }
+
+        stream::unfold(State::Start, move |state| async move {
+            let mut state = state;
+            loop {
+                match state {
+                    State::Start => {
+                        if let Err(e) = ensure_valid_request(request) {
+                            return Some((Err(e), State::Draining(VecDeque::new())));
+                        }
+                        let req = self
+                            .json_body(self.http.post(self.url("/v1/convert/source")), request)
+                            .header(
+                                reqwest::header::ACCEPT,
+                                "application/x-ndjson, application/json",
+                            );
+                        let req = match self.auth(req).await {
+                            Ok(req) => req,
+                            Err(e) => return Some((Err(e), State::Draining(VecDeque::new()))),
+                        };
+                        let resp = match self.send(req).await {
+                            Ok(resp) => resp,
+                            Err(e) => return Some((Err(e), State::Draining(VecDeque::new()))),
+                        };
+                        let resp = match self.handle_response(resp).await {
+                            Ok(resp) => resp,
+                            Err(e) => return Some((Err(e), State::Draining(VecDeque::new()))),
+                        };
+                        let is_ndjson = resp
+                            .headers()
+                            .get(reqwest::header::CONTENT_TYPE)
+                            .and_then(|v| v.to_str().ok())
+                            .is_some_and(|ct| ct.starts_with("application/x-ndjson"));
+                        if !is_ndjson {
+                            let result = self.read_json::<ConvertDocumentResponse>(resp).await;
+                            return Some((result, State::Draining(VecDeque::new())));
+                        }
+                        state = State::Streaming {
+                            body: resp.bytes_stream().boxed(),
+                            buf: Vec::new(),
+                            ready: VecDeque::new(),
+                        };
+                    }
+                    State::Streaming {
+                        mut body,
+                        mut buf,
+                        mut ready,
+                    } => {
+                        if let Some(item) = ready.pop_front() {
+                            return Some((item, State::Streaming { body, buf, ready }));
+                        }
+                        match body.next().await {
+                            Some(Ok(chunk)) => {
+                                buf.extend_from_slice(&chunk);
+                                while let Some(pos) = buf.iter().position(|&b| b == b'\n') {
+                                    let line: Vec<u8> = buf.drain(..=pos).collect();
+                                    self.log_body("response", &line);
+                                    ready.extend(parse_line(&line));
+                                }
+                                state = State::Streaming { body, buf, ready };
+                            }
+                            Some(Err(e)) => {
+                                return Some((Err(e.into()), State::Draining(VecDeque::new())));
+                            }
+                            None => {
+                                // Trailing line without a final newline
+                                self.log_body("response", &buf);
+                                ready.extend(parse_line(&buf));
+                                state = State::Draining(ready);
+                            }
+                        }
+                    }
+                    State::Draining(mut ready) => {
+                        let item = ready.pop_front()?;
+                        return Some((item, State::Draining(ready)));
+                    }
+                }
+            }
+        })
+    }
+
+    /// Convert every URL from a stream, running up to `concurrency`
+    /// conversions at once.
+    ///
+    /// Each URL is converted with [`convert_source`](Self::convert_source)
+    /// using a clone of `options`. Results are yielded as they complete, so
+    /// their order may differ from the input; each comes with the index of
+    /// its URL in `urls`, as in
+    /// [`convert_many_stream`](Self::convert_many_stream). URLs are only pulled
+    /// from `urls` while fewer than `concurrency` conversions are in flight,
+    /// so a slow consumer applies backpressure to the source. A
+    /// `concurrency` of `0` is treated as `1`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::StreamExt;
+    ///
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let urls = futures::stream::iter(vec![
+    ///     "https://arxiv.org/pdf/2206.01062".to_string(),
+    ///     "https://arxiv.org/pdf/2305.03393".to_string(),
+    /// ]);
+    /// let mut results = std::pin::pin!(client.convert_url_stream(urls, None, 4));
+    /// while let Some((index, result)) = results.next().await {
+    ///     println!("#{index}: {}", result?.document.filename);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn convert_url_stream<'a>(
+        &'a self,
+        urls: impl Stream<Item = String> + 'a,
+        options: Option<ConvertDocumentsRequestOptions>,
+        concurrency: usize,
+    ) -> impl Stream<Item = (usize, Result<ConvertDocumentResponse, DoclingError>)> + 'a {
+        urls.enumerate()
+            .map(move |(index, url)| {
+                let options = options.clone();
+                async move { (index, self.convert_source(&url, options).await) }
+            })
+            .buffer_unordered(concurrency.max(1))
+    }
+
+    /// Convert a list of URLs, running up to `max_concurrency` conversions at
+    /// once, and return every result in input order.
+    ///
+    /// Each URL is converted with [`convert_source`](Self::convert_source)
+    /// using a clone of `options`. A failed conversion is kept as an `Err` at
+    /// its position rather than failing the batch. A `max_concurrency` of `0`
+    /// is treated as `1`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example(urls: Vec<String>) {
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let results = client.convert_many(&urls, None, 8).await;
+    /// for (url, result) in urls.iter().zip(results) {
+    ///     match result {
+    ///         Ok(doc) => println!("{url}: {}", doc.status),
+    ///         Err(e) => eprintln!("{url}: {e}"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn convert_many(
+        &self,
+        urls: &[String],
+        options: Option<ConvertDocumentsRequestOptions>,
+        max_concurrency: usize,
+    ) -> Vec<Result<ConvertDocumentResponse, DoclingError>> {
+        let mut results: Vec<_> = self
+            .convert_many_stream(urls, options, max_concurrency)
+            .collect()
+            .await;
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Convert a list of URLs with bounded concurrency, yielding each result
+    /// with its input index as soon as it completes.
+    ///
+    /// The streaming counterpart of [`convert_many`](Self::convert_many):
+    /// results arrive in completion order, so they can be written out
+    /// incrementally without holding the whole batch in memory. Conversions
+    /// run in a [`FuturesUnordered`](futures::stream::FuturesUnordered) set
+    /// that is only refilled while the stream is polled and fewer than
+    /// `max_concurrency` are in flight, so a slow consumer holds back new
+    /// requests. A `max_concurrency` of `0` is treated as `1`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example(urls: Vec<String>) {
+    /// use futures::StreamExt;
+    ///
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let mut results = std::pin::pin!(client.convert_many_stream(&urls, None, 8));
+    /// while let Some((index, result)) = results.next().await {
+    ///     match result {
+    ///         Ok(doc) => println!("{}: {}", urls[index], doc.status),
+    ///         Err(e) => eprintln!("{}: {e}", urls[index]),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn convert_many_stream<'a>(
+        &'a self,
+        urls: &'a [String],
+        options: Option<ConvertDocumentsRequestOptions>,
+        max_concurrency: usize,
+    ) -> impl Stream<Item = (usize, Result<ConvertDocumentResponse, DoclingError>)> + 'a {
+        // `buffer_unordered` drives its futures with a `FuturesUnordered` and
+        // only pulls the next URL when a slot is free.
+        stream::iter(urls.iter().enumerate())
+            .map(move |(index, url)| {
+                let options = options.clone();
+                async move { (index, self.convert_source(url, options).await) }
+            })
+            .buffer_unordered(max_concurrency.max(1))
+    }
+
+    // ========================================================================
+    // Async URL conversion
+    // ========================================================================
+
+    /// Submit a document for asynchronous conversion.
+    ///
+    /// Returns a `TaskStatusResponse` containing the `task_id` which can be
+    /// used to poll for status and retrieve results.
+    ///
+    /// `POST /v1/convert/source/async`
+    pub async fn convert_source_async(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+    ) -> Result<TaskStatusResponse, DoclingError> {
+        validate_options(options.as_ref())?;
+        let request_body = ConvertDocumentsRequest {
+            sources: vec![Source::from_http_url(url)?],
+            options,
+            target: None,
+        };
+
+        let req = self
+            .auth(self.json_body(
+                self.http.post(self.url("/v1/convert/source/async")),
+                &request_body,
+            ))
+            .await?;
+
+        let resp = self.send_retrying(req).await?;
+        let resp = self.handle_response(resp).await?;
+        let body = self.read_json::<TaskStatusResponse>(resp).await?;
+        Ok(body)
+    }
+
+    /// Submit a full request for asynchronous conversion.
+    ///
+    /// `POST /v1/convert/source/async`
+    pub async fn convert_async(
+        &self,
+        request: &ConvertDocumentsRequest,
+    ) -> Result<TaskStatusResponse, DoclingError> {
+        ensure_valid_request(request)?;
+        let req = self
+            .auth(self.json_body(
+                self.http.post(self.url("/v1/convert/source/async")),
+                request,
+            ))
+            .await?;
+
+        let resp = self.send_retrying(req).await?;
+        let resp = self.handle_response(resp).await?;
+        let body = self.read_json::<TaskStatusResponse>(resp).await?;
+        Ok(body)
+    }
+
+    /// Submit a document for asynchronous conversion and return a
+    /// [`TaskHandle`] to poll, wait on, or cancel it.
+    ///
+    /// `POST /v1/convert/source/async`
+    pub async fn submit_source(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+    ) -> Result<TaskHandle, DoclingError> {
+        let task = self.convert_source_async(url, options).await?;
+        Ok(TaskHandle::new(self.clone(), task.task_id))
+    }
+
+    /// Submit a full request for asynchronous conversion and return a
+    /// [`TaskHandle`].
+    ///
+    /// `POST /v1/convert/source/async`
+    pub async fn submit(
+        &self,
+        request: &ConvertDocumentsRequest,
+    ) -> Result<TaskHandle, DoclingError> {
+        let task = self.convert_async(request).await?;
+        Ok(TaskHandle::new(self.clone(), task.task_id))
+    }
+
+    // ========================================================================
+    // Task polling & result retrieval
+    // ========================================================================
+
+    /// Poll the status of an async task.
+    ///
+    /// `GET /v1/status/poll/{task_id}?wait=N`
+    ///
+    /// # Arguments
+    /// * `task_id` — The task ID from `convert_source_async`.
+    /// * `wait_secs` — Optional long-poll duration. The server will hold the
+    ///   connection open for up to this many seconds waiting for completion.
+    ///   Pass `None` or `Some(0.0)` for an immediate status check. If a
+    ///   request timeout is configured, this request's timeout is raised to
+    ///   at least `wait_secs` plus 10 seconds.
+    ///
+    /// Long-polling is the only HTTP status mechanism: Docling Serve has no
+    /// server-sent-events endpoint. Its other push channel is the WebSocket
+    /// at `/v1/status/ws/{task_id}`, which this client does not use, so
+    /// long-polling also works where WebSockets are blocked.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "docling.poll_task_status", skip_all, fields(task_id = %task_id))
+    )]
+    pub async fn poll_task_status(
+        &self,
+        task_id: &str,
+        wait_secs: Option<f64>,
+    ) -> Result<TaskStatusResponse, DoclingError> {
+        let mut url = self.url(&format!(
+            "/v1/status/poll/{}",
+            encode_path_segment(task_id)?
+        ));
+        if let Some(w) = wait_secs {
+            url = format!("{}?wait={}", url, w);
+        }
+
+        // Never time out a long-poll before the server's own wait elapses.
+        let wait = wait_secs
+            .and_then(|w| Duration::try_from_secs_f64(w).ok())
+            .unwrap_or_default();
+        let timeout = self
+            .request_timeout
+            .map(|t| t.max(wait + LONG_POLL_TIMEOUT_SLACK));
+
+        let req = self.auth(self.http.get(&url)).await?;
+        let resp = self.send_with_timeout(req, timeout, true).await?;
+        let resp = self.handle_response(resp).await?;
+        let body = self.read_json::<TaskStatusResponse>(resp).await?;
+        Ok(body)
+    }
+
+    /// Watch an async task as a stream of status updates.
+    ///
+    /// Each item is the result of one
+    /// [`poll_task_status`](Self::poll_task_status) call, long-polling for up
+    /// to `interval`. The stream ends after yielding a terminal status
+    /// (`SUCCESS` or `FAILURE`) or an error. Polling is driven by the
+    /// consumer, so dropping the stream stops it. Fetch the result with
+    /// [`get_task_result`](Self::get_task_result) once it reports `SUCCESS`.
+    ///
+    /// As in the `wait_for_*` methods, a poll that returns in under 500 ms
+    /// (an `interval` of zero, or a server that ignores it) delays the next
+    /// one until `interval`, or at least 500 ms, after it started.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example(task_id: &str) -> Result<(), docling_rs::DoclingError> {
+    /// use std::time::Duration;
+    /// use futures::StreamExt;
+    ///
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let mut updates = std::pin::pin!(client.watch_task(task_id, Duration::from_secs(5)));
+    /// while let Some(status) = updates.next().await {
+    ///     let status = status?;
+    ///     println!("{} (position {:?})", status.task_status, status.task_position);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_task<'a>(
+        &'a self,
+        task_id: &'a str,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<TaskStatusResponse, DoclingError>> + 'a {
+        stream::unfold((false, None), move |(finished, next_poll)| async move {
+            if finished {
+                return None;
+            }
+            if let Some(next_poll) = next_poll {
+                tokio::time::sleep_until(next_poll).await;
+            }
+
+            let poll_started = tokio::time::Instant::now();
+            let result = self
+                .poll_task_status(task_id, Some(interval.as_secs_f64()))
+                .await;
+            let finished = match &result {
+                Ok(status) => status.task_status.is_terminal(),
+                Err(_) => true,
+            };
+            let next_poll = (poll_started.elapsed() < MIN_POLL_INTERVAL)
+                .then(|| poll_started + interval.max(MIN_POLL_INTERVAL));
+            Some((result, (finished, next_poll)))
+        })
+    }
+
+    /// Retrieve the result of a completed async task.
+    ///
+    /// `GET /v1/result/{task_id}`
+    ///
+    /// This should only be called after `poll_task_status` indicates the
+    /// task has completed (status = "SUCCESS").
+    ///
+    /// Results are all-or-nothing: Docling Serve does not expose per-document
+    /// partial results while a multi-source task is still running, so there
+    /// is no way to fetch the documents that finished early. Split large
+    /// batches into several tasks to process completed documents sooner.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "docling.get_task_result", skip_all, fields(task_id = %task_id))
+    )]
+    pub async fn get_task_result(
+        &self,
+        task_id: &str,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let req = self
+            .auth(
+                self.http
+                    .get(self.url(&format!("/v1/result/{}", encode_path_segment(task_id)?))),
+            )
+            .await?;
+
+        let resp = self.send_retrying(req).await?;
+        let resp = self.handle_response(resp).await?;
+        let body = self.read_json::<ConvertDocumentResponse>(resp).await?;
+        Ok(body)
+    }
+
+    /// Retrieve the result of a completed chunking task.
+    ///
+    /// `GET /v1/result/{task_id}`
+    ///
+    /// Like [`get_task_result`](Self::get_task_result), for tasks submitted
+    /// with [`chunk_hybrid_async`](Self::chunk_hybrid_async) or
+    /// [`chunk_async`](Self::chunk_async), whose result is a list of chunks
+    /// instead of a converted document.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "docling.get_chunk_result", skip_all, fields(task_id = %task_id))
+    )]
+    pub async fn get_chunk_result(
+        &self,
+        task_id: &str,
+    ) -> Result<ChunkDocumentResponse, DoclingError> {
+        let req = self
+            .auth(
+                self.http
+                    .get(self.url(&format!("/v1/result/{}", encode_path_segment(task_id)?))),
+            )
+            .await?;
+
+        let resp = self.send_retrying(req).await?;
+        let resp = self.handle_response(resp).await?;
+        self.read_json::<ChunkDocumentResponse>(resp).await
+    }
+
+    /// Write the Markdown of a completed conversion task to `writer` as the
+    /// result downloads.
+    ///
+    /// `GET /v1/result/{task_id}`
+    ///
+    /// The streaming counterpart of [`get_task_result`](Self::get_task_result);
+    /// see [`convert_source_to_writer`](Self::convert_source_to_writer) for
+    /// how the body is processed. Returns the number of bytes written.
+    pub async fn get_task_result_to_writer(
+        &self,
+        task_id: &str,
+        writer: impl std::io::Write,
+    ) -> Result<u64, DoclingError> {
+        let req = self
+            .auth(
+                self.http
+                    .get(self.url(&format!("/v1/result/{}", encode_path_segment(task_id)?))),
+            )
+            .await?;
+
+        let resp = self.send_retrying(req).await?;
+        let resp = self.handle_response(resp).await?;
+        write_markdown(resp, writer).await
+    }
+
+    /// Download part of a task's raw result body, e.g. a large zip archive.
+    ///
+    /// `GET /v1/result/{task_id}` with `Range: bytes=<start>-<end - 1>`
+    ///
+    /// If the server honors the range (`206 Partial Content`), the returned
+    /// [`ResultRange`] holds the requested bytes and the total size from
+    /// `Content-Range`. If it ignores the header and answers `200` with the
+    /// whole body, `partial` is `false`, `start` is `0`, and `bytes` holds
+    /// everything. An empty range returns [`DoclingError::InvalidOptions`].
+    ///
+    /// # Resumable downloads
+    /// Request consecutive ranges and append each one to a file, restarting
+    /// from the file's current length after a failure:
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: &docling_rs::DoclingClient, task_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::io::Write;
+    ///
+    /// const CHUNK: u64 = 8 * 1024 * 1024;
+    /// let mut file = std::fs::OpenOptions::new().create(true).append(true).open("result.zip")?;
+    /// let mut offset = file.metadata()?.len();
+    /// loop {
+    ///     let range = client.get_task_result_range(task_id, offset..offset + CHUNK).await?;
+    ///     if !range.partial {
+    ///         // Server ignored the range: the body is the whole result.
+    ///         file.set_len(0)?;
+    ///         file.write_all(&range.bytes)?;
+    ///         break;
+    ///     }
+    ///     file.write_all(&range.bytes)?;
+    ///     offset += range.bytes.len() as u64;
+    ///     if range.total.is_some_and(|total| offset >= total) {
+    ///         break;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_task_result_range(
+        &self,
+        task_id: &str,
+        range: std::ops::Range<u64>,
+    ) -> Result<ResultRange, DoclingError> {
+        if range.is_empty() {
+            return Err(DoclingError::InvalidOptions {
+                reason: format!("empty byte range {}..{}", range.start, range.end),
+            });
+        }
+        let req = self
+            .auth(
+                self.http
+                    .get(self.url(&format!("/v1/result/{}", encode_path_segment(task_id)?)))
+                    .header(
+                        reqwest::header::RANGE,
+                        format!("bytes={}-{}", range.start, range.end - 1),
+                    ),
+            )
+            .await?;
+
+        let resp = self.send_retrying(req).await?;
+        let resp = self.handle_response(resp).await?;
+        if resp.status() != reqwest::StatusCode::PARTIAL_CONTENT {
+            let bytes = resp.bytes().await?.to_vec();
+            return Ok(ResultRange {
+                start: 0,
+                total: Some(bytes.len() as u64),
+                bytes,
+                partial: false,
+            });
+        }
+
+        let (start, total) = resp
+            .headers()
+            .get(reqwest::header::CONTENT_RANGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_content_range)
+            .unwrap_or((range.start, None));
+        let bytes = resp.bytes().await?.to_vec();
+        Ok(ResultRange {
+            bytes,
+            start,
+            total,
+            partial: true,
+        })
+    }
+
+    /// Ask the server to cancel an async task.
+    ///
+    /// Docling Serve's API (v1.12.0) has no cancellation endpoint. This sends
+    /// `DELETE /v1/result/{task_id}`, a convention of this crate that only
+    /// works against deployments that add such a route, e.g. a gateway in
+    /// front of Docling Serve. A stock server answers `404` or `405`,
+    /// surfaced as [`DoclingError::Api`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "docling.cancel_task", skip_all, fields(task_id = %task_id))
+    )]
+    pub async fn cancel_task(&self, task_id: &str) -> Result<(), DoclingError> {
+        let req = self
+            .auth(
+                self.http
+                    .delete(self.url(&format!("/v1/result/{}", encode_path_segment(task_id)?))),
+            )
+            .await?;
+
+        let resp = self.send(req).await?;
+        self.handle_response(resp).await?;
+        Ok(())
+    }
+
+    /// Cancel a stuck task and submit the same request again.
+    ///
+    /// Calls [`cancel_task`](Self::cancel_task) and then re-submits
+    /// `original_request` via [`convert_async`](Self::convert_async). The
+    /// server does not keep the original request by task id, so the caller
+    /// must supply it.
+    ///
+    /// Because `cancel_task` depends on server support, a `404` or `405`
+    /// from the cancel call does not stop the re-submission; it is reported
+    /// as [`RestartOutcome::old_cancelled`] being `false`, and the old task
+    /// may keep running on the server. Any other cancel error is returned
+    /// without re-submitting.
+    pub async fn restart_task(
+        &self,
+        task_id: &str,
+        original_request: &ConvertDocumentsRequest,
+    ) -> Result<RestartOutcome, DoclingError> {
+        let old_cancelled = match self.cancel_task(task_id).await {
+            Ok(()) => true,
+            Err(DoclingError::Api {
+                status_code: 404 | 405,
+                ..
+            }) => false,
+            Err(e) => return Err(e),
+        };
+        let new_task = self.convert_async(original_request).await?;
+        Ok(RestartOutcome {
+            new_task,
+            old_cancelled,
+        })
+    }
+
+    // ========================================================================
+    // Convenience: submit URL + wait
+    // ========================================================================
+
+    /// Submit an async conversion and wait for it to complete.
+    ///
+    /// This is a convenience method that combines `convert_source_async`,
+    /// polling via `poll_task_status`, and `get_task_result` into a single
+    /// call. The method polls using server-side long-polling for efficiency.
+    ///
+    /// A `SUCCESS` or `PARTIAL_SUCCESS` task returns its result; check
+    /// `result.errors` for documents that failed. `FAILURE` and `SKIPPED`
+    /// return [`DoclingError::TaskFailed`].
+    ///
+    /// # Arguments
+    /// * `url` — The HTTP URL of the document to convert.
+    /// * `options` — Optional conversion options.
+    /// * `timeout` — Maximum time to wait for completion.
+    /// * `poll_interval_secs` — Server-side long-poll wait time per request.
+    ///   Defaults to 5 seconds if `None`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = docling_rs::client::DoclingClient::new("http://127.0.0.1:5001");
+    /// let result = client
+    ///     .wait_for_conversion(
+    ///         "https://arxiv.org/pdf/2206.01062",
+    ///         None,
+    ///         Duration::from_secs(300),
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// println!("Status: {:?}", result.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_conversion(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+        timeout: Duration,
+        poll_interval_secs: Option<f64>,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let config = WaitConfig::from_legacy(timeout, poll_interval_secs);
+        self.wait_for_conversion_with(url, options, &config).await
+    }
+
+    /// Like [`wait_for_conversion`](Self::wait_for_conversion), but stops
+    /// waiting with [`DoclingError::Cancelled`] once `cancel` is triggered,
+    /// e.g. when a user clicks "stop".
+    ///
+    /// The token is checked before every status poll, and a poll or pause in
+    /// progress is interrupted. Only the wait ends: the task keeps running
+    /// on the server unless you also call [`cancel_task`](Self::cancel_task)
+    /// with the task id from the error.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # async fn example() -> Result<(), docling_rs::DoclingError> {
+    /// use docling_rs::CancellationToken;
+    ///
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let cancel = CancellationToken::new();
+    /// let stop = cancel.clone(); // call stop.cancel() from another task
+    /// let result = client
+    ///     .wait_for_conversion_cancellable(
+    ///         "https://arxiv.org/pdf/2206.01062",
+    ///         None,
+    ///         Duration::from_secs(300),
+    ///         None,
+    ///         cancel,
+    ///     )
+    ///     .await;
+    /// if let Err(docling_rs::DoclingError::Cancelled { task_id }) = result {
+    ///     client.cancel_task(&task_id).await?;
+    /// }
+    /// # drop(stop);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_conversion_cancellable(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+        timeout: Duration,
+        poll_interval_secs: Option<f64>,
+        cancel: CancellationToken,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let config = WaitConfig::from_legacy(timeout, poll_interval_secs);
+        let task = self.convert_source_async(url, options).await?;
+        let task_id = task.task_id.as_str();
+        self.poll_until_finished(task_id, TaskType::Convert, &config, Some(&cancel), || {
+            self.get_task_result(task_id)
+        })
+        .await
+        .map(|(response, _)| response)
+    }
+
+    /// Submit an async conversion and wait for it using a [`WaitConfig`].
+    ///
+    /// Same as [`wait_for_conversion`](Self::wait_for_conversion), with the
+    /// timeout, poll strategy, and poll limits taken from `config`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// use docling_rs::WaitConfig;
+    ///
+    /// let client = docling_rs::client::DoclingClient::new("http://127.0.0.1:5001");
+    /// let config = WaitConfig::new(Duration::from_secs(600)).max_poll_errors(3);
+    /// let result = client
+    ///     .wait_for_conversion_with("https://arxiv.org/pdf/2206.01062", None, &config)
+    ///     .await?;
+    /// println!("Status: {:?}", result.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_conversion_with(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+        config: &WaitConfig,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let task = self.convert_source_async(url, options).await?;
+        self.poll_until_complete(&task.task_id, config).await
+    }
+
+    /// Like [`wait_for_conversion_with`](Self::wait_for_conversion_with), also
+    /// returning [`WaitStats`] on how many status polls were sent and how
+    /// long the task took to finish after it was submitted.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// use docling_rs::WaitConfig;
+    ///
+    /// let client = docling_rs::client::DoclingClient::new("http://127.0.0.1:5001");
+    /// let (result, stats) = client
+    ///     .wait_for_conversion_detailed(
+    ///         "https://arxiv.org/pdf/2206.01062",
+    ///         None,
+    ///         &WaitConfig::default(),
+    ///     )
+    ///     .await?;
+    /// println!("{} polls over {:?}", stats.polls, stats.waited);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_conversion_detailed(
+        &self,
+        url: &str,
+        options: Option<ConvertDocumentsRequestOptions>,
+        config: &WaitConfig,
+    ) -> Result<(ConvertDocumentResponse, WaitStats), DoclingError> {
+        let task = self.convert_source_async(url, options).await?;
+        self.poll_until_complete_detailed(&task.task_id, config)
+            .await
+    }
+
+    // ========================================================================
+    // Multipart file upload
+    // ========================================================================
+
+    /// Recommend inline (base64 JSON) or multipart upload for a local file.
+    ///
+    /// Reads only the file's metadata. Recommends [`UploadMode::Inline`] if
+    /// the base64-encoded size ([`Source::encoded_size`]) is at most the
+    /// threshold set with
+    /// [`DoclingClientBuilder::inline_upload_threshold`] (10 MiB by default),
+    /// and [`UploadMode::Multipart`] otherwise. Multipart sends the raw bytes,
+    /// so it avoids the ~33% base64 overhead and building a large JSON body
+    /// in memory.
+    pub async fn recommended_upload_mode(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<UploadMode, DoclingError> {
+        let file_len = tokio::fs::metadata(path).await?.len();
+        if Source::encoded_size(file_len) <= self.inline_upload_threshold {
+            Ok(UploadMode::Inline)
+        } else {
+            Ok(UploadMode::Multipart)
+        }
+    }
+
+    /// The text fields [`convert_file`](Self::convert_file) would send
+    /// alongside the uploaded files, as `(name, value)` pairs in send order.
+    ///
+    /// Repeated names are array options, one field per element. Options that
+    /// fail validation return [`DoclingError::InvalidOptions`], as the upload
+    /// itself would.
+    pub fn build_file_form_fields(
+        &self,
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<Vec<(String, String)>, DoclingError> {
+        validate_options(options)?;
+        Ok(form_option_fields(options, target_type))
+    }
+
+    /// Build a `multipart/form-data` form from file paths and conversion options.
+    ///
+    /// Each file is streamed from disk as a binary part named `files`, so
+    /// uploads never hold a whole file in memory. The part length comes from
+    /// the file's metadata when the form is built; a file that changes size
+    /// before it is sent fails the upload. A file whose extension gives no
+    /// MIME type is labeled from its first bytes when they are recognized.
+    /// Each conversion option (if set) is added as a text form field using the
+    /// same field names as the OpenAPI spec. Array fields (e.g. `from_formats`,
+    /// `to_formats`, `ocr_lang`) are sent as repeated form fields, which is how
+    /// FastAPI parses multipart list parameters.
+    ///
+    /// Filenames are sent as UTF-8 in `filename="..."`, as RFC 7578 §4.2
+    /// requires for `multipart/form-data`; the RFC 5987 `filename*=` form is
+    /// not used because that section forbids it and FastAPI decodes the
+    /// UTF-8 name directly. Path names that are not valid UTF-8 are converted
+    /// lossily.
+    ///
+    /// With `progress`, each chunk read from disk is also reported to the
+    /// callback as it is handed to the connection.
+    async fn build_file_multipart(
+        &self,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+        progress: Option<&UploadProgress>,
+    ) -> Result<Form, DoclingError> {
+        let files = file_paths
+            .iter()
+            .map(|path| (path.as_ref(), None))
+            .collect();
+        self.build_multipart_with_mimes(files, options, target_type, progress)
+            .await
+    }
+
+    /// Like [`build_file_multipart`](Self::build_file_multipart), but each
+    /// file may carry an explicit MIME type; `None` guesses it from the
+    /// extension, or from the file's first bytes if the extension is unknown.
+    async fn build_multipart_with_mimes(
+        &self,
+        files: Vec<(&Path, Option<&str>)>,
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+        progress: Option<&UploadProgress>,
+    ) -> Result<Form, DoclingError> {
+        validate_options(options)?;
+        let mut form = Form::new();
+
+        // Attach each file as a binary part streamed from disk
+        for (path, mime) in files {
+            let mut file = tokio::fs::File::open(path).await?;
+            let len = file.metadata().await?.len();
+            let filename = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "file".to_string());
+
+            let mime = match mime {
+                Some(mime) => mime,
+                None => match mime_for_filename(&filename) {
+                    OCTET_STREAM => {
+                        let mut head = Vec::with_capacity(SNIFF_BYTES);
+                        (&mut file)
+                            .take(SNIFF_BYTES as u64)
+                            .read_to_end(&mut head)
+                            .await?;
+                        file.rewind().await?;
+                        content_mime(&head).unwrap_or(OCTET_STREAM)
+                    }
+                    mime => mime,
+                },
+            };
+            let chunks = ReaderStream::with_capacity(file, UPLOAD_CHUNK_BYTES);
+
+            let body = match progress {
+                Some(progress) => progress.body(chunks),
+                None => reqwest::Body::wrap_stream(chunks),
+            };
+            let part = Part::stream_with_length(body, len);
+            form = form.part("files", file_part(part, filename, mime)?);
+        }
+
+        Ok(add_form_options(form, options, target_type))
+    }
+
+    /// Build the multipart form for in-memory files given as
+    /// `(filename, bytes)`, with the same fields as
+    /// [`build_file_multipart`](Self::build_file_multipart).
+    fn build_bytes_multipart(
+        files: impl IntoIterator<Item = (String, Vec<u8>)>,
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<Form, DoclingError> {
+        validate_options(options)?;
+        let mut form = Form::new();
+        for (filename, bytes) in files {
+            let mime = match mime_for_filename(&filename) {
+                OCTET_STREAM => content_mime(&bytes).unwrap_or(OCTET_STREAM),
+                mime => mime,
+            };
+            form = form.part("files", file_part(Part::bytes(bytes), filename, mime)?);
+        }
+        Ok(add_form_options(form, options, target_type))
+    }
+
+    /// Convert one or more local files (synchronous).
+    ///
+    /// Reads each file from disk and uploads via `multipart/form-data`.
+    /// The call blocks (async) until conversion is complete.
+    ///
+    /// `POST /v1/convert/file`
+    ///
+    /// # Arguments
+    /// * `file_paths` — One or more local file paths to convert.
+    /// * `options` — Optional conversion options. Pass `None` for server defaults.
+    /// * `target_type` — Optional target type. Pass `None` for default (in-body).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = docling_rs::client::DoclingClient::new("http://127.0.0.1:5001");
+    /// let result = client
+    ///     .convert_file(&["./document.pdf"], None, None)
+    ///     .await?;
+    /// if let Some(md) = &result.document.md_content {
+    ///     println!("{}", md);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_file(
+        &self,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let resp = self
+            .send_convert_file(file_paths, options, target_type)
+            .await?;
+        let body = self.read_json::<ConvertDocumentResponse>(resp).await?;
+        Ok(body)
+    }
+
+    /// Convert local files and also return the response headers.
+    ///
+    /// `POST /v1/convert/file`
+    ///
+    /// Behaves like [`convert_file`](Self::convert_file); see
+    /// [`convert_source_with_headers`](Self::convert_source_with_headers).
+    pub async fn convert_file_with_headers(
+        &self,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<(ConvertDocumentResponse, reqwest::header::HeaderMap), DoclingError> {
+        let resp = self
+            .send_convert_file(file_paths, options, target_type)
+            .await?;
+        let headers = resp.headers().clone();
+        let body = self.read_json::<ConvertDocumentResponse>(resp).await?;
+        Ok((body, headers))
+    }
+
+    /// Upload files to `POST /v1/convert/file` and return the successful
+    /// response, before its body is read.
+    async fn send_convert_file(
+        &self,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<reqwest::Response, DoclingError> {
+        ensure_json_target(target_type == Some(&TargetName::Zip), "convert_file_zip")?;
+        let form = self
+            .build_file_multipart(file_paths, options, target_type, None)
+            .await?;
+
+        let req = self
+            .auth(self.http.post(self.url("/v1/convert/file")).multipart(form))
+            .await?;
+
+        let resp = self.send(req).await?;
+        self.handle_response(resp).await
+    }
+
+    /// Convert local files, giving each one's MIME type explicitly instead of
+    /// guessing it from the extension.
+    ///
+    /// `POST /v1/convert/file`
+    ///
+    /// Useful for staged files without a meaningful extension. Otherwise
+    /// behaves like [`convert_file`](Self::convert_file). A MIME string that
+    /// does not parse returns [`DoclingError::InvalidMime`] before anything
+    /// is sent.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use std::path::PathBuf;
+    /// # async fn example() -> Result<(), docling_rs::DoclingError> {
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let files = [(PathBuf::from("/tmp/upload-3f2a"), "application/pdf")];
+    /// let result = client.convert_file_with_mimes(&files, None, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_file_with_mimes(
+        &self,
+        files: &[(PathBuf, &str)],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        ensure_json_target(target_type == Some(&TargetName::Zip), "convert_file_zip")?;
+        let files = files
+            .iter()
+            .map(|(path, mime)| (path.as_path(), Some(*mime)))
+            .collect();
+        let form = self
+            .build_multipart_with_mimes(files, options, target_type, None)
+            .await?;
+
+        let req = self
+            .auth(self.http.post(self.url("/v1/convert/file")).multipart(form))
+            .await?;
+
+        let resp = self.send(req).await?;
+        let resp = self.handle_response(resp).await?;
+        let body = self.read_json::<ConvertDocumentResponse>(resp).await?;
+        Ok(body)
+    }
+
+    /// Convert local files with the `zip` target and return the raw ZIP
+    /// archive.
+    ///
+    /// `POST /v1/convert/file`
+    ///
+    /// Uploads like [`convert_file`](Self::convert_file) with `target_type`
+    /// set to [`TargetName::Zip`].
+    pub async fn convert_file_zip(
+        &self,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+    ) -> Result<Vec<u8>, DoclingError> {
+        let form = self
+            .build_file_multipart(file_paths, options, Some(&TargetName::Zip), None)
+            .await?;
+
+        let req = self
+            .auth(self.http.post(self.url("/v1/convert/file")).multipart(form))
+            .await?;
+
+        let resp = self.send(req).await?;
+        let resp = self.handle_response(resp).await?;
+        Ok(resp.bytes().await?.to_vec())
+    }
+
+    /// Convert local files like [`convert_file`](Self::convert_file),
+    /// reporting upload progress.
+    ///
+    /// `POST /v1/convert/file`
+    ///
+    /// `on_upload_progress(sent, total)` is called as file bytes are streamed
+    /// to the connection, so it tracks the actual upload rather than the disk
+    /// read. `sent` counts file content only (not multipart framing or form
+    /// fields) and `total` is the combined size of all files. `total` is
+    /// `None` only for sources of unknown length, which cannot happen for
+    /// files on disk.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = docling_rs::client::DoclingClient::new("http://127.0.0.1:5001");
+    /// let result = client
+    ///     .convert_file_with_progress(&["./scan.pdf"], None, None, |sent, total| {
+    ///         if let Some(total) = total {
+    ///             println!("uploaded {sent}/{total} bytes");
+    ///         }
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_file_with_progress(
+        &self,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+        on_upload_progress: impl FnMut(u64, Option<u64>) + Send + 'static,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        ensure_json_target(target_type == Some(&TargetName::Zip), "convert_file_zip")?;
+        let mut total = 0;
+        for path in file_paths {
+            total += tokio::fs::metadata(path.as_ref()).await?.len();
         }
+        let progress = UploadProgress::new(Some(total), on_upload_progress);
+        let form = self
+            .build_file_multipart(file_paths, options, target_type, Some(&progress))
+            .await?;
+
+        let req = self
+            .auth(self.http.post(self.url("/v1/convert/file")).multipart(form))
+            .await?;
+
+        let resp = self.send(req).await?;
+        let resp = self.handle_response(resp).await?;
+        let body = self.read_json::<ConvertDocumentResponse>(resp).await?;
+        Ok(body)
+    }
+
+    /// Submit one or more local files for asynchronous conversion.
+    ///
+    /// Returns a `TaskStatusResponse` containing the `task_id` which can be
+    /// used to poll for status and retrieve results.
+    ///
+    /// `POST /v1/convert/file/async`
+    ///
+    /// # Arguments
+    /// * `file_paths` — One or more local file paths to convert.
+    /// * `options` — Optional conversion options. Pass `None` for server defaults.
+    /// * `target_type` — Optional target type. Pass `None` for default (in-body).
+    pub async fn convert_file_async(
+        &self,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<TaskStatusResponse, DoclingError> {
+        let form = self
+            .build_file_multipart(file_paths, options, target_type, None)
+            .await?;
+
+        let req = self
+            .auth(
+                self.http
+                    .post(self.url("/v1/convert/file/async"))
+                    .multipart(form),
+            )
+            .await?;
+
+        let resp = self.send_retrying(req).await?;
+        let resp = self.handle_response(resp).await?;
+        let body = self.read_json::<TaskStatusResponse>(resp).await?;
+        Ok(body)
+    }
+
+    /// Submit local files for asynchronous conversion and return a
+    /// [`TaskHandle`].
+    ///
+    /// `POST /v1/convert/file/async`
+    pub async fn submit_file(
+        &self,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<TaskHandle, DoclingError> {
+        let task = self
+            .convert_file_async(file_paths, options, target_type)
+            .await?;
+        Ok(TaskHandle::new(self.clone(), task.task_id))
+    }
+
+    /// Convert a document held in memory (synchronous).
+    ///
+    /// Uploads `bytes` via `multipart/form-data` exactly like
+    /// [`convert_file`](Self::convert_file), without touching the disk.
+    /// The MIME type is guessed from `filename`'s extension, then from the
+    /// content (see [`detect_input_format`](crate::detect_input_format)),
+    /// falling back to `application/octet-stream`.
+    ///
+    /// `POST /v1/convert/file`
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example(pdf: Vec<u8>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let result = client.convert_bytes("report.pdf", pdf, None, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_bytes(
+        &self,
+        filename: &str,
+        bytes: Vec<u8>,
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        ensure_json_target(target_type == Some(&TargetName::Zip), "convert_file_zip")?;
+        let form =
+            Self::build_bytes_multipart([(filename.to_string(), bytes)], options, target_type)?;
 
-        let req = self.auth(self.http.get(&url));
-        let resp = req.send().await?;
+        let req = self
+            .auth(self.http.post(self.url("/v1/convert/file")).multipart(form))
+            .await?;
+
+        let resp = self.send(req).await?;
         let resp = self.handle_response(resp).await?;
-        let body = resp.json::<TaskStatusResponse>().await?;
+        let body = self.read_json::<ConvertDocumentResponse>(resp).await?;
         Ok(body)
     }
 
-    /// Retrieve the result of a completed async task.
+    /// Submit a document held in memory for asynchronous conversion.
     ///
-    /// `GET /v1/result/{task_id}`
+    /// See [`convert_bytes`](Self::convert_bytes) for how the upload is built.
     ///
-    /// This should only be called after `poll_task_status` indicates the
-    /// task has completed (status = "SUCCESS").
-    pub async fn get_task_result(
+    /// `POST /v1/convert/file/async`
+    pub async fn convert_bytes_async(
         &self,
-        task_id: &str,
-    ) -> Result<ConvertDocumentResponse, DoclingError> {
-        let req = self.auth(
-            self.http
-                .get(self.url(&format!("/v1/result/{}", task_id))),
-        );
+        filename: &str,
+        bytes: Vec<u8>,
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<TaskStatusResponse, DoclingError> {
+        let form =
+            Self::build_bytes_multipart([(filename.to_string(), bytes)], options, target_type)?;
+
+        let req = self
+            .auth(
+                self.http
+                    .post(self.url("/v1/convert/file/async"))
+                    .multipart(form),
+            )
+            .await?;
 
-        let resp = req.send().await?;
+        let resp = self.send_retrying(req).await?;
         let resp = self.handle_response(resp).await?;
-        let body = resp.json::<ConvertDocumentResponse>().await?;
+        let body = self.read_json::<TaskStatusResponse>(resp).await?;
         Ok(body)
     }
 
     // ========================================================================
-    // Convenience: submit URL + wait
+    // Mixed inputs
     // ========================================================================
 
-    /// Submit an async conversion and wait for it to complete.
+    /// Convert any mix of URLs, local files, and in-memory documents
+    /// (synchronous).
     ///
-    /// This is a convenience method that combines `convert_source_async`,
-    /// polling via `poll_task_status`, and `get_task_result` into a single
-    /// call. The method polls using server-side long-polling for efficiency.
+    /// Inputs are routed to one request:
+    ///
+    /// - only [`Input::Url`]s: sent as HTTP sources to
+    ///   `POST /v1/convert/source`, like [`convert`](Self::convert);
+    /// - only [`Input::Path`]s and [`Input::Bytes`]: uploaded as
+    ///   `multipart/form-data` to `POST /v1/convert/file`, like
+    ///   [`convert_file`](Self::convert_file);
+    /// - URLs mixed with files: every file is read into memory and
+    ///   base64-encoded as a [`Source::File`] next to the URLs, and the whole
+    ///   batch goes to `POST /v1/convert/source`. Base64 adds about a third
+    ///   to the upload size, so keep large files in a separate call.
+    ///
+    /// No inputs, a ZIP target, or conflicting options return
+    /// [`DoclingError::InvalidOptions`] before anything is sent; unreadable
+    /// paths return [`DoclingError::Io`].
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example(pdf: Vec<u8>) -> Result<(), docling_rs::DoclingError> {
+    /// use docling_rs::Input;
+    ///
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let inputs = vec![
+    ///     Input::url("https://arxiv.org/pdf/2206.01062"),
+    ///     Input::path("./report.pdf"),
+    ///     Input::bytes("scan.pdf", pdf),
+    /// ];
+    /// let result = client.convert_input(inputs, None, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_input(
+        &self,
+        inputs: Vec<Input>,
+        options: Option<ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        ensure_json_target(target_type == Some(&TargetName::Zip), "convert_zip")?;
+        validate_options(options.as_ref())?;
+        if inputs.is_empty() {
+            return Err(DoclingError::InvalidOptions {
+                reason: "no sources provided".to_string(),
+            });
+        }
+
+        let has_urls = inputs.iter().any(|input| matches!(input, Input::Url(_)));
+        let has_files = inputs.iter().any(|input| !matches!(input, Input::Url(_)));
+        if has_files && !has_urls {
+            let mut files = Vec::with_capacity(inputs.len());
+            for input in inputs {
+                files.push(input.into_named_bytes().await?);
+            }
+            let form = Self::build_bytes_multipart(files, options.as_ref(), target_type)?;
+            let req = self
+                .auth(self.http.post(self.url("/v1/convert/file")).multipart(form))
+                .await?;
+
+            let resp = self.send(req).await?;
+            let resp = self.handle_response(resp).await?;
+            return self.read_json::<ConvertDocumentResponse>(resp).await;
+        }
+
+        let mut sources = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            sources.push(input.into_source().await?);
+        }
+        let request = ConvertDocumentsRequest {
+            sources,
+            options,
+            target: None,
+        };
+        self.convert(&request).await
+    }
+
+    // ========================================================================
+    // Convenience: submit file + wait
+    // ========================================================================
+
+    /// Submit local files for async conversion and wait for completion.
+    ///
+    /// Convenience method that combines `convert_file_async`, polling via
+    /// `poll_task_status`, and `get_task_result` into a single call.
     ///
     /// # Arguments
-    /// * `url` — The HTTP URL of the document to convert.
+    /// * `file_paths` — One or more local file paths to convert.
     /// * `options` — Optional conversion options.
+    /// * `target_type` — Optional target type. Pass `None` for default (in-body).
     /// * `timeout` — Maximum time to wait for completion.
     /// * `poll_interval_secs` — Server-side long-poll wait time per request.
     ///   Defaults to 5 seconds if `None`.
@@ -345,8 +2524,9 @@ impl DoclingClient {
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = docling_rs::client::DoclingClient::new("http://127.0.0.1:5001");
     /// let result = client
-    ///     .wait_for_conversion(
-    ///         "https://arxiv.org/pdf/2206.01062",
+    ///     .wait_for_file_conversion(
+    ///         &["./document.pdf"],
+    ///         None,
     ///         None,
     ///         Duration::from_secs(300),
     ///         None,
@@ -356,326 +2536,942 @@ impl DoclingClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn wait_for_conversion(
+    pub async fn wait_for_file_conversion(
         &self,
-        url: &str,
-        options: Option<ConvertDocumentsRequestOptions>,
+        file_paths: &[impl AsRef<Path>],
+        options: Option<&ConvertDocumentsRequestOptions>,
+        target_type: Option<&TargetName>,
         timeout: Duration,
         poll_interval_secs: Option<f64>,
     ) -> Result<ConvertDocumentResponse, DoclingError> {
-        let task = self.convert_source_async(url, options).await?;
-        self.poll_until_complete(&task.task_id, timeout, poll_interval_secs)
+        let config = WaitConfig::from_legacy(timeout, poll_interval_secs);
+        self.wait_for_file_conversion_with(file_paths, options, target_type, &config)
             .await
     }
 
-    // ========================================================================
-    // Multipart file upload
-    // ========================================================================
-
-    /// Build a `multipart/form-data` form from file paths and conversion options.
+    /// Submit local files for async conversion and wait using a [`WaitConfig`].
     ///
-    /// Each file is read from disk and attached as a binary part named `files`.
-    /// Each conversion option (if set) is added as a text form field using the
-    /// same field names as the OpenAPI spec. Array fields (e.g. `from_formats`,
-    /// `to_formats`, `ocr_lang`) are sent as repeated form fields, which is how
-    /// FastAPI parses multipart list parameters.
-    async fn build_file_multipart(
+    /// Same as [`wait_for_file_conversion`](Self::wait_for_file_conversion),
+    /// with the timeout, poll strategy, and poll limits taken from `config`.
+    pub async fn wait_for_file_conversion_with(
         &self,
         file_paths: &[impl AsRef<Path>],
         options: Option<&ConvertDocumentsRequestOptions>,
         target_type: Option<&TargetName>,
-    ) -> Result<Form, DoclingError> {
-        let mut form = Form::new();
+        config: &WaitConfig,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        let task = self
+            .convert_file_async(file_paths, options, target_type)
+            .await?;
+        self.poll_until_complete(&task.task_id, config).await
+    }
+
+    // ========================================================================
+    // Chunking
+    // ========================================================================
+
+    /// Convert a document and split it into chunks with the hybrid chunker.
+    ///
+    /// `POST /v1/chunk/hybrid/source`
+    ///
+    /// The document is converted with the server's default options. Use
+    /// [`chunk`](Self::chunk) to also pass conversion options or several
+    /// sources.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example() -> Result<(), docling_rs::DoclingError> {
+    /// use docling_rs::{ChunkingOptions, Source};
+    ///
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let source = Source::Http {
+    ///     url: "https://arxiv.org/pdf/2206.01062".to_string(),
+    ///     headers: None,
+    /// };
+    /// let options = ChunkingOptions {
+    ///     max_tokens: Some(256),
+    ///     ..Default::default()
+    /// };
+    /// let response = client.chunk_hybrid(source, options).await?;
+    /// for chunk in &response.chunks {
+    ///     println!("{}", chunk.serialize_for_embedding(true));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn chunk_hybrid(
+        &self,
+        source: Source,
+        options: ChunkingOptions,
+    ) -> Result<ChunkDocumentResponse, DoclingError> {
+        self.chunk(&chunk_request(source, options)).await
+    }
+
+    /// Submit a document for asynchronous hybrid chunking.
+    ///
+    /// `POST /v1/chunk/hybrid/source/async`
+    ///
+    /// Returns a [`TaskStatusResponse`] whose `task_type` is
+    /// [`TaskType::Chunk`]; poll it like a conversion task.
+    pub async fn chunk_hybrid_async(
+        &self,
+        source: Source,
+        options: ChunkingOptions,
+    ) -> Result<TaskStatusResponse, DoclingError> {
+        self.chunk_async(&chunk_request(source, options)).await
+    }
+
+    /// Convert and chunk documents from a full request (synchronous).
+    ///
+    /// `POST /v1/chunk/hybrid/source`
+    ///
+    /// A request with no sources or conflicting conversion options is
+    /// rejected locally with [`DoclingError::InvalidOptions`].
+    pub async fn chunk(
+        &self,
+        request: &ChunkDocumentsRequest,
+    ) -> Result<ChunkDocumentResponse, DoclingError> {
+        ensure_valid_chunk_request(request)?;
+        let req = self
+            .auth(self.json_body(self.http.post(self.url("/v1/chunk/hybrid/source")), request))
+            .await?;
+
+        let resp = self.send(req).await?;
+        let resp = self.handle_response(resp).await?;
+        self.read_json::<ChunkDocumentResponse>(resp).await
+    }
+
+    /// Submit a full chunking request for asynchronous processing.
+    ///
+    /// `POST /v1/chunk/hybrid/source/async`
+    pub async fn chunk_async(
+        &self,
+        request: &ChunkDocumentsRequest,
+    ) -> Result<TaskStatusResponse, DoclingError> {
+        ensure_valid_chunk_request(request)?;
+        let req = self
+            .auth(self.json_body(
+                self.http.post(self.url("/v1/chunk/hybrid/source/async")),
+                request,
+            ))
+            .await?;
+
+        let resp = self.send_retrying(req).await?;
+        let resp = self.handle_response(resp).await?;
+        self.read_json::<TaskStatusResponse>(resp).await
+    }
+
+    /// Submit a document for hybrid chunking and wait for the chunks.
+    ///
+    /// Combines [`chunk_hybrid_async`](Self::chunk_hybrid_async) and
+    /// [`get_chunk_result`](Self::get_chunk_result), polling as configured by
+    /// `config` like [`wait_for_conversion_with`](Self::wait_for_conversion_with).
+    pub async fn wait_for_chunking(
+        &self,
+        source: Source,
+        options: ChunkingOptions,
+        config: &WaitConfig,
+    ) -> Result<ChunkDocumentResponse, DoclingError> {
+        let task = self.chunk_hybrid_async(source, options).await?;
+        let task_id = task.task_id.as_str();
+        self.poll_until_finished(task_id, TaskType::Chunk, config, None, || {
+            self.get_chunk_result(task_id)
+        })
+        .await
+        .map(|(response, _)| response)
+    }
+
+    // ========================================================================
+    // Raw access
+    // ========================================================================
+
+    /// **Advanced, unstable:** `GET` an arbitrary path on the server and
+    /// return the raw response.
+    ///
+    /// For endpoints this SDK does not wrap yet. `path` is appended to the
+    /// base URL (a missing leading `/` is added), the API key, default
+    /// headers, timeout, and retry policy apply as for any other call, and a
+    /// non-success status returns [`DoclingError::Api`] (or
+    /// [`DoclingError::Validation`]). Prefer a typed method when one exists;
+    /// this escape hatch may change as endpoints get proper support.
+    ///
+    /// ```rust,no_run
+    /// # async fn example() -> Result<(), docling_rs::DoclingError> {
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let resp = client.get_raw("/v1/memory/stats").await?;
+    /// let stats: serde_json::Value = resp.json().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_raw(&self, path: &str) -> Result<reqwest::Response, DoclingError> {
+        let req = self.auth(self.http.get(self.raw_url(path))).await?;
+        let resp = self.send_retrying(req).await?;
+        self.handle_response(resp).await
+    }
+
+    /// **Advanced, unstable:** `POST` a JSON body to an arbitrary path on the
+    /// server and return the raw response.
+    ///
+    /// See [`get_raw`](Self::get_raw). The body is serialized like the
+    /// typed requests, including gzip with
+    /// [`DoclingClientBuilder::compress_requests`].
+    pub async fn post_raw_json<B: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<reqwest::Response, DoclingError> {
+        let req = self
+            .auth(self.json_body(self.http.post(self.raw_url(path)), body))
+            .await?;
+        let resp = self.send(req).await?;
+        self.handle_response(resp).await
+    }
+
+    /// **Advanced, unstable:** `GET` an arbitrary path and deserialize the
+    /// JSON response into `T`.
+    ///
+    /// Typed counterpart of [`get_raw`](Self::get_raw), for calling
+    /// endpoints this SDK does not wrap yet. A non-success status returns
+    /// [`DoclingError::Api`] and a body that does not match `T` returns
+    /// [`DoclingError::Json`].
+    ///
+    /// ```rust,no_run
+    /// # async fn example() -> Result<(), docling_rs::DoclingError> {
+    /// #[derive(serde::Deserialize)]
+    /// struct MemoryStats {
+    ///     rss: u64,
+    /// }
+    ///
+    /// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+    /// let stats: MemoryStats = client.get_json("/v1/memory/stats").await?;
+    /// println!("rss: {}", stats.rss);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T, DoclingError> {
+        let resp = self.get_raw(path).await?;
+        self.read_json(resp).await
+    }
+
+    /// **Advanced, unstable:** `POST` a JSON body to an arbitrary path and
+    /// deserialize the JSON response into `T`.
+    ///
+    /// Typed counterpart of [`post_raw_json`](Self::post_raw_json); see
+    /// [`get_json`](Self::get_json).
+    pub async fn post_json<B: Serialize + ?Sized, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T, DoclingError> {
+        let resp = self.post_raw_json(path, body).await?;
+        self.read_json(resp).await
+    }
+
+    /// Build a URL for a caller-supplied path, adding the leading `/` if
+    /// missing.
+    fn raw_url(&self, path: &str) -> String {
+        if path.starts_with('/') {
+            self.url(path)
+        } else {
+            self.url(&format!("/{}", path))
+        }
+    }
+}
+
+/// Stream `document.md_content` out of a conversion response body into
+/// `writer`, returning the number of bytes written.
+async fn write_markdown(
+    mut resp: reqwest::Response,
+    mut writer: impl std::io::Write,
+) -> Result<u64, DoclingError> {
+    let mut extractor = StringFieldExtractor::new(&["document", "md_content"]);
+    let mut decoded = Vec::new();
+    let mut written = 0;
+    while let Some(chunk) = resp.chunk().await? {
+        decoded.clear();
+        extractor.feed(&chunk, &mut decoded)?;
+        writer.write_all(&decoded)?;
+        written += decoded.len() as u64;
+    }
+    writer.flush()?;
+    if !extractor.found() {
+        return Err(DoclingError::MissingContent {
+            format: OutputFormat::Md,
+        });
+    }
+    Ok(written)
+}
+
+/// The cache key for [`DoclingClient::convert_source_memoized`]: the inputs
+/// serialized in full, so distinct requests never share an entry.
+#[cfg(feature = "cache")]
+fn memo_key(
+    url: &str,
+    options: Option<&ConvertDocumentsRequestOptions>,
+) -> Result<String, DoclingError> {
+    Ok(serde_json::to_string(&(url, options))?)
+}
+
+/// Size of the chunks file parts are read from disk in.
+const UPLOAD_CHUNK_BYTES: usize = 64 * 1024;
+
+type ProgressCallback = dyn FnMut(u64, Option<u64>) + Send;
+
+/// Shared upload progress state for all file parts of one request.
+#[derive(Clone)]
+struct UploadProgress {
+    sent: Arc<AtomicU64>,
+    total: Option<u64>,
+    callback: Arc<Mutex<ProgressCallback>>,
+}
+
+impl UploadProgress {
+    fn new(total: Option<u64>, callback: impl FnMut(u64, Option<u64>) + Send + 'static) -> Self {
+        Self {
+            sent: Arc::new(AtomicU64::new(0)),
+            total,
+            callback: Arc::new(Mutex::new(callback)),
+        }
+    }
+
+    /// Wrap a stream of file chunks in a body that reports every chunk.
+    fn body(
+        &self,
+        chunks: impl Stream<Item = std::io::Result<bytes::Bytes>> + Send + 'static,
+    ) -> reqwest::Body {
+        let progress = self.clone();
+        let chunks = chunks.inspect(move |chunk| {
+            if let Ok(chunk) = chunk {
+                let sent = progress
+                    .sent
+                    .fetch_add(chunk.len() as u64, Ordering::Relaxed)
+                    + chunk.len() as u64;
+                (progress.callback.lock().unwrap())(sent, progress.total);
+            }
+        });
+        reqwest::Body::wrap_stream(chunks)
+    }
+}
+
+/// Run `fut` to completion, or return `None` as soon as `cancel` is
+/// triggered.
+async fn unless_cancelled<F: Future>(
+    cancel: Option<&CancellationToken>,
+    fut: F,
+) -> Option<F::Output> {
+    match cancel {
+        Some(cancel) => cancel.run_until_cancelled(fut).await,
+        None => Some(fut.await),
+    }
+}
+
+/// Append `target_type` and the conversion options to a multipart form as
+/// flat form fields.
+fn add_form_options(
+    form: Form,
+    options: Option<&ConvertDocumentsRequestOptions>,
+    target_type: Option<&TargetName>,
+) -> Form {
+    form_option_fields(options, target_type)
+        .into_iter()
+        .fold(form, |form, (name, value)| form.text(name, value))
+}
+
+/// `target_type` followed by the conversion options, as multipart fields.
+fn form_option_fields(
+    options: Option<&ConvertDocumentsRequestOptions>,
+    target_type: Option<&TargetName>,
+) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    if let Some(tt) = target_type {
+        fields.push(("target_type".to_string(), tt.to_string()));
+    }
+    if let Some(opts) = options {
+        fields.extend(form_fields(opts));
+    }
+    fields
+}
+
+/// The conversion options as multipart form fields, derived from their JSON
+/// serialization so the two encodings cannot drift apart.
+///
+/// Arrays become repeated fields, as FastAPI expects; objects (model
+/// options) are sent as JSON strings. Unset options are skipped.
+fn form_fields(options: &ConvertDocumentsRequestOptions) -> Vec<(String, String)> {
+    // Options hold only strings, numbers, enums and JSON values, so this
+    // cannot fail.
+    let serde_json::Value::Object(map) =
+        serde_json::to_value(options).expect("options serialize to JSON")
+    else {
+        unreachable!("options serialize to a JSON object");
+    };
+
+    let mut fields = Vec::new();
+    for (name, value) in map {
+        match value {
+            serde_json::Value::Array(items) => {
+                for item in &items {
+                    fields.push((name.clone(), form_value(item)));
+                }
+            }
+            value => fields.push((name, form_value(&value))),
+        }
+    }
+    fields
+}
+
+/// One form field value. Floats are formatted as Rust does (`2` rather than
+/// `2.0`), matching how the fields were sent before they were derived from
+/// serde.
+///
+/// Booleans are sent as lowercase `true`/`false`. FastAPI parses form fields
+/// with pydantic, which accepts these (as well as `1`/`0`) for `bool`
+/// parameters but rejects other spellings such as Python's `True`.
+fn form_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Bool(true) => "true".to_string(),
+        serde_json::Value::Bool(false) => "false".to_string(),
+        serde_json::Value::Number(n) if n.is_f64() => n.as_f64().unwrap_or_default().to_string(),
+        value => value.to_string(),
+    }
+}
+
+/// The `Retry-After` delay of a response, given either in seconds or as an
+/// HTTP date. A date in the past yields a zero delay.
+fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(std::time::SystemTime::now())
+            .unwrap_or_default(),
+    )
+}
+
+/// Build the `reqwest` client used when none is supplied to the builder.
+///
+/// Sets [`DEFAULT_USER_AGENT`]. Uses rustls when the `rustls-tls` feature
+/// is enabled, even if `native-tls` is enabled too. Panics if the TLS
+/// backend cannot be initialized, as [`reqwest::Client::new`] does.
+fn build_http_client(proxies: Vec<reqwest::Proxy>, accept_invalid_certs: bool) -> reqwest::Client {
+    let builder = reqwest::Client::builder().user_agent(DEFAULT_USER_AGENT);
+    #[cfg(feature = "rustls-tls")]
+    let builder = builder.tls_backend_rustls();
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    let builder = builder.tls_danger_accept_invalid_certs(accept_invalid_certs);
+    #[cfg(not(any(feature = "rustls-tls", feature = "native-tls")))]
+    let _ = accept_invalid_certs;
+    proxies
+        .into_iter()
+        .fold(builder, |builder, proxy| builder.proxy(proxy))
+        .build()
+        .expect("failed to build the HTTP client")
+}
+
+/// An `Authorization: Bearer` value for a token from a [`TokenProvider`],
+/// marked sensitive so it is left out of debug output.
+fn bearer_header(token: &str) -> Result<reqwest::header::HeaderValue, DoclingError> {
+    let mut value =
+        reqwest::header::HeaderValue::try_from(format!("Bearer {}", token)).map_err(|_| {
+            DoclingError::InvalidOptions {
+                reason: "token provider returned a token that is not a valid header value"
+                    .to_string(),
+            }
+        })?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+/// Percent-encode a value for use as a single URL path segment.
+///
+/// Everything but RFC 3986 unreserved characters is encoded, so a `/`, `?`,
+/// or space in a task id cannot change which endpoint is requested. An empty
+/// value, `.`, or `..` is rejected with [`DoclingError::InvalidOptions`]:
+/// URL parsers resolve `.` and `..` segments even when percent-encoded, so
+/// no encoding keeps them in place.
+fn encode_path_segment(segment: &str) -> Result<String, DoclingError> {
+    if matches!(segment, "" | "." | "..") {
+        return Err(DoclingError::InvalidOptions {
+            reason: format!("{:?} is not a valid task id", segment),
+        });
+    }
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    Ok(encoded)
+}
+
+/// Name a file part and set its MIME type, rejecting a MIME string that does
+/// not parse instead of panicking.
+fn file_part(part: Part, filename: String, mime: &str) -> Result<Part, DoclingError> {
+    part.file_name(filename)
+        .mime_str(mime)
+        .map_err(|_| DoclingError::InvalidMime(mime.to_string()))
+}
+
+/// The MIME type sent for files of unknown type.
+const OCTET_STREAM: &str = "application/octet-stream";
+
+/// Guess a MIME type from a filename's extension, ignoring case, falling
+/// back to `application/octet-stream`. Callers with the file's content then
+/// try [`content_mime`] before settling for the fallback.
+fn mime_for_filename(filename: &str) -> &'static str {
+    let extension = Path::new(filename)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("pdf") => "application/pdf",
+        Some("docx") => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        Some("pptx") => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        Some("xlsx") => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        Some("html") | Some("htm") => "text/html",
+        Some("md") => "text/markdown",
+        Some("csv") => "text/csv",
+        Some("json") => "application/json",
+        Some("xml") => "application/xml",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("tiff") | Some("tif") => "image/tiff",
+        Some("bmp") => "image/bmp",
+        Some("webp") => "image/webp",
+        Some("mp3") => "audio/mpeg",
+        Some("wav") => "audio/wav",
+        Some("vtt") => "text/vtt",
+        Some("txt") => "text/plain",
+        Some("epub") => "application/epub+zip",
+        Some("rtf") => "application/rtf",
+        Some("odt") => "application/vnd.oasis.opendocument.text",
+        _ => OCTET_STREAM,
+    }
+}
+
+/// Gzip-compress a request body.
+fn gzip(bytes: &[u8]) -> Vec<u8> {
+    use std::io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    // Writing to a Vec cannot fail.
+    encoder.write_all(bytes).expect("gzip into Vec");
+    encoder.finish().expect("gzip into Vec")
+}
+
+/// Parse `Content-Range: bytes <start>-<end>/<total>` into the start offset
+/// and total size (`None` when the total is `*`).
+fn parse_content_range(value: &str) -> Option<(u64, Option<u64>)> {
+    let (span, total) = value.trim().strip_prefix("bytes ")?.split_once('/')?;
+    let (start, _end) = span.split_once('-')?;
+    let start = start.trim().parse().ok()?;
+    let total = match total.trim() {
+        "*" => None,
+        total => Some(total.parse().ok()?),
+    };
+    Some((start, total))
+}
+
+/// Reject requests without sources or with conflicting options before they
+/// reach the server, which would answer with a less helpful `422`.
+fn ensure_valid_request(request: &ConvertDocumentsRequest) -> Result<(), DoclingError> {
+    if request.sources.is_empty() {
+        return Err(DoclingError::InvalidOptions {
+            reason: "no sources provided".to_string(),
+        });
+    }
+    validate_options(request.options.as_ref())
+}
+
+/// A single-source hybrid chunking request with default conversion options.
+fn chunk_request(source: Source, options: ChunkingOptions) -> ChunkDocumentsRequest {
+    ChunkDocumentsRequest {
+        sources: vec![source],
+        convert_options: None,
+        chunking_options: Some(options),
+        include_converted_doc: None,
+    }
+}
+
+/// [`ensure_valid_request`] for chunking requests.
+fn ensure_valid_chunk_request(request: &ChunkDocumentsRequest) -> Result<(), DoclingError> {
+    if request.sources.is_empty() {
+        return Err(DoclingError::InvalidOptions {
+            reason: "no sources provided".to_string(),
+        });
+    }
+    validate_options(request.convert_options.as_ref())
+}
+
+/// Run [`ConvertDocumentsRequestOptions::validate`] on options, if any.
+fn validate_options(options: Option<&ConvertDocumentsRequestOptions>) -> Result<(), DoclingError> {
+    options.map_or(Ok(()), ConvertDocumentsRequestOptions::validate)
+}
+
+/// Whether a response carries a ZIP archive instead of JSON.
+fn is_zip_response(resp: &reqwest::Response) -> bool {
+    resp.headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("application/zip"))
+}
 
-        // Attach each file as a binary part
-        for path in file_paths {
-            let path = path.as_ref();
-            let bytes = tokio::fs::read(path).await.map_err(DoclingError::Io)?;
-            let filename = path
-                .file_name()
-                .map(|n| n.to_string_lossy().into_owned())
-                .unwrap_or_else(|| "file".to_string());
+/// Reject a ZIP target on a method that parses a JSON response, pointing at
+/// the method that returns the archive instead.
+fn ensure_json_target(is_zip: bool, zip_method: &str) -> Result<(), DoclingError> {
+    if is_zip {
+        return Err(target_mismatch("zip", zip_method));
+    }
+    Ok(())
+}
 
-            // Guess MIME type from extension
-            let mime = match path.extension().and_then(|e| e.to_str()) {
-                Some("pdf") => "application/pdf",
-                Some("docx") => {
-                    "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
-                }
-                Some("pptx") => {
-                    "application/vnd.openxmlformats-officedocument.presentationml.presentation"
-                }
-                Some("xlsx") => {
-                    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
-                }
-                Some("html") | Some("htm") => "text/html",
-                Some("md") => "text/markdown",
-                Some("csv") => "text/csv",
-                Some("json") => "application/json",
-                Some("xml") => "application/xml",
-                Some("png") => "image/png",
-                Some("jpg") | Some("jpeg") => "image/jpeg",
-                Some("tiff") | Some("tif") => "image/tiff",
-                Some("bmp") => "image/bmp",
-                Some("webp") => "image/webp",
-                Some("mp3") => "audio/mpeg",
-                Some("wav") => "audio/wav",
-                Some("vtt") => "text/vtt",
-                _ => "application/octet-stream",
-            };
+/// Error for a target whose response is not a `ConvertDocumentResponse`.
+fn target_mismatch(target: &str, method: &str) -> DoclingError {
+    DoclingError::InvalidOptions {
+        reason: format!(
+            "the {} target does not return the converted document as JSON; use {} instead",
+            target, method
+        ),
+    }
+}
 
-            let part = Part::bytes(bytes)
-                .file_name(filename)
-                .mime_str(mime)
-                .unwrap();
-            form = form.part("files", part);
+/// Execute a built request inside a `docling.request` span recording the
+/// method, endpoint path, and response status. Bodies are never recorded
+/// here; see `log_bodies`.
+#[cfg(feature = "tracing")]
+async fn execute(
+    http: &reqwest::Client,
+    request: reqwest::Request,
+) -> Result<reqwest::Response, DoclingError> {
+    let span = tracing::debug_span!(
+        "docling.request",
+        method = %request.method(),
+        endpoint = request.url().path(),
+        status_code = tracing::field::Empty,
+    );
+    let started = Instant::now();
+    let result = http.execute(request).instrument(span.clone()).await;
+    let _entered = span.enter();
+    match &result {
+        Ok(resp) => {
+            span.record("status_code", resp.status().as_u16());
+            tracing::debug!(
+                status_code = resp.status().as_u16(),
+                elapsed_ms = started.elapsed().as_millis() as u64,
+                "docling response"
+            );
         }
+        Err(err) => tracing::debug!(error = %err, "docling request failed"),
+    }
+    Ok(result?)
+}
 
-        // Add target_type
-        if let Some(tt) = target_type {
-            form = form.text("target_type", tt.to_string());
-        }
+#[cfg(not(feature = "tracing"))]
+async fn execute(
+    http: &reqwest::Client,
+    request: reqwest::Request,
+) -> Result<reqwest::Response, DoclingError> {
+    Ok(http.execute(request).await?)
+}
 
-        // Add options as flat form fields
-        if let Some(opts) = options {
-            // Array fields — sent as repeated form fields for FastAPI
-            if let Some(ref fmts) = opts.from_formats {
-                for fmt in fmts {
-                    form = form.text("from_formats", fmt.to_string());
-                }
-            }
-            if let Some(ref fmts) = opts.to_formats {
-                for fmt in fmts {
-                    form = form.text("to_formats", fmt.to_string());
-                }
-            }
-            if let Some(ref langs) = opts.ocr_lang {
-                for lang in langs {
-                    form = form.text("ocr_lang", lang.clone());
-                }
-            }
-            if let Some(ref range) = opts.page_range {
-                form = form.text("page_range", range.0.to_string());
-                form = form.text("page_range", range.1.to_string());
-            }
+/// Render a body for logging: lossy UTF-8, capped at
+/// [`MAX_LOGGED_BODY_BYTES`], with `secret` replaced by `[REDACTED]`.
+#[cfg(feature = "tracing")]
+fn body_snippet(bytes: &[u8], secret: Option<&str>) -> String {
+    // Redact over a slightly larger window so a key straddling the cap is
+    // still caught before truncation.
+    let secret = secret.filter(|s| !s.is_empty());
+    let window = MAX_LOGGED_BODY_BYTES + secret.map_or(0, str::len);
+    let mut snippet = String::from_utf8_lossy(&bytes[..bytes.len().min(window)]).into_owned();
+    if let Some(secret) = secret {
+        snippet = snippet.replace(secret, "[REDACTED]");
+    }
+    if snippet.len() > MAX_LOGGED_BODY_BYTES || bytes.len() > window {
+        let mut end = MAX_LOGGED_BODY_BYTES.min(snippet.len());
+        while !snippet.is_char_boundary(end) {
+            end -= 1;
+        }
+        snippet.truncate(end);
+        snippet.push_str(&format!("... ({} bytes total)", bytes.len()));
+    }
+    snippet
+}
 
-            // Enum fields
-            if let Some(ref v) = opts.image_export_mode {
-                form = form.text("image_export_mode", v.to_string());
-            }
-            if let Some(ref v) = opts.ocr_engine {
-                form = form.text("ocr_engine", v.to_string());
-            }
-            if let Some(ref v) = opts.pdf_backend {
-                form = form.text("pdf_backend", v.to_string());
-            }
-            if let Some(ref v) = opts.table_mode {
-                form = form.text("table_mode", v.to_string());
-            }
-            if let Some(ref v) = opts.pipeline {
-                form = form.text("pipeline", v.to_string());
-            }
-            if let Some(ref v) = opts.vlm_pipeline_model {
-                form = form.text("vlm_pipeline_model", v.to_string());
-            }
+// ============================================================================
+// Builder
+// ============================================================================
 
-            // Boolean fields
-            if let Some(v) = opts.do_ocr {
-                form = form.text("do_ocr", v.to_string());
-            }
-            if let Some(v) = opts.force_ocr {
-                form = form.text("force_ocr", v.to_string());
-            }
-            if let Some(v) = opts.table_cell_matching {
-                form = form.text("table_cell_matching", v.to_string());
-            }
-            if let Some(v) = opts.abort_on_error {
-                form = form.text("abort_on_error", v.to_string());
-            }
-            if let Some(v) = opts.do_table_structure {
-                form = form.text("do_table_structure", v.to_string());
-            }
-            if let Some(v) = opts.include_images {
-                form = form.text("include_images", v.to_string());
-            }
-            if let Some(v) = opts.do_code_enrichment {
-                form = form.text("do_code_enrichment", v.to_string());
-            }
-            if let Some(v) = opts.do_formula_enrichment {
-                form = form.text("do_formula_enrichment", v.to_string());
-            }
-            if let Some(v) = opts.do_picture_classification {
-                form = form.text("do_picture_classification", v.to_string());
-            }
-            if let Some(v) = opts.do_chart_extraction {
-                form = form.text("do_chart_extraction", v.to_string());
-            }
-            if let Some(v) = opts.do_picture_description {
-                form = form.text("do_picture_description", v.to_string());
-            }
+/// Builder for [`DoclingClient`], created via [`DoclingClient::builder`].
+#[derive(Debug, Clone)]
+pub struct DoclingClientBuilder {
+    base_url: String,
+    credentials: Option<Auth>,
+    token_provider: Option<Arc<dyn TokenProvider>>,
+    http_client: Option<reqwest::Client>,
+    proxies: Vec<reqwest::Proxy>,
+    accept_invalid_certs: bool,
+    user_agent: Option<String>,
+    default_headers: reqwest::header::HeaderMap,
+    log_bodies: bool,
+    compress_requests: bool,
+    inline_upload_threshold: u64,
+    max_concurrent_requests: Option<usize>,
+    request_timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    #[cfg(feature = "cache")]
+    cache_capacity: usize,
+}
 
-            // Numeric fields
-            if let Some(v) = opts.document_timeout {
-                form = form.text("document_timeout", v.to_string());
-            }
-            if let Some(v) = opts.images_scale {
-                form = form.text("images_scale", v.to_string());
-            }
-            if let Some(v) = opts.picture_description_area_threshold {
-                form = form.text("picture_description_area_threshold", v.to_string());
-            }
+impl DoclingClientBuilder {
+    /// Create a builder pointing at the given Docling Serve base URL.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            credentials: None,
+            token_provider: None,
+            http_client: None,
+            proxies: Vec::new(),
+            accept_invalid_certs: false,
+            user_agent: None,
+            default_headers: reqwest::header::HeaderMap::new(),
+            log_bodies: false,
+            compress_requests: false,
+            inline_upload_threshold: DEFAULT_INLINE_UPLOAD_THRESHOLD,
+            max_concurrent_requests: None,
+            request_timeout: None,
+            retry_policy: None,
+            #[cfg(feature = "cache")]
+            cache_capacity: DEFAULT_CACHE_CAPACITY,
+        }
+    }
 
-            // String fields
-            if let Some(ref v) = opts.md_page_break_placeholder {
-                form = form.text("md_page_break_placeholder", v.clone());
-            }
+    /// Replace the base URL given to [`DoclingClientBuilder::new`].
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
 
-            // JSON-encoded object fields (sent as JSON strings in multipart)
-            if let Some(ref v) = opts.picture_description_local {
-                form = form.text("picture_description_local", v.to_string());
-            }
-            if let Some(ref v) = opts.picture_description_api {
-                form = form.text("picture_description_api", v.to_string());
-            }
-            if let Some(ref v) = opts.vlm_pipeline_model_local {
-                form = form.text("vlm_pipeline_model_local", v.to_string());
-            }
-            if let Some(ref v) = opts.vlm_pipeline_model_api {
-                form = form.text("vlm_pipeline_model_api", v.to_string());
-            }
-        }
+    /// Send `Authorization: Bearer <key>` on requests to secured endpoints.
+    ///
+    /// Shortcut for [`auth`](Self::auth) with [`Auth::Bearer`].
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.credentials = Some(Auth::Bearer(api_key.into()));
+        self
+    }
 
-        Ok(form)
+    /// Send these credentials on requests to secured endpoints, replacing
+    /// any earlier [`api_key`](Self::api_key) or `auth` call.
+    ///
+    /// Use it for deployments behind HTTP Basic auth or a gateway that
+    /// expects the credential in a custom header.
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.credentials = Some(auth);
+        self
     }
 
-    /// Convert one or more local files (synchronous).
+    /// Ask `provider` for a bearer token before every request, for
+    /// credentials that expire and must be refreshed. See [`TokenProvider`].
     ///
-    /// Reads each file from disk and uploads via `multipart/form-data`.
-    /// The call blocks (async) until conversion is complete.
+    /// The token is sent on the same secured endpoints as an
+    /// [`api_key`](Self::api_key), and takes precedence over it or any
+    /// [`auth`](Self::auth) credentials.
+    pub fn token_provider(mut self, provider: impl TokenProvider + 'static) -> Self {
+        self.token_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Send requests through an existing [`reqwest::Client`] instead of a
+    /// new one. See [`DoclingClient::with_http_client`].
+    pub fn http_client(mut self, http: reqwest::Client) -> Self {
+        self.http_client = Some(http);
+        self
+    }
+
+    /// Route requests through a proxy. Can be called more than once; the
+    /// first proxy that matches a request's URL is used.
     ///
-    /// `POST /v1/convert/file`
+    /// Every endpoint, including `/health`, goes through the configured
+    /// proxies. Without this setting the client honors the standard
+    /// `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables;
+    /// setting a proxy here turns that lookup off. Ignored when an
+    /// [`http_client`](Self::http_client) is supplied, since that client's
+    /// own proxy settings apply.
     ///
-    /// # Arguments
-    /// * `file_paths` — One or more local file paths to convert.
-    /// * `options` — Optional conversion options. Pass `None` for server defaults.
-    /// * `target_type` — Optional target type. Pass `None` for default (in-body).
+    /// ```rust
+    /// use docling_rs::DoclingClient;
     ///
-    /// # Example
-    /// ```rust,no_run
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = docling_rs::client::DoclingClient::new("http://127.0.0.1:5001");
-    /// let result = client
-    ///     .convert_file(&["./document.pdf"], None, None)
-    ///     .await?;
-    /// if let Some(md) = &result.document.md_content {
-    ///     println!("{}", md);
-    /// }
-    /// # Ok(())
-    /// # }
+    /// let client = DoclingClient::builder("https://docling.example.com")
+    ///     .proxy(reqwest::Proxy::all("http://proxy.corp:3128").unwrap())
+    ///     .build();
     /// ```
-    pub async fn convert_file(
-        &self,
-        file_paths: &[impl AsRef<Path>],
-        options: Option<&ConvertDocumentsRequestOptions>,
-        target_type: Option<&TargetName>,
-    ) -> Result<ConvertDocumentResponse, DoclingError> {
-        let form = self
-            .build_file_multipart(file_paths, options, target_type)
-            .await?;
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
 
-        let req = self.auth(
-            self.http
-                .post(self.url("/v1/convert/file"))
-                .multipart(form),
-        );
+    /// **Dangerous:** skip TLS certificate validation, so HTTPS connections
+    /// succeed even with self-signed, expired, or mismatched certificates.
+    /// Off by default.
+    ///
+    /// Only for development against a throwaway HTTPS instance: with this
+    /// on, anyone on the network path can impersonate the server and read
+    /// the documents and API key you send. Ignored when an
+    /// [`http_client`](Self::http_client) is supplied.
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
 
-        let resp = req.send().await?;
-        let resp = self.handle_response(resp).await?;
-        let body = resp.json::<ConvertDocumentResponse>().await?;
-        Ok(body)
+    /// Send this `User-Agent` header on every request, overriding any
+    /// default of the underlying `reqwest` client.
+    ///
+    /// Without it, requests carry `docling-rs/<version>`, or the default of
+    /// a client supplied with [`http_client`](Self::http_client).
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
     }
 
-    /// Submit one or more local files for asynchronous conversion.
+    /// Send these headers on every request, e.g. a tenant header required by
+    /// an API gateway. Replaces any headers set by an earlier call.
     ///
-    /// Returns a `TaskStatusResponse` containing the `task_id` which can be
-    /// used to poll for status and retrieve results.
+    /// A header the request already sets, such as `Authorization` from
+    /// [`api_key`](Self::api_key) or the [`user_agent`](Self::user_agent),
+    /// takes precedence over a default with the same name.
     ///
-    /// `POST /v1/convert/file/async`
+    /// ```rust
+    /// use docling_rs::DoclingClient;
+    /// use reqwest::header::{HeaderMap, HeaderValue};
     ///
-    /// # Arguments
-    /// * `file_paths` — One or more local file paths to convert.
-    /// * `options` — Optional conversion options. Pass `None` for server defaults.
-    /// * `target_type` — Optional target type. Pass `None` for default (in-body).
-    pub async fn convert_file_async(
-        &self,
-        file_paths: &[impl AsRef<Path>],
-        options: Option<&ConvertDocumentsRequestOptions>,
-        target_type: Option<&TargetName>,
-    ) -> Result<TaskStatusResponse, DoclingError> {
-        let form = self
-            .build_file_multipart(file_paths, options, target_type)
-            .await?;
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-tenant-id", HeaderValue::from_static("acme"));
+    /// let client = DoclingClient::builder("http://127.0.0.1:5001")
+    ///     .default_headers(headers)
+    ///     .build();
+    /// ```
+    pub fn default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
 
-        let req = self.auth(
-            self.http
-                .post(self.url("/v1/convert/file/async"))
-                .multipart(form),
-        );
+    /// Log serialized JSON request bodies and raw response bodies at `debug`
+    /// level. Off by default.
+    ///
+    /// Only takes effect with the `tracing` feature enabled. Each logged body
+    /// is capped at 4 KiB and the API key is redacted. Multipart uploads are
+    /// not logged.
+    pub fn log_bodies(mut self, enabled: bool) -> Self {
+        self.log_bodies = enabled;
+        self
+    }
 
-        let resp = req.send().await?;
-        let resp = self.handle_response(resp).await?;
-        let body = resp.json::<TaskStatusResponse>().await?;
-        Ok(body)
+    /// Gzip JSON request bodies and send them with `Content-Encoding: gzip`.
+    /// Off by default.
+    ///
+    /// Useful for large inline (base64) sources. Only enable this if the
+    /// server, or a proxy in front of it, decompresses request bodies; Docling
+    /// Serve does not do so out of the box. Multipart uploads are never
+    /// compressed.
+    pub fn compress_requests(mut self, enabled: bool) -> Self {
+        self.compress_requests = enabled;
+        self
     }
 
-    // ========================================================================
-    // Convenience: submit file + wait
-    // ========================================================================
+    /// Largest base64-encoded size, in bytes, for which
+    /// [`DoclingClient::recommended_upload_mode`] suggests inline upload.
+    /// Defaults to 10 MiB.
+    pub fn inline_upload_threshold(mut self, bytes: u64) -> Self {
+        self.inline_upload_threshold = bytes;
+        self
+    }
 
-    /// Submit local files for async conversion and wait for completion.
+    /// Cap the number of HTTP requests this client has in flight at once,
+    /// across all calls. Requests beyond the limit wait for a free slot
+    /// rather than failing. Unlimited by default; `0` is treated as 1.
     ///
-    /// Convenience method that combines `convert_file_async`, polling via
-    /// `poll_task_status`, and `get_task_result` into a single call.
+    /// A slot is held from sending a request until its response headers
+    /// arrive. For long-polls and synchronous conversions that covers the
+    /// time the server spends on the request.
+    pub fn max_concurrent_requests(mut self, limit: usize) -> Self {
+        self.max_concurrent_requests = Some(limit);
+        self
+    }
+
+    /// Time out any single HTTP request after `timeout`. No timeout by
+    /// default.
     ///
-    /// # Arguments
-    /// * `file_paths` — One or more local file paths to convert.
-    /// * `options` — Optional conversion options.
-    /// * `target_type` — Optional target type. Pass `None` for default (in-body).
-    /// * `timeout` — Maximum time to wait for completion.
-    /// * `poll_interval_secs` — Server-side long-poll wait time per request.
-    ///   Defaults to 5 seconds if `None`.
+    /// The timeout covers the whole request, from connecting until the
+    /// response body has been read. Status long-polls get at least their
+    /// `wait` plus 10 seconds, so the two never fight. A timed-out request
+    /// returns [`DoclingError::Http`]; check it with
+    /// [`DoclingError::is_timeout`]. This is separate from the task-level
+    /// timeout of the `wait_for_*` methods.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Retry requests that get HTTP 429, 500, 502, 503, or 504, with
+    /// exponential backoff. No retries by default.
     ///
-    /// # Example
-    /// ```rust,no_run
-    /// # use std::time::Duration;
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = docling_rs::client::DoclingClient::new("http://127.0.0.1:5001");
-    /// let result = client
-    ///     .wait_for_file_conversion(
-    ///         &["./document.pdf"],
-    ///         None,
-    ///         None,
-    ///         Duration::from_secs(300),
-    ///         None,
-    ///     )
-    ///     .await?;
-    /// println!("Status: {:?}", result.status);
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub async fn wait_for_file_conversion(
-        &self,
-        file_paths: &[impl AsRef<Path>],
-        options: Option<&ConvertDocumentsRequestOptions>,
-        target_type: Option<&TargetName>,
-        timeout: Duration,
-        poll_interval_secs: Option<f64>,
-    ) -> Result<ConvertDocumentResponse, DoclingError> {
-        let task = self
-            .convert_file_async(file_paths, options, target_type)
-            .await?;
-        self.poll_until_complete(&task.task_id, timeout, poll_interval_secs)
-            .await
+    /// Idempotent GETs (health, status polls, results) and the async submit
+    /// endpoints are retried. Synchronous conversions and chunking are sent
+    /// once, since replaying them would redo the work on the server, and so
+    /// are multipart uploads, which stream their body. When a request
+    /// timeout is set, it bounds all attempts of a request together, and a
+    /// retry that would overrun it is not attempted.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Number of results kept by
+    /// [`DoclingClient::convert_source_memoized`]. Defaults to 64; `0` is
+    /// treated as 1.
+    ///
+    /// Requires the `cache` feature.
+    #[cfg(feature = "cache")]
+    pub fn cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache_capacity = capacity;
+        self
+    }
+
+    /// Build the configured [`DoclingClient`].
+    ///
+    /// Trailing slashes are stripped from the base URL, so
+    /// `http://host:5001/` and `http://host:5001` behave the same, as do
+    /// `https://host/docling/` and `https://host/docling`.
+    pub fn build(self) -> DoclingClient {
+        DoclingClient {
+            base_url: self.base_url.trim_end_matches('/').to_string(),
+            credentials: self.credentials,
+            token_provider: self.token_provider,
+            http: match self.http_client {
+                Some(http) => http,
+                None => build_http_client(self.proxies, self.accept_invalid_certs),
+            },
+            log_bodies: self.log_bodies,
+            compress_requests: self.compress_requests,
+            inline_upload_threshold: self.inline_upload_threshold,
+            limiter: self
+                .max_concurrent_requests
+                .map(|limit| Arc::new(tokio::sync::Semaphore::new(limit.max(1)))),
+            request_timeout: self.request_timeout,
+            user_agent: self.user_agent,
+            default_headers: self.default_headers,
+            retry_policy: self.retry_policy,
+            #[cfg(feature = "cache")]
+            cache: Arc::new(new_response_cache(self.cache_capacity)),
+        }
     }
 }
 
@@ -714,6 +3510,291 @@ mod tests {
     fn with_api_key_also_strips_trailing_slash() {
         let client = DoclingClient::with_api_key("http://localhost:5001/", "key");
         assert_eq!(client.url("/health"), "http://localhost:5001/health");
-        assert_eq!(client.api_key.as_deref(), Some("key"));
+        assert_eq!(client.credentials, Some(Auth::bearer("key")));
+    }
+
+    #[test]
+    fn builder_sets_api_key_and_log_bodies() {
+        let client = DoclingClient::builder("http://localhost:5001/")
+            .api_key("key")
+            .log_bodies(true)
+            .build();
+        assert_eq!(client.url("/health"), "http://localhost:5001/health");
+        assert_eq!(client.credentials, Some(Auth::bearer("key")));
+        assert!(client.log_bodies);
+    }
+
+    #[test]
+    fn builder_defaults_to_no_body_logging() {
+        let client = DoclingClient::builder("http://localhost:5001").build();
+        assert!(!client.log_bodies);
+        assert!(!client.compress_requests);
+        assert!(client.credentials.is_none());
+    }
+
+    #[test]
+    fn builder_base_url_overrides_and_is_normalized() {
+        let client = DoclingClient::builder("http://localhost:5001")
+            .base_url("http://docling.internal:8080//")
+            .user_agent("my-app/1.0")
+            .build();
+        assert_eq!(client.url("/health"), "http://docling.internal:8080/health");
+        assert_eq!(client.user_agent.as_deref(), Some("my-app/1.0"));
+    }
+
+    #[test]
+    fn danger_accept_invalid_certs_is_opt_in() {
+        assert!(!DoclingClientBuilder::new("https://localhost:5001").accept_invalid_certs);
+        let builder =
+            DoclingClientBuilder::new("https://localhost:5001").danger_accept_invalid_certs(true);
+        assert!(builder.accept_invalid_certs);
+        // Building the client with validation off must not fail.
+        let _client = builder.build();
+    }
+
+    #[test]
+    fn url_keeps_base_path_prefix() {
+        for base in [
+            "https://host/docling",
+            "https://host/docling/",
+            "https://host/docling//",
+        ] {
+            let client = DoclingClient::new(base);
+            assert_eq!(client.url("/health"), "https://host/docling/health");
+            assert_eq!(
+                client.url("/v1/convert/source"),
+                "https://host/docling/v1/convert/source"
+            );
+        }
+    }
+
+    #[test]
+    fn encode_path_segment_escapes_reserved_characters() {
+        assert_eq!(
+            encode_path_segment("3f2a-9b_c.d~e").unwrap(),
+            "3f2a-9b_c.d~e"
+        );
+        assert_eq!(encode_path_segment("a/b c?d").unwrap(), "a%2Fb%20c%3Fd");
+        assert_eq!(encode_path_segment("é").unwrap(), "%C3%A9");
+        assert_eq!(encode_path_segment("...").unwrap(), "...");
+        for id in ["", ".", ".."] {
+            assert!(matches!(
+                encode_path_segment(id),
+                Err(DoclingError::InvalidOptions { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn mime_for_filename_falls_back_to_octet_stream() {
+        assert_eq!(mime_for_filename("report.pdf"), "application/pdf");
+        assert_eq!(mime_for_filename("dir/page.htm"), "text/html");
+        assert_eq!(mime_for_filename("README"), "application/octet-stream");
+        assert_eq!(mime_for_filename("archive.zip"), "application/octet-stream");
+    }
+
+    #[test]
+    fn file_part_rejects_invalid_mime() {
+        let err = file_part(
+            Part::bytes(b"x".to_vec()),
+            "a.pdf".to_string(),
+            "not a mime",
+        )
+        .unwrap_err();
+        assert!(matches!(err, DoclingError::InvalidMime(ref m) if m == "not a mime"));
+        assert!(
+            file_part(
+                Part::bytes(Vec::new()),
+                "a.pdf".to_string(),
+                "application/pdf"
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn mime_for_filename_ignores_extension_case() {
+        assert_eq!(mime_for_filename("Report.PDF"), "application/pdf");
+        assert_eq!(mime_for_filename("IMAGE.JPG"), "image/jpeg");
+        assert_eq!(mime_for_filename("notes.Txt"), "text/plain");
+        assert_eq!(mime_for_filename("book.epub"), "application/epub+zip");
+        assert_eq!(mime_for_filename("letter.rtf"), "application/rtf");
+        assert_eq!(
+            mime_for_filename("draft.ODT"),
+            "application/vnd.oasis.opendocument.text"
+        );
+    }
+
+    #[test]
+    fn parse_content_range_variants() {
+        assert_eq!(
+            parse_content_range("bytes 0-99/1000"),
+            Some((0, Some(1000)))
+        );
+        assert_eq!(parse_content_range("bytes 100-199/*"), Some((100, None)));
+        assert_eq!(parse_content_range("bytes */1000"), None);
+        assert_eq!(parse_content_range("items 0-1/2"), None);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn body_snippet_redacts_api_key() {
+        let body = br#"{"headers":{"Authorization":"Bearer sk-123"}}"#;
+        let snippet = body_snippet(body, Some("sk-123"));
+        assert_eq!(
+            snippet,
+            r#"{"headers":{"Authorization":"Bearer [REDACTED]"}}"#
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn body_snippet_caps_length() {
+        let body = vec![b'a'; MAX_LOGGED_BODY_BYTES * 2];
+        let snippet = body_snippet(&body, None);
+        assert!(snippet.starts_with(&"a".repeat(MAX_LOGGED_BODY_BYTES)));
+        assert!(snippet.ends_with(&format!("... ({} bytes total)", body.len())));
+    }
+
+    /// The form fields as they were listed by hand before being derived
+    /// from serde, for every option set.
+    fn hand_written_form_fields(opts: &ConvertDocumentsRequestOptions) -> Vec<(String, String)> {
+        let mut fields: Vec<(&str, String)> = Vec::new();
+        for fmt in opts.from_formats.iter().flatten() {
+            fields.push(("from_formats", fmt.to_string()));
+        }
+        for fmt in opts.to_formats.iter().flatten() {
+            fields.push(("to_formats", fmt.to_string()));
+        }
+        for lang in opts.ocr_lang.iter().flatten() {
+            fields.push(("ocr_lang", lang.clone()));
+        }
+        if let Some(range) = opts.page_range {
+            fields.push(("page_range", range.0.to_string()));
+            fields.push(("page_range", range.1.to_string()));
+        }
+        let enums = [
+            (
+                "image_export_mode",
+                opts.image_export_mode.as_ref().map(|v| v.to_string()),
+            ),
+            (
+                "ocr_engine",
+                opts.ocr_engine.as_ref().map(|v| v.to_string()),
+            ),
+            (
+                "pdf_backend",
+                opts.pdf_backend.as_ref().map(|v| v.to_string()),
+            ),
+            (
+                "table_mode",
+                opts.table_mode.as_ref().map(|v| v.to_string()),
+            ),
+            ("pipeline", opts.pipeline.as_ref().map(|v| v.to_string())),
+            (
+                "vlm_pipeline_model",
+                opts.vlm_pipeline_model.as_ref().map(|v| v.to_string()),
+            ),
+        ];
+        let bools = [
+            ("do_ocr", opts.do_ocr),
+            ("force_ocr", opts.force_ocr),
+            ("table_cell_matching", opts.table_cell_matching),
+            ("abort_on_error", opts.abort_on_error),
+            ("do_table_structure", opts.do_table_structure),
+            ("include_images", opts.include_images),
+            ("do_code_enrichment", opts.do_code_enrichment),
+            ("do_formula_enrichment", opts.do_formula_enrichment),
+            ("do_picture_classification", opts.do_picture_classification),
+            ("do_chart_extraction", opts.do_chart_extraction),
+            ("do_picture_description", opts.do_picture_description),
+        ];
+        let numbers = [
+            ("document_timeout", opts.document_timeout),
+            ("images_scale", opts.images_scale),
+            (
+                "picture_description_area_threshold",
+                opts.picture_description_area_threshold,
+            ),
+        ];
+        let objects = [
+            ("picture_description_local", &opts.picture_description_local),
+            ("picture_description_api", &opts.picture_description_api),
+            ("vlm_pipeline_model_local", &opts.vlm_pipeline_model_local),
+            ("vlm_pipeline_model_api", &opts.vlm_pipeline_model_api),
+        ];
+        for (name, v) in enums {
+            fields.extend(v.map(|v| (name, v)));
+        }
+        for (name, v) in bools {
+            fields.extend(v.map(|v| (name, v.to_string())));
+        }
+        for (name, v) in numbers {
+            fields.extend(v.map(|v| (name, v.to_string())));
+        }
+        fields.extend(
+            opts.md_page_break_placeholder
+                .clone()
+                .map(|v| ("md_page_break_placeholder", v)),
+        );
+        for (name, v) in objects {
+            fields.extend(v.as_ref().map(|v| (name, v.to_string())));
+        }
+        fields
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect()
+    }
+
+    #[test]
+    fn form_fields_match_hand_written_encoding() {
+        use crate::models::enums::*;
+
+        let opts = ConvertDocumentsRequestOptions {
+            from_formats: Some(vec![InputFormat::Pdf, InputFormat::Docx]),
+            to_formats: Some(vec![OutputFormat::Md, OutputFormat::Json]),
+            image_export_mode: Some(ImageRefMode::Referenced),
+            do_ocr: Some(true),
+            force_ocr: Some(false),
+            ocr_engine: Some(OcrEngine::Tesserocr),
+            ocr_lang: Some(vec!["en".to_string(), "de".to_string()]),
+            pdf_backend: Some(PdfBackend::DlparseV4),
+            table_mode: Some(TableFormerMode::Fast),
+            table_cell_matching: Some(true),
+            pipeline: Some(ProcessingPipeline::Vlm),
+            page_range: Some((2, i64::MAX)),
+            document_timeout: Some(120.0),
+            abort_on_error: Some(false),
+            do_table_structure: Some(true),
+            include_images: Some(false),
+            images_scale: Some(1.5),
+            md_page_break_placeholder: Some("<!-- page-break -->".to_string()),
+            do_code_enrichment: Some(true),
+            do_formula_enrichment: Some(false),
+            do_picture_classification: Some(true),
+            do_chart_extraction: Some(false),
+            do_picture_description: Some(true),
+            picture_description_area_threshold: Some(0.05),
+            vlm_pipeline_model: Some(VlmModelType::GotOcr2),
+            picture_description_local: Some(serde_json::json!({"repo_id": "a/b"})),
+            picture_description_api: Some(serde_json::json!({"url": "http://x", "timeout": 20})),
+            vlm_pipeline_model_local: Some(serde_json::json!({"repo_id": "c/d", "scale": 2.0})),
+            vlm_pipeline_model_api: Some(serde_json::json!({"params": {"model": "m"}})),
+        };
+
+        let mut derived = form_fields(&opts);
+        let mut expected = hand_written_form_fields(&opts);
+        derived.sort();
+        expected.sort();
+        assert_eq!(derived, expected);
+
+        let defaults = ConvertDocumentsRequestOptions::default();
+        assert!(form_fields(&defaults).is_empty());
+    }
+
+    #[test]
+    fn form_value_sends_lowercase_booleans() {
+        assert_eq!(form_value(&serde_json::Value::Bool(true)), "true");
+        assert_eq!(form_value(&serde_json::Value::Bool(false)), "false");
     }
 }
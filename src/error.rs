@@ -1,5 +1,10 @@
+use std::time::Duration;
+
 use thiserror::Error;
 
+use crate::models::enums::{OutputFormat, TaskStatus};
+use crate::models::responses::{ErrorItem, HttpValidationError};
+
 /// Errors that can occur when using the Docling SDK.
 #[derive(Error, Debug)]
 pub enum DoclingError {
@@ -11,6 +16,19 @@ pub enum DoclingError {
     #[error("api error (HTTP {status_code}): {body}")]
     Api { status_code: u16, body: String },
 
+    /// The server answered HTTP 429 Too Many Requests.
+    ///
+    /// `retry_after` is the delay from the `Retry-After` header, given in
+    /// seconds or as an HTTP date, or `None` if the header is missing or
+    /// cannot be parsed. A date in the past yields a zero delay.
+    #[error("rate limited (HTTP 429){}", retry_hint(*.retry_after))]
+    RateLimited { retry_after: Option<Duration> },
+
+    /// The server rejected the request with HTTP 422 and a structured
+    /// validation error listing the offending fields.
+    #[error("validation error (HTTP 422): {0}")]
+    Validation(HttpValidationError),
+
     /// Failed to deserialize the response body.
     #[error("json deserialization error: {0}")]
     Json(#[from] serde_json::Error),
@@ -23,7 +41,112 @@ pub enum DoclingError {
     #[error("task {task_id} failed with status: {status}")]
     TaskFailed { task_id: String, status: String },
 
+    /// The server finished a conversion with status `failure`, returned by
+    /// [`ConvertDocumentResponse::into_result`](crate::ConvertDocumentResponse::into_result).
+    #[error("conversion of {filename} failed with {} error(s)", .errors.len())]
+    ConversionFailed {
+        filename: String,
+        errors: Vec<ErrorItem>,
+    },
+
+    /// Waiting for a task was cancelled through its cancellation token. The
+    /// task itself may still be running on the server.
+    #[error("wait for task {task_id} was cancelled")]
+    Cancelled { task_id: String },
+
+    /// The request or its options were rejected client-side before sending.
+    #[error("invalid options: {reason}")]
+    InvalidOptions { reason: String },
+
+    /// The server answered with a response of a different kind than the call
+    /// can return, e.g. a ZIP archive where one JSON document was expected.
+    #[error("unexpected response: {reason}")]
+    UnexpectedResponse { reason: String },
+
+    /// The response did not include content in the requested output format.
+    #[error("response has no {format} content")]
+    MissingContent { format: OutputFormat },
+
+    /// A MIME type for an uploaded file could not be parsed.
+    #[error("invalid MIME type: {0:?}")]
+    InvalidMime(String),
+
     /// Timed out waiting for an async task to complete.
-    #[error("task {task_id} timed out after {elapsed_secs:.1}s")]
-    Timeout { task_id: String, elapsed_secs: f64 },
+    ///
+    /// `last_status` and `last_position` come from the last successful
+    /// status poll, telling a task stuck in the queue apart from one still
+    /// processing. Both are `None` if no poll succeeded.
+    #[error(
+        "task {task_id} timed out after {elapsed_secs:.1}s{}",
+        last_seen(.last_status.as_ref(), *.last_position)
+    )]
+    Timeout {
+        task_id: String,
+        elapsed_secs: f64,
+        last_status: Option<TaskStatus>,
+        last_position: Option<i64>,
+    },
+}
+
+/// The `RateLimited` message suffix naming the requested delay.
+fn retry_hint(retry_after: Option<Duration>) -> String {
+    match retry_after {
+        Some(delay) => format!(", retry after {}s", delay.as_secs()),
+        None => String::new(),
+    }
+}
+
+/// The `Timeout` message suffix describing the last status seen.
+fn last_seen(status: Option<&TaskStatus>, position: Option<i64>) -> String {
+    match (status, position) {
+        (Some(status), Some(position)) => {
+            format!(" (last status: {}, queue position {})", status, position)
+        }
+        (Some(status), None) => format!(" (last status: {})", status),
+        (None, _) => String::new(),
+    }
+}
+
+impl DoclingError {
+    /// Whether this error is a timeout: either an HTTP request that hit the
+    /// client's request timeout, or a task that did not finish in time.
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            DoclingError::Http(e) => e.is_timeout(),
+            DoclingError::Timeout { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// The HTTP status code behind this error, if there is one.
+    ///
+    /// Returns the code for [`Api`](Self::Api), `429` for
+    /// [`RateLimited`](Self::RateLimited), `422` for
+    /// [`Validation`](Self::Validation), and the response status carried by
+    /// an [`Http`](Self::Http) error; `None` otherwise.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            DoclingError::Api { status_code, .. } => Some(*status_code),
+            DoclingError::RateLimited { .. } => Some(429),
+            DoclingError::Validation(_) => Some(422),
+            DoclingError::Http(e) => e.status().map(|s| s.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// Whether the failed call is worth retrying as-is: an HTTP request that
+    /// timed out or could not connect, or a `429` or `5xx` response.
+    ///
+    /// A task that did not finish within a wait timeout
+    /// ([`Timeout`](Self::Timeout)) is not retryable here, because the task
+    /// may still be running on the server; poll it again instead of
+    /// resubmitting.
+    pub fn is_retryable(&self) -> bool {
+        if let DoclingError::Http(e) = self {
+            if e.is_timeout() || e.is_connect() {
+                return true;
+            }
+        }
+        matches!(self.status_code(), Some(code) if code == 429 || code >= 500)
+    }
 }
@@ -0,0 +1,292 @@
+//! Incremental extraction of one string field from a JSON body.
+//!
+//! Lets the client write a document's Markdown out while the response is
+//! still arriving, instead of deserializing the whole conversion result.
+
+use std::io;
+
+/// Longest object key worth buffering. Longer keys never match the path.
+const MAX_KEY_LEN: usize = 64;
+
+/// Scans a JSON document chunk by chunk and decodes the string value found
+/// at `path` (a chain of object keys from the root) into an output buffer.
+///
+/// Everything else in the document is skipped without being stored, so
+/// memory stays bounded by the chunk size regardless of the body size.
+pub(crate) struct StringFieldExtractor {
+    path: &'static [&'static str],
+    stack: Vec<Frame>,
+    string: Option<StringKind>,
+    escape: Escape,
+    key: Vec<u8>,
+    high_surrogate: Option<u16>,
+    found: bool,
+}
+
+/// An open object or array.
+struct Frame {
+    object: bool,
+    expecting_key: bool,
+    /// Whether the key of the value currently being read matches the path
+    /// component at this depth.
+    key_matches: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum StringKind {
+    Key,
+    Target,
+    Other,
+}
+
+#[derive(Clone, Copy)]
+enum Escape {
+    None,
+    Backslash,
+    Unicode { digits: u8, value: u16 },
+}
+
+impl StringFieldExtractor {
+    pub(crate) fn new(path: &'static [&'static str]) -> Self {
+        Self {
+            path,
+            stack: Vec::new(),
+            string: None,
+            escape: Escape::None,
+            key: Vec::new(),
+            high_surrogate: None,
+            found: false,
+        }
+    }
+
+    /// Whether the target string has been read in full. A `null` or absent
+    /// field leaves this `false`.
+    pub(crate) fn found(&self) -> bool {
+        self.found
+    }
+
+    /// Consume the next chunk of the body, appending any decoded bytes of
+    /// the target string to `out`.
+    pub(crate) fn feed(&mut self, chunk: &[u8], out: &mut Vec<u8>) -> io::Result<()> {
+        for &byte in chunk {
+            match self.string {
+                Some(StringKind::Target) => self.target_byte(byte, out)?,
+                Some(kind) => self.skipped_byte(kind, byte),
+                None => self.structural_byte(byte),
+            }
+        }
+        Ok(())
+    }
+
+    fn structural_byte(&mut self, byte: u8) {
+        match byte {
+            b'{' | b'[' => self.stack.push(Frame {
+                object: byte == b'{',
+                expecting_key: byte == b'{',
+                key_matches: false,
+            }),
+            b'}' | b']' => {
+                self.stack.pop();
+            }
+            b',' => {
+                if let Some(frame) = self.stack.last_mut() {
+                    frame.expecting_key = frame.object;
+                }
+            }
+            b'"' => {
+                let expecting_key = self.stack.last().is_some_and(|f| f.expecting_key);
+                self.string = Some(if expecting_key {
+                    self.key.clear();
+                    StringKind::Key
+                } else if self.at_path() {
+                    StringKind::Target
+                } else {
+                    StringKind::Other
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether a value starting now sits at `path`.
+    fn at_path(&self) -> bool {
+        self.stack.len() == self.path.len() && self.stack.iter().all(|f| f.key_matches)
+    }
+
+    fn skipped_byte(&mut self, kind: StringKind, byte: u8) {
+        let escaped = matches!(self.escape, Escape::Backslash);
+        self.escape = Escape::None;
+        if !escaped && byte == b'"' {
+            self.string = None;
+            if kind == StringKind::Key {
+                self.end_key();
+            }
+            return;
+        }
+        if !escaped && byte == b'\\' {
+            self.escape = Escape::Backslash;
+        }
+        if kind == StringKind::Key && self.key.len() <= MAX_KEY_LEN {
+            self.key.push(byte);
+        }
+    }
+
+    fn end_key(&mut self) {
+        let depth = self.stack.len();
+        let matches = self
+            .path
+            .get(depth - 1)
+            .is_some_and(|component| component.as_bytes() == self.key);
+        if let Some(frame) = self.stack.last_mut() {
+            frame.expecting_key = false;
+            frame.key_matches = matches;
+        }
+    }
+
+    fn target_byte(&mut self, byte: u8, out: &mut Vec<u8>) -> io::Result<()> {
+        match self.escape {
+            Escape::None => match byte {
+                b'"' => {
+                    self.flush_surrogate(out);
+                    self.string = None;
+                    self.found = true;
+                }
+                b'\\' => self.escape = Escape::Backslash,
+                _ => {
+                    self.flush_surrogate(out);
+                    out.push(byte);
+                }
+            },
+            Escape::Backslash => {
+                self.escape = Escape::None;
+                let decoded = match byte {
+                    b'u' => {
+                        self.escape = Escape::Unicode {
+                            digits: 0,
+                            value: 0,
+                        };
+                        return Ok(());
+                    }
+                    b'"' | b'\\' | b'/' => byte,
+                    b'b' => 0x08,
+                    b'f' => 0x0c,
+                    b'n' => b'\n',
+                    b'r' => b'\r',
+                    b't' => b'\t',
+                    _ => return Err(invalid_escape()),
+                };
+                self.flush_surrogate(out);
+                out.push(decoded);
+            }
+            Escape::Unicode { digits, value } => {
+                let digit = (byte as char).to_digit(16).ok_or_else(invalid_escape)?;
+                let value = value << 4 | digit as u16;
+                if digits < 3 {
+                    self.escape = Escape::Unicode {
+                        digits: digits + 1,
+                        value,
+                    };
+                } else {
+                    self.escape = Escape::None;
+                    self.push_code_unit(value, out);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Decode one `\uXXXX` code unit, pairing surrogates across escapes.
+    /// Unpaired surrogates become U+FFFD.
+    fn push_code_unit(&mut self, unit: u16, out: &mut Vec<u8>) {
+        let units = match (self.high_surrogate.take(), unit) {
+            (Some(high), 0xDC00..=0xDFFF) => vec![high, unit],
+            (high, 0xD800..=0xDBFF) => {
+                if high.is_some() {
+                    push_char(char::REPLACEMENT_CHARACTER, out);
+                }
+                self.high_surrogate = Some(unit);
+                return;
+            }
+            (high, _) => {
+                if high.is_some() {
+                    push_char(char::REPLACEMENT_CHARACTER, out);
+                }
+                vec![unit]
+            }
+        };
+        for c in char::decode_utf16(units) {
+            push_char(c.unwrap_or(char::REPLACEMENT_CHARACTER), out);
+        }
+    }
+
+    fn flush_surrogate(&mut self, out: &mut Vec<u8>) {
+        if self.high_surrogate.take().is_some() {
+            push_char(char::REPLACEMENT_CHARACTER, out);
+        }
+    }
+}
+
+fn push_char(c: char, out: &mut Vec<u8>) {
+    let mut buf = [0; 4];
+    out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+}
+
+fn invalid_escape() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "invalid escape in JSON string")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: &[&str] = &["document", "md_content"];
+
+    fn extract(body: &str, chunk_size: usize) -> (String, bool) {
+        let mut extractor = StringFieldExtractor::new(PATH);
+        let mut out = Vec::new();
+        for chunk in body.as_bytes().chunks(chunk_size) {
+            extractor.feed(chunk, &mut out).unwrap();
+        }
+        (String::from_utf8(out).unwrap(), extractor.found())
+    }
+
+    #[test]
+    fn matches_serde_for_every_chunk_size() {
+        let md = "# Tïtle \"quoted\" \\ back\n\ttab / slash \u{1F600} \u{7}";
+        let body = serde_json::json!({
+            "errors": [{"md_content": "not this"}],
+            "document": {
+                "json_content": {"md_content": "nor this", "texts": ["a\"b"]},
+                "md_content": md,
+                "text_content": null
+            },
+            "md_content": "nor this"
+        })
+        .to_string();
+
+        for chunk_size in 1..=body.len() {
+            assert_eq!(extract(&body, chunk_size), (md.to_string(), true));
+        }
+    }
+
+    #[test]
+    fn decodes_escaped_surrogate_pairs() {
+        let body = r#"{"document": {"md_content": "a\ud83d\ude00b\u00e9\ud800"}}"#;
+        assert_eq!(extract(body, 3), ("a\u{1F600}bé\u{FFFD}".to_string(), true));
+    }
+
+    #[test]
+    fn null_or_missing_field_is_not_found() {
+        assert!(!extract(r#"{"document": {"md_content": null}}"#, 4).1);
+        assert!(!extract(r#"{"document": {"filename": "a.pdf"}}"#, 4).1);
+    }
+
+    #[test]
+    fn rejects_invalid_escapes() {
+        let mut extractor = StringFieldExtractor::new(PATH);
+        let err = extractor
+            .feed(br#"{"document": {"md_content": "\q"}}"#, &mut Vec::new())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -9,30 +9,65 @@
     feature = "blocking",
     doc = " - [`blocking`] — Synchronous/blocking versions of all APIs."
 )]
+#![cfg_attr(
+    feature = "zip",
+    doc = " - [`archive`] — [`unpack_zip`] for reading ZIP target results in memory."
+)]
+//! - [`auth`] — [`Auth`] credentials and [`TokenProvider`] for refreshable bearer tokens.
 //! - [`error`] — The [`DoclingError`] type covering all failure modes.
 //! - [`models`] — All request/response types and enums matching the OpenAPI spec.
+//! - [`retry`] — [`RetryPolicy`] for retrying transient server errors.
+//! - [`task`] — [`TaskHandle`] for following a submitted async task.
+//! - [`wait`] — [`WaitConfig`] and [`PollStrategy`] for the `wait_for_*` methods.
 
+// Without a TLS backend every `https://` request fails at runtime; fail the
+// build instead.
+#[cfg(not(any(feature = "rustls-tls", feature = "native-tls")))]
+compile_error!("docling_rs needs a TLS backend: enable the `rustls-tls` or `native-tls` feature");
+
+#[cfg(feature = "zip")]
+pub mod archive;
+pub mod auth;
 pub mod client;
 pub mod error;
+mod extract;
 pub mod models;
+pub mod retry;
+mod sniff;
+pub mod task;
+pub mod wait;
 
 #[cfg(feature = "blocking")]
 pub mod blocking;
 
 // -- Primary types (always needed) --
-pub use client::DoclingClient;
+#[cfg(feature = "zip")]
+pub use archive::unpack_zip;
+pub use auth::{Auth, StaticToken, TokenFuture, TokenProvider};
+pub use client::{DoclingClient, DoclingClientBuilder};
 pub use error::DoclingError;
+pub use retry::RetryPolicy;
+pub use sniff::detect_input_format;
+pub use task::{RestartOutcome, TaskHandle};
+pub use tokio_util::sync::CancellationToken;
+pub use wait::{PollStrategy, WaitConfig, WaitStats};
 
 // -- Request types --
-pub use models::requests::{ConvertDocumentsRequest, ConvertDocumentsRequestOptions, Source, Target};
+pub use models::requests::{
+    ChunkDocumentsRequest, ChunkingOptions, ConvertDocumentsRequest,
+    ConvertDocumentsRequestOptions, Input, OptionsBuilder, PageRange, PictureDescriptionApi,
+    PictureDescriptionLocal, S3Target, Source, Target, UploadMode, VlmModelApi, VlmModelLocal,
+};
 
 // -- Response types --
 pub use models::responses::{
-    ConvertDocumentResponse, ExportDocumentResponse, HealthCheckResponse, TaskStatusResponse,
+    AdaptiveOutcome, Chunk, ChunkDocumentResponse, ConvertDocumentResponse,
+    ConvertDocumentsResponse, ExportDocumentResponse, HealthCheckResponse, TaskStatusResponse,
+    VersionResponse,
 };
 
 // -- Commonly used enums --
 pub use models::enums::{
     ConversionStatus, InputFormat, OcrEngine, OutputFormat, PdfBackend, ProcessingPipeline,
-    TargetName,
+    TargetName, TaskStatus,
 };
@@ -0,0 +1,392 @@
+//! Typed subset of the `DoclingDocument` schema returned as `json_content`.
+//!
+//! Only the parts needed to walk the document in reading order are modeled:
+//! the body tree, text items, tables, pictures, and their provenance. Unknown
+//! fields on the document and its text, table, and picture items are kept in
+//! their `extra` maps, so newer server versions still deserialize and nothing
+//! is lost on a round trip.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A JSON pointer to another item in the document, e.g. `#/texts/3`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct RefItem {
+    #[serde(rename = "$ref")]
+    pub cref: String,
+}
+
+/// Bounding box of an item on its page.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BoundingBox {
+    pub l: f64,
+    pub t: f64,
+    pub r: f64,
+    pub b: f64,
+    #[serde(default)]
+    pub coord_origin: Option<String>,
+}
+
+/// Where an item came from in the source document.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProvenanceItem {
+    /// 1-based page number.
+    pub page_no: i64,
+    pub bbox: BoundingBox,
+    /// Character range `[start, end)` of the item within the page text.
+    #[serde(default)]
+    pub charspan: Vec<usize>,
+}
+
+/// A container node (the body, a list, a section, ...).
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct GroupItem {
+    pub self_ref: String,
+    #[serde(default)]
+    pub parent: Option<RefItem>,
+    #[serde(default)]
+    pub children: Vec<RefItem>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub label: String,
+}
+
+/// A text item (paragraph, heading, list item, caption, ...).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TextItem {
+    pub self_ref: String,
+    #[serde(default)]
+    pub parent: Option<RefItem>,
+    #[serde(default)]
+    pub children: Vec<RefItem>,
+    /// Item label, e.g. `text`, `title`, `section_header`, `list_item`.
+    pub label: String,
+    #[serde(default)]
+    pub prov: Vec<ProvenanceItem>,
+    /// Text as found in the source.
+    #[serde(default)]
+    pub orig: String,
+    /// Sanitized text.
+    pub text: String,
+    /// Heading level, for `section_header` items.
+    #[serde(default)]
+    pub level: Option<i64>,
+    /// Fields not modeled above.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// One cell of a table.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TableCell {
+    pub text: String,
+    pub start_row_offset_idx: usize,
+    pub end_row_offset_idx: usize,
+    pub start_col_offset_idx: usize,
+    pub end_col_offset_idx: usize,
+    #[serde(default)]
+    pub column_header: bool,
+    #[serde(default)]
+    pub row_header: bool,
+}
+
+/// Table contents.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct TableData {
+    #[serde(default)]
+    pub num_rows: usize,
+    #[serde(default)]
+    pub num_cols: usize,
+    #[serde(default)]
+    pub table_cells: Vec<TableCell>,
+}
+
+/// A table.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TableItem {
+    pub self_ref: String,
+    #[serde(default)]
+    pub parent: Option<RefItem>,
+    #[serde(default)]
+    pub children: Vec<RefItem>,
+    #[serde(default)]
+    pub label: String,
+    #[serde(default)]
+    pub prov: Vec<ProvenanceItem>,
+    /// References to caption text items.
+    #[serde(default)]
+    pub captions: Vec<RefItem>,
+    #[serde(default)]
+    pub data: TableData,
+    /// Fields not modeled above.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A picture.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PictureItem {
+    pub self_ref: String,
+    #[serde(default)]
+    pub parent: Option<RefItem>,
+    #[serde(default)]
+    pub children: Vec<RefItem>,
+    #[serde(default)]
+    pub label: String,
+    #[serde(default)]
+    pub prov: Vec<ProvenanceItem>,
+    /// References to caption text items.
+    #[serde(default)]
+    pub captions: Vec<RefItem>,
+    /// Fields not modeled above.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Page dimensions.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PageSize {
+    pub width: f64,
+    pub height: f64,
+}
+
+/// A page of the source document.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PageItem {
+    pub page_no: i64,
+    pub size: PageSize,
+}
+
+/// A converted document, as returned in `json_content`.
+///
+/// Obtain one with
+/// [`ExportDocumentResponse::parse_json`](crate::models::responses::ExportDocumentResponse::parse_json)
+/// or
+/// [`ExportDocumentResponse::docling_document`](crate::models::responses::ExportDocumentResponse::docling_document).
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct DoclingDocument {
+    #[serde(default)]
+    pub schema_name: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub name: String,
+    /// Root of the document tree, in reading order.
+    #[serde(default)]
+    pub body: GroupItem,
+    #[serde(default)]
+    pub groups: Vec<GroupItem>,
+    #[serde(default)]
+    pub texts: Vec<TextItem>,
+    #[serde(default)]
+    pub tables: Vec<TableItem>,
+    #[serde(default)]
+    pub pictures: Vec<PictureItem>,
+    /// Pages keyed by page number.
+    #[serde(default)]
+    pub pages: HashMap<String, PageItem>,
+    /// Fields not modeled above.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// An item resolved from a [`RefItem`].
+enum Node<'a> {
+    Group(&'a GroupItem),
+    Text(&'a TextItem),
+    Table(&'a TableItem),
+    Picture(&'a PictureItem),
+}
+
+impl DoclingDocument {
+    /// Render the body as markdown with a `<!-- page N -->` comment before
+    /// the first block of every page.
+    ///
+    /// This is a client-side renderer over the typed model, not the
+    /// server's markdown exporter: headings, paragraphs, lists, code,
+    /// tables, and picture placeholders are supported, and the output may
+    /// differ in detail from `md_content`. The page of a block is the page
+    /// of its first provenance entry; blocks without provenance never
+    /// start a new page.
+    pub fn markdown_with_provenance(&self) -> String {
+        let mut renderer = ProvenanceRenderer {
+            doc: self,
+            blocks: Vec::new(),
+            page: None,
+        };
+        renderer.children(&self.body.children);
+        renderer.blocks.join("\n\n")
+    }
+
+    fn resolve(&self, item: &RefItem) -> Option<Node<'_>> {
+        let (kind, index) = item.cref.strip_prefix("#/")?.split_once('/')?;
+        let index: usize = index.parse().ok()?;
+        match kind {
+            "groups" => self.groups.get(index).map(Node::Group),
+            "texts" => self.texts.get(index).map(Node::Text),
+            "tables" => self.tables.get(index).map(Node::Table),
+            "pictures" => self.pictures.get(index).map(Node::Picture),
+            _ => None,
+        }
+    }
+
+    fn caption_text(&self, captions: &[RefItem]) -> Option<String> {
+        let text: Vec<&str> = captions
+            .iter()
+            .filter_map(|c| match self.resolve(c) {
+                Some(Node::Text(t)) => Some(t.text.as_str()),
+                _ => None,
+            })
+            .collect();
+        (!text.is_empty()).then(|| text.join(" "))
+    }
+}
+
+struct ProvenanceRenderer<'a> {
+    doc: &'a DoclingDocument,
+    blocks: Vec<String>,
+    page: Option<i64>,
+}
+
+impl ProvenanceRenderer<'_> {
+    fn children(&mut self, children: &[RefItem]) {
+        for child in children {
+            match self.doc.resolve(child) {
+                Some(Node::Group(group)) if is_list(&group.label) => self.list(group),
+                Some(Node::Group(group)) => self.children(&group.children),
+                Some(Node::Text(text)) => {
+                    if let Some(block) = text_block(text) {
+                        self.push(&text.prov, block);
+                    }
+                    self.children(&text.children);
+                }
+                Some(Node::Table(table)) => {
+                    let mut block = self.doc.caption_text(&table.captions).unwrap_or_default();
+                    let rows = table_markdown(&table.data);
+                    if !rows.is_empty() {
+                        if !block.is_empty() {
+                            block.push_str("\n\n");
+                        }
+                        block.push_str(&rows);
+                    }
+                    if !block.is_empty() {
+                        self.push(&table.prov, block);
+                    }
+                }
+                Some(Node::Picture(picture)) => {
+                    let mut block = "<!-- image -->".to_string();
+                    if let Some(caption) = self.doc.caption_text(&picture.captions) {
+                        block.push_str("\n\n");
+                        block.push_str(&caption);
+                    }
+                    self.push(&picture.prov, block);
+                }
+                None => {}
+            }
+        }
+    }
+
+    fn list(&mut self, group: &GroupItem) {
+        let ordered = group.label == "ordered_list";
+        let mut lines = Vec::new();
+        let mut number = 1;
+        for child in &group.children {
+            let Some(Node::Text(item)) = self.doc.resolve(child) else {
+                continue;
+            };
+            // A new page inside a list splits it into separate blocks.
+            if let Some(page) = first_page(&item.prov) {
+                if self.page != Some(page) && !lines.is_empty() {
+                    self.blocks.push(lines.join("\n"));
+                    lines.clear();
+                }
+                self.mark_page(page);
+            }
+            let marker = if ordered {
+                format!("{}.", number)
+            } else {
+                "-".to_string()
+            };
+            lines.push(format!("{} {}", marker, item.text));
+            number += 1;
+        }
+        if !lines.is_empty() {
+            self.blocks.push(lines.join("\n"));
+        }
+    }
+
+    fn push(&mut self, prov: &[ProvenanceItem], block: String) {
+        if let Some(page) = first_page(prov) {
+            self.mark_page(page);
+        }
+        self.blocks.push(block);
+    }
+
+    fn mark_page(&mut self, page: i64) {
+        if self.page != Some(page) {
+            self.page = Some(page);
+            self.blocks.push(format!("<!-- page {} -->", page));
+        }
+    }
+}
+
+fn is_list(label: &str) -> bool {
+    matches!(label, "list" | "ordered_list")
+}
+
+fn first_page(prov: &[ProvenanceItem]) -> Option<i64> {
+    prov.first().map(|p| p.page_no)
+}
+
+/// Markdown for a single text item, or `None` for page furniture.
+fn text_block(item: &TextItem) -> Option<String> {
+    let text = item.text.as_str();
+    let block = match item.label.as_str() {
+        "page_header" | "page_footer" => return None,
+        "title" => format!("# {}", text),
+        "section_header" => {
+            let level = item.level.unwrap_or(1).clamp(1, 5) as usize;
+            format!("{} {}", "#".repeat(level + 1), text)
+        }
+        "list_item" => format!("- {}", text),
+        "code" => format!("```\n{}\n```", text),
+        "formula" => format!("$${}$$", text),
+        _ => text.to_string(),
+    };
+    (!text.is_empty()).then_some(block)
+}
+
+/// Render table cells as a markdown table, treating the first row as the
+/// header. Spanning cells are repeated in every row/column they cover.
+fn table_markdown(data: &TableData) -> String {
+    if data.num_rows == 0 || data.num_cols == 0 {
+        return String::new();
+    }
+    let mut grid = vec![vec![String::new(); data.num_cols]; data.num_rows];
+    for cell in &data.table_cells {
+        let text = cell.text.replace('|', "\\|").replace('\n', " ");
+        for row in grid
+            .iter_mut()
+            .take(cell.end_row_offset_idx)
+            .skip(cell.start_row_offset_idx)
+        {
+            for slot in row
+                .iter_mut()
+                .take(cell.end_col_offset_idx)
+                .skip(cell.start_col_offset_idx)
+            {
+                slot.clone_from(&text);
+            }
+        }
+    }
+
+    let mut lines = Vec::with_capacity(grid.len() + 1);
+    for (i, row) in grid.iter().enumerate() {
+        lines.push(format!("| {} |", row.join(" | ")));
+        if i == 0 {
+            lines.push(format!("|{}", "---|".repeat(data.num_cols)));
+        }
+    }
+    lines.join("\n")
+}
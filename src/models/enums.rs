@@ -1,5 +1,32 @@
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+/// Error returned when parsing one of the API enums from an unknown string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnumError {
+    type_name: &'static str,
+    value: String,
+}
+
+impl std::fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown {} value: {:?}", self.type_name, self.value)
+    }
+}
+
+impl std::error::Error for ParseEnumError {}
+
+/// Parse a snake_case wire value using the enum's serde renames.
+fn parse_wire_value<T: DeserializeOwned>(
+    s: &str,
+    type_name: &'static str,
+) -> Result<T, ParseEnumError> {
+    serde_json::from_value(serde_json::Value::String(s.to_string())).map_err(|_| ParseEnumError {
+        type_name,
+        value: s.to_string(),
+    })
+}
+
 /// A document format supported by document backend parsers.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -32,6 +59,65 @@ impl std::fmt::Display for InputFormat {
     }
 }
 
+impl std::str::FromStr for InputFormat {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_wire_value(s, "InputFormat")
+    }
+}
+
+impl InputFormat {
+    /// Whether this is a raster image input (PNG, JPEG, TIFF, ...).
+    pub fn is_image(&self) -> bool {
+        matches!(self, InputFormat::Image)
+    }
+
+    /// Whether this is an audio input, transcribed by the ASR pipeline.
+    pub fn is_audio(&self) -> bool {
+        matches!(self, InputFormat::Audio)
+    }
+
+    /// Whether this is an Office Open XML document (DOCX, PPTX, XLSX).
+    pub fn is_office(&self) -> bool {
+        matches!(
+            self,
+            InputFormat::Docx | InputFormat::Pptx | InputFormat::Xlsx
+        )
+    }
+
+    /// Guess the input format from a file extension (without the dot,
+    /// case-insensitive). Returns `None` for unknown extensions.
+    ///
+    /// XML is ambiguous (USPTO, JATS, METS) and is not guessed.
+    pub fn from_extension(ext: &str) -> Option<InputFormat> {
+        let format = match ext.to_ascii_lowercase().as_str() {
+            "pdf" => InputFormat::Pdf,
+            "docx" | "docm" | "dotx" => InputFormat::Docx,
+            "pptx" | "pptm" | "potx" => InputFormat::Pptx,
+            "xlsx" | "xlsm" => InputFormat::Xlsx,
+            "html" | "htm" | "xhtml" => InputFormat::Html,
+            "png" | "jpg" | "jpeg" | "tif" | "tiff" | "bmp" | "webp" => InputFormat::Image,
+            "adoc" | "asciidoc" | "asc" => InputFormat::Asciidoc,
+            "md" | "markdown" => InputFormat::Md,
+            "csv" => InputFormat::Csv,
+            "json" => InputFormat::JsonDocling,
+            "wav" | "mp3" => InputFormat::Audio,
+            "vtt" => InputFormat::Vtt,
+            _ => return None,
+        };
+        Some(format)
+    }
+
+    /// Guess the input format from a path's extension, as
+    /// [`from_extension`](Self::from_extension) does. Returns `None` when the
+    /// path has no extension or it is not recognized.
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Option<InputFormat> {
+        let ext = path.as_ref().extension()?.to_str()?;
+        Self::from_extension(ext)
+    }
+}
+
 /// Output format for document conversion.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -53,6 +139,32 @@ impl std::fmt::Display for OutputFormat {
     }
 }
 
+impl std::str::FromStr for OutputFormat {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_wire_value(s, "OutputFormat")
+    }
+}
+
+impl OutputFormat {
+    /// Whether the output is human-readable text or markup (Markdown, HTML,
+    /// plain text, YAML, DocTags) rather than structured JSON.
+    pub fn is_textual(&self) -> bool {
+        !self.is_structured()
+    }
+
+    /// Whether the output is the structured JSON `DoclingDocument`.
+    pub fn is_structured(&self) -> bool {
+        matches!(self, OutputFormat::Json)
+    }
+
+    /// Whether the output is HTML (single page or split by page).
+    pub fn is_html(&self) -> bool {
+        matches!(self, OutputFormat::Html | OutputFormat::HtmlSplitPage)
+    }
+}
+
 /// Image export mode for the document.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -69,6 +181,14 @@ impl std::fmt::Display for ImageRefMode {
     }
 }
 
+impl std::str::FromStr for ImageRefMode {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_wire_value(s, "ImageRefMode")
+    }
+}
+
 /// Table structure extraction mode.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -84,6 +204,14 @@ impl std::fmt::Display for TableFormerMode {
     }
 }
 
+impl std::str::FromStr for TableFormerMode {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_wire_value(s, "TableFormerMode")
+    }
+}
+
 /// Available PDF parsing backends.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -104,6 +232,14 @@ impl std::fmt::Display for PdfBackend {
     }
 }
 
+impl std::str::FromStr for PdfBackend {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_wire_value(s, "PdfBackend")
+    }
+}
+
 /// Available document processing pipeline types.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -121,6 +257,14 @@ impl std::fmt::Display for ProcessingPipeline {
     }
 }
 
+impl std::str::FromStr for ProcessingPipeline {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_wire_value(s, "ProcessingPipeline")
+    }
+}
+
 /// OCR engine options.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -140,6 +284,14 @@ impl std::fmt::Display for OcrEngine {
     }
 }
 
+impl std::str::FromStr for OcrEngine {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_wire_value(s, "OcrEngine")
+    }
+}
+
 /// Status of a document conversion.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -159,6 +311,102 @@ impl std::fmt::Display for ConversionStatus {
     }
 }
 
+/// Status of an async task, as reported in
+/// [`TaskStatusResponse::task_status`](crate::models::responses::TaskStatusResponse::task_status).
+///
+/// Known statuses are matched case-insensitively and serialize in upper
+/// case. Any other value is kept verbatim in [`TaskStatus::Other`], so a
+/// newer server never breaks deserialization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TaskStatus {
+    Pending,
+    Started,
+    Success,
+    /// Finished, but some documents failed; the result lists the errors.
+    PartialSuccess,
+    /// Finished without converting anything.
+    Skipped,
+    Failure,
+    /// A status this version of the client does not know.
+    Other(String),
+}
+
+impl TaskStatus {
+    /// The wire value, e.g. `"PENDING"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TaskStatus::Pending => "PENDING",
+            TaskStatus::Started => "STARTED",
+            TaskStatus::Success => "SUCCESS",
+            TaskStatus::PartialSuccess => "PARTIAL_SUCCESS",
+            TaskStatus::Skipped => "SKIPPED",
+            TaskStatus::Failure => "FAILURE",
+            TaskStatus::Other(status) => status,
+        }
+    }
+
+    /// Whether the task has finished, successfully or not.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            TaskStatus::Success
+                | TaskStatus::PartialSuccess
+                | TaskStatus::Skipped
+                | TaskStatus::Failure
+        )
+    }
+}
+
+impl From<String> for TaskStatus {
+    fn from(status: String) -> Self {
+        match status.to_ascii_uppercase().as_str() {
+            "PENDING" => TaskStatus::Pending,
+            "STARTED" => TaskStatus::Started,
+            "SUCCESS" => TaskStatus::Success,
+            "PARTIAL_SUCCESS" => TaskStatus::PartialSuccess,
+            "SKIPPED" => TaskStatus::Skipped,
+            "FAILURE" => TaskStatus::Failure,
+            _ => TaskStatus::Other(status),
+        }
+    }
+}
+
+impl From<&str> for TaskStatus {
+    fn from(status: &str) -> Self {
+        TaskStatus::from(status.to_string())
+    }
+}
+
+impl From<TaskStatus> for String {
+    fn from(status: TaskStatus) -> Self {
+        match status {
+            TaskStatus::Other(status) => status,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Compares against a wire value, ignoring case, so `status == "SUCCESS"`
+/// keeps working.
+impl PartialEq<str> for TaskStatus {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+}
+
+impl PartialEq<&str> for TaskStatus {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
 /// Docling component types (for error reporting).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -170,6 +418,13 @@ pub enum DoclingComponentType {
     Pipeline,
 }
 
+impl std::fmt::Display for DoclingComponentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = serde_json::to_value(self).unwrap();
+        write!(f, "{}", s.as_str().unwrap())
+    }
+}
+
 /// Profiling scope.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -186,6 +441,13 @@ pub enum TaskType {
     Chunk,
 }
 
+impl std::fmt::Display for TaskType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = serde_json::to_value(self).unwrap();
+        write!(f, "{}", s.as_str().unwrap())
+    }
+}
+
 /// VLM model type presets.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -216,23 +478,26 @@ impl std::fmt::Display for VlmModelType {
     }
 }
 
+impl std::str::FromStr for VlmModelType {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_wire_value(s, "VlmModelType")
+    }
+}
+
 /// Flat string enum for the target type in multipart form requests.
 ///
 /// Used as a simple string form field in `/v1/convert/file` (multipart),
 /// as opposed to the tagged `Target` union used in JSON request bodies.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum TargetName {
+    #[default]
     Inbody,
     Zip,
 }
 
-impl Default for TargetName {
-    fn default() -> Self {
-        TargetName::Inbody
-    }
-}
-
 impl std::fmt::Display for TargetName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -241,3 +506,110 @@ impl std::fmt::Display for TargetName {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn task_status_parses_known_and_unknown_values() {
+        let parsed: TaskStatus = serde_json::from_str(r#""SUCCESS""#).unwrap();
+        assert_eq!(parsed, TaskStatus::Success);
+        let parsed: TaskStatus = serde_json::from_str(r#""started""#).unwrap();
+        assert_eq!(parsed, TaskStatus::Started);
+        let parsed: TaskStatus = serde_json::from_str(r#""REVOKED""#).unwrap();
+        assert_eq!(parsed, TaskStatus::Other("REVOKED".to_string()));
+
+        assert_eq!(
+            serde_json::to_string(&TaskStatus::Pending).unwrap(),
+            r#""PENDING""#
+        );
+        assert_eq!(
+            serde_json::to_string(&TaskStatus::Other("RETRY".to_string())).unwrap(),
+            r#""RETRY""#
+        );
+        assert_eq!(TaskStatus::Failure, "failure");
+        assert!(TaskStatus::Failure.is_terminal());
+        let parsed: TaskStatus = serde_json::from_str(r#""partial_success""#).unwrap();
+        assert_eq!(parsed, TaskStatus::PartialSuccess);
+        assert!(parsed.is_terminal());
+        assert!(!TaskStatus::Other("RETRY".to_string()).is_terminal());
+    }
+
+    #[test]
+    fn output_format_textual_vs_structured() {
+        for fmt in [
+            OutputFormat::Md,
+            OutputFormat::Yaml,
+            OutputFormat::Html,
+            OutputFormat::HtmlSplitPage,
+            OutputFormat::Text,
+            OutputFormat::Doctags,
+        ] {
+            assert!(fmt.is_textual(), "{fmt} should be textual");
+            assert!(!fmt.is_structured(), "{fmt} should not be structured");
+        }
+        assert!(OutputFormat::Json.is_structured());
+        assert!(!OutputFormat::Json.is_textual());
+    }
+
+    #[test]
+    fn output_format_is_html() {
+        assert!(OutputFormat::Html.is_html());
+        assert!(OutputFormat::HtmlSplitPage.is_html());
+        assert!(!OutputFormat::Md.is_html());
+    }
+
+    #[test]
+    fn input_format_categories() {
+        assert!(InputFormat::Image.is_image());
+        assert!(!InputFormat::Pdf.is_image());
+
+        assert!(InputFormat::Audio.is_audio());
+        assert!(!InputFormat::Vtt.is_audio());
+
+        assert!(InputFormat::Docx.is_office());
+        assert!(InputFormat::Pptx.is_office());
+        assert!(InputFormat::Xlsx.is_office());
+        assert!(!InputFormat::Pdf.is_office());
+        assert!(!InputFormat::Csv.is_office());
+    }
+
+    #[test]
+    fn input_format_from_extension() {
+        assert_eq!(InputFormat::from_extension("pdf"), Some(InputFormat::Pdf));
+        assert_eq!(InputFormat::from_extension("PDF"), Some(InputFormat::Pdf));
+        assert_eq!(
+            InputFormat::from_extension("jpeg"),
+            Some(InputFormat::Image)
+        );
+        assert_eq!(InputFormat::from_extension("htm"), Some(InputFormat::Html));
+        assert_eq!(InputFormat::from_extension("xml"), None);
+        assert_eq!(InputFormat::from_extension("01062"), None);
+    }
+
+    #[test]
+    fn input_format_from_path_maps_every_image_extension_to_image() {
+        for name in [
+            "scan.png",
+            "scan.jpg",
+            "scan.JPEG",
+            "scan.tif",
+            "scan.tiff",
+            "scan.bmp",
+            "scan.webp",
+        ] {
+            assert_eq!(
+                InputFormat::from_path(name),
+                Some(InputFormat::Image),
+                "{name}"
+            );
+        }
+        assert_eq!(
+            InputFormat::from_path("dir.v2/report.docx"),
+            Some(InputFormat::Docx)
+        );
+        assert_eq!(InputFormat::from_path("dir.v2/README"), None);
+        assert_eq!(InputFormat::from_path("notes.txt"), None);
+    }
+}
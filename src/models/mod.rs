@@ -6,11 +6,14 @@
 //! - [`enums`] — All API enums (InputFormat, OutputFormat, ConversionStatus, etc.)
 //! - [`requests`] — Request types (Source, Target, ConvertDocumentsRequest, options)
 //! - [`responses`] — Response types (ConvertDocumentResponse, TaskStatusResponse, etc.)
+//! - [`document`] — Typed subset of `DoclingDocument` (the `json_content` output)
 
+pub mod document;
 pub mod enums;
 pub mod requests;
 pub mod responses;
 
+pub use document::*;
 pub use enums::*;
 pub use requests::*;
 pub use responses::*;
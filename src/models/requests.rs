@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use super::enums::*;
+use crate::error::DoclingError;
 
 // ============================================================================
 // Source types (discriminated union on "kind")
@@ -31,29 +34,616 @@ pub enum Source {
     },
 }
 
+impl Source {
+    /// Read a local file into a [`Source::File`], base64-encoding its
+    /// contents and taking `filename` from the path's file name.
+    ///
+    /// Use this to send local files through the JSON `/v1/convert/source`
+    /// endpoints. For large files prefer the multipart `convert_file`
+    /// methods; see
+    /// [`DoclingClient::recommended_upload_mode`](crate::DoclingClient::recommended_upload_mode).
+    ///
+    /// Read failures return [`DoclingError::Io`].
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example() -> Result<(), docling_rs::DoclingError> {
+    /// use docling_rs::Source;
+    /// let source = Source::from_path("./document.pdf").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<Self, DoclingError> {
+        let path = path.as_ref();
+        let bytes = tokio::fs::read(path).await?;
+        Ok(Source::from_bytes(file_name_of(path), &bytes))
+    }
+
+    /// Base64-encode an in-memory document into a [`Source::File`].
+    pub fn from_bytes(filename: impl Into<String>, bytes: &[u8]) -> Self {
+        use base64::Engine;
+
+        Source::File {
+            base64_string: base64::engine::general_purpose::STANDARD.encode(bytes),
+            filename: filename.into(),
+        }
+    }
+
+    /// A [`Source::Http`] for `url`, rejecting anything that is not an
+    /// absolute `http` or `https` URL before it reaches the server, whose
+    /// fetch error for e.g. a local path is much less clear.
+    pub(crate) fn from_http_url(url: &str) -> Result<Self, DoclingError> {
+        match reqwest::Url::parse(url) {
+            Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(Source::Http {
+                url: url.to_string(),
+                headers: None,
+            }),
+            _ => Err(DoclingError::InvalidOptions {
+                reason: format!(
+                    "{:?} is not an http(s) URL; use convert_file or convert_bytes for local files",
+                    url
+                ),
+            }),
+        }
+    }
+
+    /// Size in bytes of the base64 string for a [`Source::File`] holding a
+    /// file of `file_len` bytes: `4 * ceil(file_len / 3)`, roughly 33% more
+    /// than the file itself.
+    pub fn encoded_size(file_len: u64) -> u64 {
+        file_len.div_ceil(3) * 4
+    }
+
+    /// Guess the input format from the file extension of the filename or
+    /// the URL path. Returns `None` when there is no recognizable extension,
+    /// e.g. `https://arxiv.org/pdf/2206.01062`.
+    pub fn guess_format(&self) -> Option<InputFormat> {
+        let name = match self {
+            Source::Http { url, .. } => {
+                let path = url.split(['?', '#']).next().unwrap_or_default();
+                path.rsplit('/').next().unwrap_or_default()
+            }
+            Source::File { filename, .. } => filename.as_str(),
+        };
+        let (_, ext) = name.rsplit_once('.')?;
+        InputFormat::from_extension(ext)
+    }
+}
+
+/// The file name of `path`, or `"file"` if it has none.
+fn file_name_of(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "file".to_string())
+}
+
+/// One document to convert, for
+/// [`DoclingClient::convert_input`](crate::DoclingClient::convert_input).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// A document the server fetches over HTTP.
+    Url(String),
+    /// A local file, read when the request is built.
+    Path(PathBuf),
+    /// A document already in memory. `filename` supplies the extension the
+    /// server uses to detect the format.
+    Bytes { filename: String, data: Vec<u8> },
+}
+
+impl Input {
+    /// A document the server fetches from `url`.
+    pub fn url(url: impl Into<String>) -> Self {
+        Input::Url(url.into())
+    }
+
+    /// A local file at `path`.
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        Input::Path(path.into())
+    }
+
+    /// An in-memory document named `filename`.
+    pub fn bytes(filename: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Input::Bytes {
+            filename: filename.into(),
+            data: data.into(),
+        }
+    }
+
+    /// The JSON source for this input, base64-encoding file contents.
+    pub(crate) async fn into_source(self) -> Result<Source, DoclingError> {
+        match self {
+            Input::Url(url) => Source::from_http_url(&url),
+            Input::Path(path) => Source::from_path(path).await,
+            Input::Bytes { filename, data } => Ok(Source::from_bytes(filename, &data)),
+        }
+    }
+
+    /// The `(filename, bytes)` of a file input, reading paths from disk.
+    /// URLs have no content to upload; callers route them elsewhere.
+    pub(crate) async fn into_named_bytes(self) -> Result<(String, Vec<u8>), DoclingError> {
+        match self {
+            Input::Url(url) => Err(DoclingError::InvalidOptions {
+                reason: format!("{} is a URL, not a file", url),
+            }),
+            Input::Path(path) => {
+                let bytes = tokio::fs::read(&path).await?;
+                Ok((file_name_of(&path), bytes))
+            }
+            Input::Bytes { filename, data } => Ok((filename, data)),
+        }
+    }
+}
+
+/// How to send a local file, as recommended by
+/// [`DoclingClient::recommended_upload_mode`](crate::DoclingClient::recommended_upload_mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadMode {
+    /// Inline as a base64 [`Source::File`] in a JSON request.
+    Inline,
+    /// As a `multipart/form-data` upload (`convert_file`).
+    Multipart,
+}
+
 // ============================================================================
 // Target types (discriminated union on "kind")
 // ============================================================================
 
 /// Where to deliver the conversion result — discriminated union on `kind`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(tag = "kind")]
 pub enum Target {
     /// Return results in the response body (default).
+    #[default]
     #[serde(rename = "inbody")]
     InBody,
 
     /// Return results as a ZIP archive.
     #[serde(rename = "zip")]
     Zip,
+
+    /// Upload results to an S3-compatible bucket. The response only reports
+    /// counts; see
+    /// [`DoclingClient::convert_to_s3`](crate::DoclingClient::convert_to_s3).
+    #[serde(rename = "s3")]
+    S3(S3Target),
+}
+
+/// S3-compatible bucket that Docling Serve uploads results to.
+///
+/// The `Debug` output redacts both keys.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct S3Target {
+    /// S3 endpoint, e.g. `s3.eu-west-1.amazonaws.com` or a MinIO host.
+    pub endpoint: String,
+
+    /// Verify the endpoint's TLS certificate. Defaults to `true`.
+    #[serde(default = "default_true")]
+    pub verify_ssl: bool,
+
+    /// Access key ID.
+    pub access_key: String,
+
+    /// Secret access key.
+    pub secret_key: String,
+
+    /// Destination bucket.
+    pub bucket: String,
+
+    /// Prefix prepended to every uploaded object key.
+    #[serde(default)]
+    pub key_prefix: String,
+
+    /// `"path"` or `"virtual"` bucket addressing. The server picks when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub addressing_style: Option<String>,
+}
+
+impl S3Target {
+    /// Target `bucket` at `endpoint` with the given credentials, verifying TLS
+    /// and using no key prefix.
+    pub fn new(
+        endpoint: impl Into<String>,
+        bucket: impl Into<String>,
+        access_key: impl Into<String>,
+        secret_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            verify_ssl: true,
+            access_key: access_key.into(),
+            secret_key: secret_key.into(),
+            bucket: bucket.into(),
+            key_prefix: String::new(),
+            addressing_style: None,
+        }
+    }
+
+    /// Set the prefix prepended to every uploaded object key.
+    pub fn key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.key_prefix = key_prefix.into();
+        self
+    }
+}
+
+impl std::fmt::Debug for S3Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("S3Target")
+            .field("endpoint", &self.endpoint)
+            .field("verify_ssl", &self.verify_ssl)
+            .field("access_key", &"[REDACTED]")
+            .field("secret_key", &"[REDACTED]")
+            .field("bucket", &self.bucket)
+            .field("key_prefix", &self.key_prefix)
+            .field("addressing_style", &self.addressing_style)
+            .finish()
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+// ============================================================================
+// Page ranges
+// ============================================================================
+
+/// Pages to convert, for [`OptionsBuilder::pages`]. Pages start at 1.
+///
+/// The server takes a `[start, end]` array; [`bounds`](Self::bounds) gives
+/// that pair, using `i64::MAX` as the end of an open range. A plain
+/// `(start, end)` tuple converts into [`PageRange::Range`].
+///
+/// ```rust
+/// use docling_rs::{ConvertDocumentsRequestOptions, PageRange};
+///
+/// let options = ConvertDocumentsRequestOptions::builder()
+///     .pages(PageRange::From(5))
+///     .build()?;
+/// assert_eq!(options.page_range, Some((5, i64::MAX)));
+/// # Ok::<(), docling_rs::DoclingError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageRange {
+    /// Pages `start..=end`.
+    Range(i64, i64),
+    /// Page `start` through the end of the document.
+    From(i64),
+    /// A single page.
+    Single(i64),
+}
+
+impl PageRange {
+    /// The `(start, end)` pair sent to the server.
+    pub fn bounds(self) -> (i64, i64) {
+        match self {
+            PageRange::Range(start, end) => (start, end),
+            PageRange::From(start) => (start, i64::MAX),
+            PageRange::Single(page) => (page, page),
+        }
+    }
+}
+
+impl From<(i64, i64)> for PageRange {
+    fn from((start, end): (i64, i64)) -> Self {
+        PageRange::Range(start, end)
+    }
+}
+
+impl From<PageRange> for (i64, i64) {
+    fn from(range: PageRange) -> Self {
+        range.bounds()
+    }
+}
+
+// ============================================================================
+// Picture description and VLM model options
+// ============================================================================
+
+/// A local vision-language model for picture description, for
+/// [`ConvertDocumentsRequestOptions::picture_description_local`].
+///
+/// Unset fields are omitted so the server defaults apply.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PictureDescriptionLocal {
+    /// Hugging Face model repository, e.g. `HuggingFaceTB/SmolVLM-256M-Instruct`.
+    pub repo_id: String,
+
+    /// Prompt sent with each picture.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+
+    /// Generation settings passed to the model, e.g. `max_new_tokens`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generation_config: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl PictureDescriptionLocal {
+    /// Describe pictures with the model in `repo_id`.
+    pub fn new(repo_id: impl Into<String>) -> Self {
+        Self {
+            repo_id: repo_id.into(),
+            prompt: None,
+            generation_config: None,
+        }
+    }
+
+    /// Set the prompt sent with each picture.
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+}
+
+/// An OpenAI-compatible chat completions endpoint for picture description,
+/// for [`ConvertDocumentsRequestOptions::picture_description_api`].
+///
+/// Unset fields are omitted so the server defaults apply. The `Debug` output
+/// redacts header values, which often carry credentials.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct PictureDescriptionApi {
+    /// Endpoint URL, e.g. `http://localhost:8000/v1/chat/completions`.
+    pub url: String,
+
+    /// Headers sent with each call, e.g. `Authorization`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+
+    /// Extra body parameters, e.g. `{"model": "granite3.2-vision:2b"}`.
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub params: serde_json::Map<String, serde_json::Value>,
+
+    /// Per-call timeout in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<f64>,
+
+    /// Number of concurrent calls.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<u32>,
+
+    /// Prompt sent with each picture.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+}
+
+impl PictureDescriptionApi {
+    /// Describe pictures by calling the endpoint at `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: HashMap::new(),
+            params: serde_json::Map::new(),
+            timeout: None,
+            concurrency: None,
+            prompt: None,
+        }
+    }
+
+    /// Add a header sent with each call.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Add an extra body parameter, e.g. `("model", json!("granite3.2-vision:2b"))`.
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.params.insert(name.into(), value.into());
+        self
+    }
+
+    /// Set the prompt sent with each picture.
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+
+    /// Set the per-call timeout in seconds.
+    pub fn timeout(mut self, secs: f64) -> Self {
+        self.timeout = Some(secs);
+        self
+    }
+}
+
+impl std::fmt::Debug for PictureDescriptionApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PictureDescriptionApi")
+            .field("url", &self.url)
+            .field("headers", &redacted_headers(&self.headers))
+            .field("params", &self.params)
+            .field("timeout", &self.timeout)
+            .field("concurrency", &self.concurrency)
+            .field("prompt", &self.prompt)
+            .finish()
+    }
+}
+
+/// A local vision-language model for the VLM pipeline, for
+/// [`ConvertDocumentsRequestOptions::vlm_pipeline_model_local`].
+///
+/// Unset fields are omitted so the server defaults apply.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VlmModelLocal {
+    /// Hugging Face model repository, e.g. `ibm-granite/granite-docling-258M`.
+    pub repo_id: String,
+
+    /// Format the model answers in, e.g. `doctags` or `markdown`.
+    pub response_format: String,
+
+    /// Runtime to load the model with, e.g. `transformers`, `mlx` or `vllm`.
+    pub inference_framework: String,
+
+    /// Prompt sent with each page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+
+    /// Scale applied to page images before they are sent to the model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<f64>,
+
+    /// Transformers model class, e.g. `automodel-vision2seq`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transformers_model_type: Option<String>,
+
+    /// Extra generation settings, e.g. `max_new_tokens`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra_generation_config: Option<serde_json::Map<String, serde_json::Value>>,
+
+    /// Sampling temperature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+}
+
+impl VlmModelLocal {
+    /// Run the model in `repo_id` with `inference_framework`, expecting
+    /// answers in `response_format`.
+    pub fn new(
+        repo_id: impl Into<String>,
+        response_format: impl Into<String>,
+        inference_framework: impl Into<String>,
+    ) -> Self {
+        Self {
+            repo_id: repo_id.into(),
+            response_format: response_format.into(),
+            inference_framework: inference_framework.into(),
+            prompt: None,
+            scale: None,
+            transformers_model_type: None,
+            extra_generation_config: None,
+            temperature: None,
+        }
+    }
+
+    /// Set the prompt sent with each page.
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+}
+
+/// An OpenAI-compatible chat completions endpoint for the VLM pipeline, for
+/// [`ConvertDocumentsRequestOptions::vlm_pipeline_model_api`].
+///
+/// Unset fields are omitted so the server defaults apply. The `Debug` output
+/// redacts header values, which often carry credentials.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct VlmModelApi {
+    /// Endpoint URL, e.g. `http://localhost:8000/v1/chat/completions`.
+    pub url: String,
+
+    /// Format the model answers in, e.g. `doctags` or `markdown`.
+    pub response_format: String,
+
+    /// Headers sent with each call, e.g. `Authorization`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+
+    /// Extra body parameters, e.g. `{"model": "granite-docling"}`.
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub params: serde_json::Map<String, serde_json::Value>,
+
+    /// Per-call timeout in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<f64>,
+
+    /// Number of concurrent calls.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<u32>,
+
+    /// Prompt sent with each page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+
+    /// Scale applied to page images before they are sent to the model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<f64>,
+}
+
+impl VlmModelApi {
+    /// Call the endpoint at `url`, expecting answers in `response_format`.
+    pub fn new(url: impl Into<String>, response_format: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            response_format: response_format.into(),
+            headers: HashMap::new(),
+            params: serde_json::Map::new(),
+            timeout: None,
+            concurrency: None,
+            prompt: None,
+            scale: None,
+        }
+    }
+
+    /// Add a header sent with each call.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Add an extra body parameter, e.g. `("model", json!("granite-docling"))`.
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.params.insert(name.into(), value.into());
+        self
+    }
+
+    /// Set the prompt sent with each page.
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+}
+
+impl std::fmt::Debug for VlmModelApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VlmModelApi")
+            .field("url", &self.url)
+            .field("response_format", &self.response_format)
+            .field("headers", &redacted_headers(&self.headers))
+            .field("params", &self.params)
+            .field("timeout", &self.timeout)
+            .field("concurrency", &self.concurrency)
+            .field("prompt", &self.prompt)
+            .field("scale", &self.scale)
+            .finish()
+    }
+}
+
+/// Header names with every value replaced, for `Debug` output.
+fn redacted_headers(headers: &HashMap<String, String>) -> HashMap<&str, &str> {
+    headers
+        .keys()
+        .map(|name| (name.as_str(), "[REDACTED]"))
+        .collect()
+}
+
+impl From<PictureDescriptionLocal> for serde_json::Value {
+    fn from(options: PictureDescriptionLocal) -> Self {
+        model_options_value(&options)
+    }
+}
+
+impl From<PictureDescriptionApi> for serde_json::Value {
+    fn from(options: PictureDescriptionApi) -> Self {
+        model_options_value(&options)
+    }
+}
+
+impl From<VlmModelLocal> for serde_json::Value {
+    fn from(options: VlmModelLocal) -> Self {
+        model_options_value(&options)
+    }
 }
 
-impl Default for Target {
-    fn default() -> Self {
-        Target::InBody
+impl From<VlmModelApi> for serde_json::Value {
+    fn from(options: VlmModelApi) -> Self {
+        model_options_value(&options)
     }
 }
 
+/// Serialize model options. Their fields are strings, numbers and
+/// string-keyed maps, so this cannot fail.
+fn model_options_value<T: Serialize>(options: &T) -> serde_json::Value {
+    serde_json::to_value(options).expect("model options serialize to JSON")
+}
+
 // ============================================================================
 // Request options
 // ============================================================================
@@ -71,6 +661,11 @@ pub struct ConvertDocumentsRequestOptions {
     pub to_formats: Option<Vec<OutputFormat>>,
 
     /// Image export mode. Defaults to `embedded`.
+    ///
+    /// The server applies a single mode to every format in `to_formats`;
+    /// there is no per-format setting. To get embedded images in Markdown and
+    /// referenced images in JSON, send two requests. See
+    /// [`warnings`](Self::warnings) for detecting a likely-wrong combination.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_export_mode: Option<ImageRefMode>,
 
@@ -91,6 +686,11 @@ pub struct ConvertDocumentsRequestOptions {
     pub ocr_lang: Option<Vec<String>>,
 
     /// PDF parsing backend. Defaults to `dlparse_v4`.
+    ///
+    /// Applies only to PDF inputs, and a single backend is used for every PDF
+    /// in the request: Docling Serve has no per-format backend setting. See
+    /// [`ConvertDocumentsRequest::warnings`] for detecting a backend that
+    /// would be ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pdf_backend: Option<PdfBackend>,
 
@@ -106,7 +706,9 @@ pub struct ConvertDocumentsRequestOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pipeline: Option<ProcessingPipeline>,
 
-    /// Page range to convert `[start, end]`. Pages start at 1.
+    /// Page range to convert `[start, end]`. Pages start at 1; an end of
+    /// `i64::MAX` means the last page. See [`PageRange`] for a friendlier
+    /// way to set it.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_range: Option<(i64, i64)>,
 
@@ -163,26 +765,580 @@ pub struct ConvertDocumentsRequestOptions {
     pub vlm_pipeline_model: Option<VlmModelType>,
 
     /// Options for running a local VLM for picture description.
-    /// Pass as a JSON object. Mutually exclusive with `picture_description_api`.
+    /// Pass a [`PictureDescriptionLocal`] or a raw JSON object.
+    /// Mutually exclusive with `picture_description_api`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub picture_description_local: Option<serde_json::Value>,
 
     /// API details for a VLM used for picture description.
-    /// Pass as a JSON object. Mutually exclusive with `picture_description_local`.
+    /// Pass a [`PictureDescriptionApi`] or a raw JSON object.
+    /// Mutually exclusive with `picture_description_local`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub picture_description_api: Option<serde_json::Value>,
 
     /// Options for running a local VLM for the VLM pipeline.
-    /// Pass as a JSON object. Mutually exclusive with `vlm_pipeline_model_api` and `vlm_pipeline_model`.
+    /// Pass a [`VlmModelLocal`] or a raw JSON object.
+    /// Mutually exclusive with `vlm_pipeline_model_api` and `vlm_pipeline_model`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vlm_pipeline_model_local: Option<serde_json::Value>,
 
     /// API details for a VLM used in the VLM pipeline.
-    /// Pass as a JSON object. Mutually exclusive with `vlm_pipeline_model_local` and `vlm_pipeline_model`.
+    /// Pass a [`VlmModelApi`] or a raw JSON object.
+    /// Mutually exclusive with `vlm_pipeline_model_local` and `vlm_pipeline_model`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vlm_pipeline_model_api: Option<serde_json::Value>,
 }
 
+/// A likely-unintended option combination, reported by
+/// [`ConvertDocumentsRequestOptions::warnings`].
+///
+/// Warnings never block a request; the server accepts these combinations but
+/// the result is often not what the caller expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OptionWarning {
+    /// JSON is requested alongside Markdown/HTML output, but the server
+    /// applies one `image_export_mode` to all of them. `mode` is `None` when
+    /// the server default (`embedded`) applies.
+    SharedImageExportMode { mode: Option<ImageRefMode> },
+
+    /// `pdf_backend` is set, but none of the inputs are PDFs, so the backend
+    /// is silently ignored.
+    PdfBackendWithoutPdf { backend: PdfBackend },
+}
+
+impl std::fmt::Display for OptionWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptionWarning::SharedImageExportMode { mode } => {
+                let mode = mode.as_ref().map_or_else(
+                    || "embedded (server default)".to_string(),
+                    |m| m.to_string(),
+                );
+                write!(
+                    f,
+                    "image_export_mode {} applies to every output format; \
+                     send separate requests to get different image modes for JSON and Markdown/HTML",
+                    mode
+                )
+            }
+            OptionWarning::PdfBackendWithoutPdf { backend } => write!(
+                f,
+                "pdf_backend {} is set but no input is a PDF; it will be ignored",
+                backend
+            ),
+        }
+    }
+}
+
+impl ConvertDocumentsRequestOptions {
+    /// Start building options with fluent setters. See [`OptionsBuilder`].
+    pub fn builder() -> OptionsBuilder {
+        OptionsBuilder::new()
+    }
+
+    /// Preset for quick conversions of digital documents.
+    ///
+    /// Sets `table_mode` to [`TableFormerMode::Fast`], turns `do_ocr` off
+    /// and uses the [`ProcessingPipeline::Standard`] pipeline. Scanned pages
+    /// come back without text; use [`accurate`](Self::accurate) for those.
+    /// Every other field is left unset, so it can be tweaked afterwards:
+    ///
+    /// ```rust
+    /// use docling_rs::{ConvertDocumentsRequestOptions, OutputFormat};
+    ///
+    /// let options = ConvertDocumentsRequestOptions {
+    ///     to_formats: Some(vec![OutputFormat::Md]),
+    ///     ..ConvertDocumentsRequestOptions::fast()
+    /// };
+    /// ```
+    pub fn fast() -> Self {
+        Self {
+            table_mode: Some(TableFormerMode::Fast),
+            do_ocr: Some(false),
+            pipeline: Some(ProcessingPipeline::Standard),
+            ..Default::default()
+        }
+    }
+
+    /// Preset for the best layout and table quality, at the cost of speed.
+    ///
+    /// Sets `table_mode` to [`TableFormerMode::Accurate`], turns `do_ocr` on
+    /// and uses the [`PdfBackend::DlparseV4`] PDF backend. Every other field
+    /// is left unset.
+    pub fn accurate() -> Self {
+        Self {
+            table_mode: Some(TableFormerMode::Accurate),
+            do_ocr: Some(true),
+            pdf_backend: Some(PdfBackend::DlparseV4),
+            ..Default::default()
+        }
+    }
+
+    /// Preset for text-only Markdown output.
+    ///
+    /// Sets `to_formats` to `[md]` and `include_images` to `false`, which
+    /// keeps responses small. Every other field is left unset.
+    pub fn markdown_only() -> Self {
+        Self {
+            to_formats: Some(vec![OutputFormat::Md]),
+            include_images: Some(false),
+            ..Default::default()
+        }
+    }
+
+    /// Build options from environment variables named `{prefix}_{FIELD}`.
+    ///
+    /// For example with prefix `"DOCLING"`, `DOCLING_TO_FORMATS=md,json` sets
+    /// `to_formats` and `DOCLING_OCR_ENGINE=tesseract` sets `ocr_engine`.
+    /// Field names are the upper-cased option names. List fields
+    /// (`FROM_FORMATS`, `TO_FORMATS`, `OCR_LANG`) are comma-separated,
+    /// booleans accept `true`/`false`/`1`/`0`/`yes`/`no`, and `PAGE_RANGE`
+    /// takes `start-end`. The JSON-valued picture description and VLM options
+    /// are not read.
+    ///
+    /// Unset or empty variables are ignored so the server default applies.
+    /// A value that fails to parse returns [`DoclingError::InvalidOptions`]
+    /// naming the variable.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use docling_rs::ConvertDocumentsRequestOptions;
+    /// let options = ConvertDocumentsRequestOptions::from_env("DOCLING")?;
+    /// # Ok::<(), docling_rs::DoclingError>(())
+    /// ```
+    pub fn from_env(prefix: &str) -> Result<Self, DoclingError> {
+        Self::from_lookup(prefix, |key| std::env::var(key).ok())
+    }
+
+    /// Shared implementation of [`from_env`](Self::from_env) over an arbitrary
+    /// variable lookup.
+    fn from_lookup(
+        prefix: &str,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, DoclingError> {
+        let prefix = prefix.trim_end_matches('_');
+        let var = |field: &str| {
+            let key = if prefix.is_empty() {
+                field.to_string()
+            } else {
+                format!("{}_{}", prefix, field)
+            };
+            let value = lookup(&key).map(|v| v.trim().to_string());
+            (key, value.filter(|v| !v.is_empty()))
+        };
+
+        fn invalid(key: &str, value: &str, err: impl std::fmt::Display) -> DoclingError {
+            DoclingError::InvalidOptions {
+                reason: format!("{}={:?}: {}", key, value, err),
+            }
+        }
+        fn parse<T: FromStr>(field: (String, Option<String>)) -> Result<Option<T>, DoclingError>
+        where
+            T::Err: std::fmt::Display,
+        {
+            let (key, Some(value)) = field else {
+                return Ok(None);
+            };
+            value
+                .parse()
+                .map(Some)
+                .map_err(|e| invalid(&key, &value, e))
+        }
+        fn parse_list<T: FromStr>(
+            field: (String, Option<String>),
+        ) -> Result<Option<Vec<T>>, DoclingError>
+        where
+            T::Err: std::fmt::Display,
+        {
+            let (key, Some(value)) = field else {
+                return Ok(None);
+            };
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| item.parse().map_err(|e| invalid(&key, &value, e)))
+                .collect::<Result<Vec<T>, _>>()
+                .map(Some)
+        }
+        fn parse_bool(field: (String, Option<String>)) -> Result<Option<bool>, DoclingError> {
+            let (key, Some(value)) = field else {
+                return Ok(None);
+            };
+            match value.to_ascii_lowercase().as_str() {
+                "true" | "1" | "yes" => Ok(Some(true)),
+                "false" | "0" | "no" => Ok(Some(false)),
+                _ => Err(invalid(&key, &value, "expected a boolean")),
+            }
+        }
+        fn parse_range(
+            field: (String, Option<String>),
+        ) -> Result<Option<(i64, i64)>, DoclingError> {
+            let (key, Some(value)) = field else {
+                return Ok(None);
+            };
+            let parsed = value.split_once('-').and_then(|(start, end)| {
+                Some((start.trim().parse().ok()?, end.trim().parse().ok()?))
+            });
+            parsed
+                .map(Some)
+                .ok_or_else(|| invalid(&key, &value, "expected `start-end`"))
+        }
+
+        Ok(Self {
+            from_formats: parse_list(var("FROM_FORMATS"))?,
+            to_formats: parse_list(var("TO_FORMATS"))?,
+            image_export_mode: parse(var("IMAGE_EXPORT_MODE"))?,
+            do_ocr: parse_bool(var("DO_OCR"))?,
+            force_ocr: parse_bool(var("FORCE_OCR"))?,
+            ocr_engine: parse(var("OCR_ENGINE"))?,
+            ocr_lang: parse_list(var("OCR_LANG"))?,
+            pdf_backend: parse(var("PDF_BACKEND"))?,
+            table_mode: parse(var("TABLE_MODE"))?,
+            table_cell_matching: parse_bool(var("TABLE_CELL_MATCHING"))?,
+            pipeline: parse(var("PIPELINE"))?,
+            page_range: parse_range(var("PAGE_RANGE"))?,
+            document_timeout: parse(var("DOCUMENT_TIMEOUT"))?,
+            abort_on_error: parse_bool(var("ABORT_ON_ERROR"))?,
+            do_table_structure: parse_bool(var("DO_TABLE_STRUCTURE"))?,
+            include_images: parse_bool(var("INCLUDE_IMAGES"))?,
+            images_scale: parse(var("IMAGES_SCALE"))?,
+            md_page_break_placeholder: var("MD_PAGE_BREAK_PLACEHOLDER").1,
+            do_code_enrichment: parse_bool(var("DO_CODE_ENRICHMENT"))?,
+            do_formula_enrichment: parse_bool(var("DO_FORMULA_ENRICHMENT"))?,
+            do_picture_classification: parse_bool(var("DO_PICTURE_CLASSIFICATION"))?,
+            do_chart_extraction: parse_bool(var("DO_CHART_EXTRACTION"))?,
+            do_picture_description: parse_bool(var("DO_PICTURE_DESCRIPTION"))?,
+            picture_description_area_threshold: parse(var("PICTURE_DESCRIPTION_AREA_THRESHOLD"))?,
+            vlm_pipeline_model: parse(var("VLM_PIPELINE_MODEL"))?,
+            ..Default::default()
+        })
+    }
+
+    /// Reject option combinations the server does not accept.
+    ///
+    /// Returns [`DoclingError::InvalidOptions`], naming the conflicting
+    /// fields, when both `picture_description_local` and
+    /// `picture_description_api` are set, when more than one of
+    /// `vlm_pipeline_model`, `vlm_pipeline_model_local` and
+    /// `vlm_pipeline_model_api` is set, or when `page_range` ends before it
+    /// starts. The client calls this before sending any conversion request,
+    /// so conflicts fail locally instead of with a server `422`.
+    pub fn validate(&self) -> Result<(), DoclingError> {
+        let exclusive = |fields: &[(&str, bool)]| {
+            let set: Vec<&str> = fields
+                .iter()
+                .filter(|(_, is_set)| *is_set)
+                .map(|(name, _)| *name)
+                .collect();
+            if set.len() > 1 {
+                return Err(DoclingError::InvalidOptions {
+                    reason: format!("{} are mutually exclusive", set.join(" and ")),
+                });
+            }
+            Ok(())
+        };
+
+        exclusive(&[
+            (
+                "picture_description_local",
+                self.picture_description_local.is_some(),
+            ),
+            (
+                "picture_description_api",
+                self.picture_description_api.is_some(),
+            ),
+        ])?;
+        exclusive(&[
+            ("vlm_pipeline_model", self.vlm_pipeline_model.is_some()),
+            (
+                "vlm_pipeline_model_local",
+                self.vlm_pipeline_model_local.is_some(),
+            ),
+            (
+                "vlm_pipeline_model_api",
+                self.vlm_pipeline_model_api.is_some(),
+            ),
+        ])?;
+
+        if let Some((start, end)) = self.page_range {
+            if end < start {
+                return Err(DoclingError::InvalidOptions {
+                    reason: format!("page_range ends before it starts: {}..={}", start, end),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check for option combinations that are accepted but likely wrong.
+    ///
+    /// Reports [`OptionWarning::SharedImageExportMode`] when `to_formats`
+    /// mixes JSON with Markdown or HTML and `image_export_mode` is not
+    /// `placeholder`: embedded images bloat the JSON with base64, while
+    /// referenced images leave in-body Markdown pointing at files that were
+    /// never delivered.
+    ///
+    /// Reports [`OptionWarning::PdfBackendWithoutPdf`] when `pdf_backend` is
+    /// set but `from_formats` excludes PDF. Use
+    /// [`ConvertDocumentsRequest::warnings`] to also check the sources.
+    pub fn warnings(&self) -> Vec<OptionWarning> {
+        let mut warnings = Vec::new();
+
+        if let Some(formats) = &self.to_formats {
+            let has_json = formats.contains(&OutputFormat::Json);
+            let has_markup = formats
+                .iter()
+                .any(|f| matches!(f, OutputFormat::Md) || f.is_html());
+            let images_exported = self.image_export_mode != Some(ImageRefMode::Placeholder);
+            if has_json && has_markup && images_exported {
+                warnings.push(OptionWarning::SharedImageExportMode {
+                    mode: self.image_export_mode.clone(),
+                });
+            }
+        }
+
+        if let (Some(backend), Some(formats)) = (&self.pdf_backend, &self.from_formats) {
+            if !formats.contains(&InputFormat::Pdf) {
+                warnings.push(OptionWarning::PdfBackendWithoutPdf {
+                    backend: backend.clone(),
+                });
+            }
+        }
+
+        warnings
+    }
+}
+
+// ============================================================================
+// Options builder
+// ============================================================================
+
+/// Fluent builder for [`ConvertDocumentsRequestOptions`], created via
+/// [`ConvertDocumentsRequestOptions::builder`].
+///
+/// Every setter maps to the field of the same name; fields left unset are
+/// omitted from the request so the server default applies.
+///
+/// ```rust
+/// use docling_rs::{ConvertDocumentsRequestOptions, OutputFormat};
+///
+/// let options = ConvertDocumentsRequestOptions::builder()
+///     .to_formats([OutputFormat::Md, OutputFormat::Json])
+///     .do_ocr(true)
+///     .ocr_lang(["en", "fr"])
+///     .page_range(1, 10)
+///     .build()?;
+/// # Ok::<(), docling_rs::DoclingError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OptionsBuilder {
+    options: ConvertDocumentsRequestOptions,
+}
+
+impl OptionsBuilder {
+    /// Create a builder with every option unset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Input format(s) to convert from.
+    pub fn from_formats(mut self, formats: impl IntoIterator<Item = InputFormat>) -> Self {
+        self.options.from_formats = Some(formats.into_iter().collect());
+        self
+    }
+
+    /// Output format(s) to convert to.
+    pub fn to_formats(mut self, formats: impl IntoIterator<Item = OutputFormat>) -> Self {
+        self.options.to_formats = Some(formats.into_iter().collect());
+        self
+    }
+
+    /// Image export mode, applied to every output format.
+    pub fn image_export_mode(mut self, mode: ImageRefMode) -> Self {
+        self.options.image_export_mode = Some(mode);
+        self
+    }
+
+    /// Enable or disable OCR.
+    pub fn do_ocr(mut self, enabled: bool) -> Self {
+        self.options.do_ocr = Some(enabled);
+        self
+    }
+
+    /// Force OCR over existing text.
+    pub fn force_ocr(mut self, enabled: bool) -> Self {
+        self.options.force_ocr = Some(enabled);
+        self
+    }
+
+    /// OCR engine to use.
+    pub fn ocr_engine(mut self, engine: OcrEngine) -> Self {
+        self.options.ocr_engine = Some(engine);
+        self
+    }
+
+    /// Languages for the OCR engine.
+    pub fn ocr_lang<S: Into<String>>(mut self, langs: impl IntoIterator<Item = S>) -> Self {
+        self.options.ocr_lang = Some(langs.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// PDF parsing backend.
+    pub fn pdf_backend(mut self, backend: PdfBackend) -> Self {
+        self.options.pdf_backend = Some(backend);
+        self
+    }
+
+    /// Table structure extraction mode.
+    pub fn table_mode(mut self, mode: TableFormerMode) -> Self {
+        self.options.table_mode = Some(mode);
+        self
+    }
+
+    /// Match table cell predictions back to PDF cells.
+    pub fn table_cell_matching(mut self, enabled: bool) -> Self {
+        self.options.table_cell_matching = Some(enabled);
+        self
+    }
+
+    /// Processing pipeline.
+    pub fn pipeline(mut self, pipeline: ProcessingPipeline) -> Self {
+        self.options.pipeline = Some(pipeline);
+        self
+    }
+
+    /// Convert pages `start..=end`. Pages start at 1.
+    pub fn page_range(mut self, start: i64, end: i64) -> Self {
+        self.options.page_range = Some((start, end));
+        self
+    }
+
+    /// Convert the given pages: a [`PageRange`] or a `(start, end)` tuple.
+    pub fn pages(mut self, range: impl Into<PageRange>) -> Self {
+        self.options.page_range = Some(range.into().bounds());
+        self
+    }
+
+    /// Per-document processing timeout in seconds.
+    pub fn document_timeout(mut self, secs: f64) -> Self {
+        self.options.document_timeout = Some(secs);
+        self
+    }
+
+    /// Per-document processing timeout as a [`Duration`](std::time::Duration),
+    /// sent as fractional seconds.
+    pub fn document_timeout_duration(mut self, timeout: std::time::Duration) -> Self {
+        self.options.document_timeout = Some(timeout.as_secs_f64());
+        self
+    }
+
+    /// Abort on error.
+    pub fn abort_on_error(mut self, enabled: bool) -> Self {
+        self.options.abort_on_error = Some(enabled);
+        self
+    }
+
+    /// Extract table structure.
+    pub fn do_table_structure(mut self, enabled: bool) -> Self {
+        self.options.do_table_structure = Some(enabled);
+        self
+    }
+
+    /// Extract images from documents.
+    pub fn include_images(mut self, enabled: bool) -> Self {
+        self.options.include_images = Some(enabled);
+        self
+    }
+
+    /// Scale factor for images.
+    pub fn images_scale(mut self, scale: f64) -> Self {
+        self.options.images_scale = Some(scale);
+        self
+    }
+
+    /// Placeholder between pages in markdown output.
+    pub fn md_page_break_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.options.md_page_break_placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Enable OCR code enrichment.
+    pub fn do_code_enrichment(mut self, enabled: bool) -> Self {
+        self.options.do_code_enrichment = Some(enabled);
+        self
+    }
+
+    /// Enable formula OCR (LaTeX).
+    pub fn do_formula_enrichment(mut self, enabled: bool) -> Self {
+        self.options.do_formula_enrichment = Some(enabled);
+        self
+    }
+
+    /// Enable picture classification.
+    pub fn do_picture_classification(mut self, enabled: bool) -> Self {
+        self.options.do_picture_classification = Some(enabled);
+        self
+    }
+
+    /// Enable chart data extraction.
+    pub fn do_chart_extraction(mut self, enabled: bool) -> Self {
+        self.options.do_chart_extraction = Some(enabled);
+        self
+    }
+
+    /// Enable picture description.
+    pub fn do_picture_description(mut self, enabled: bool) -> Self {
+        self.options.do_picture_description = Some(enabled);
+        self
+    }
+
+    /// Minimum area percentage for picture processing.
+    pub fn picture_description_area_threshold(mut self, threshold: f64) -> Self {
+        self.options.picture_description_area_threshold = Some(threshold);
+        self
+    }
+
+    /// VLM pipeline model preset.
+    pub fn vlm_pipeline_model(mut self, model: VlmModelType) -> Self {
+        self.options.vlm_pipeline_model = Some(model);
+        self
+    }
+
+    /// Options for running a local VLM for picture description.
+    pub fn picture_description_local(mut self, options: impl Into<serde_json::Value>) -> Self {
+        self.options.picture_description_local = Some(options.into());
+        self
+    }
+
+    /// API details for a VLM used for picture description.
+    pub fn picture_description_api(mut self, options: impl Into<serde_json::Value>) -> Self {
+        self.options.picture_description_api = Some(options.into());
+        self
+    }
+
+    /// Options for running a local VLM for the VLM pipeline.
+    pub fn vlm_pipeline_model_local(mut self, options: impl Into<serde_json::Value>) -> Self {
+        self.options.vlm_pipeline_model_local = Some(options.into());
+        self
+    }
+
+    /// API details for a VLM used in the VLM pipeline.
+    pub fn vlm_pipeline_model_api(mut self, options: impl Into<serde_json::Value>) -> Self {
+        self.options.vlm_pipeline_model_api = Some(options.into());
+        self
+    }
+
+    /// Finish building, rejecting combinations the server does not accept.
+    ///
+    /// See [`ConvertDocumentsRequestOptions::validate`].
+    pub fn build(self) -> Result<ConvertDocumentsRequestOptions, DoclingError> {
+        self.options.validate()?;
+        Ok(self.options)
+    }
+}
+
 // ============================================================================
 // Request body
 // ============================================================================
@@ -201,3 +1357,98 @@ pub struct ConvertDocumentsRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<Target>,
 }
+
+impl ConvertDocumentsRequest {
+    /// Check the options against the sources for combinations that are
+    /// accepted but likely wrong.
+    ///
+    /// Includes everything from [`ConvertDocumentsRequestOptions::warnings`],
+    /// plus [`OptionWarning::PdfBackendWithoutPdf`] when `pdf_backend` is set
+    /// and every source has a known non-PDF extension (see
+    /// [`Source::guess_format`]). Sources without a recognizable extension
+    /// are assumed to possibly be PDFs.
+    pub fn warnings(&self) -> Vec<OptionWarning> {
+        let Some(options) = &self.options else {
+            return Vec::new();
+        };
+        let mut warnings = options.warnings();
+
+        if let Some(backend) = &options.pdf_backend {
+            let may_have_pdf = self
+                .sources
+                .iter()
+                .any(|s| matches!(s.guess_format(), None | Some(InputFormat::Pdf)));
+            let warning = OptionWarning::PdfBackendWithoutPdf {
+                backend: backend.clone(),
+            };
+            if !self.sources.is_empty() && !may_have_pdf && !warnings.contains(&warning) {
+                warnings.push(warning);
+            }
+        }
+
+        warnings
+    }
+}
+
+// ============================================================================
+// Chunking
+// ============================================================================
+
+/// Settings for Docling's hybrid chunker, which splits a document along its
+/// structure and then merges or splits chunks to fit a token budget.
+///
+/// Fields left unset are omitted so the server default applies.
+///
+/// ```rust
+/// use docling_rs::ChunkingOptions;
+///
+/// let options = ChunkingOptions {
+///     tokenizer: Some("sentence-transformers/all-MiniLM-L6-v2".to_string()),
+///     max_tokens: Some(256),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChunkingOptions {
+    /// Hugging Face tokenizer used to count tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tokenizer: Option<String>,
+
+    /// Maximum tokens per chunk. Defaults to the tokenizer's limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+
+    /// Merge undersized neighbouring chunks that share the same headings.
+    /// Defaults to `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_peers: Option<bool>,
+
+    /// Render tables as Markdown instead of triplets. Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_markdown_tables: Option<bool>,
+
+    /// Also return each chunk's text before serialization, in
+    /// [`Chunk::raw_text`](super::responses::Chunk::raw_text). Defaults to
+    /// `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_raw_text: Option<bool>,
+}
+
+/// Request body for `POST /v1/chunk/hybrid/source` and its async variant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkDocumentsRequest {
+    /// One or more document sources to convert and chunk.
+    pub sources: Vec<Source>,
+
+    /// Conversion options (all optional, server uses defaults).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub convert_options: Option<ConvertDocumentsRequestOptions>,
+
+    /// Chunker settings (all optional, server uses defaults).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunking_options: Option<ChunkingOptions>,
+
+    /// Also return the converted documents. Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_converted_doc: Option<bool>,
+}
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
+use super::document::DoclingDocument;
 use super::enums::*;
+use crate::error::DoclingError;
 
 // ============================================================================
 // Response types
@@ -17,7 +20,8 @@ pub struct ExportDocumentResponse {
     pub md_content: Option<String>,
 
     /// Structured JSON content (if requested). Uses generic Value
-    /// because DoclingDocument is a very deep schema.
+    /// because DoclingDocument is a very deep schema; see
+    /// [`docling_document`](Self::docling_document) for a typed view.
     pub json_content: Option<serde_json::Value>,
 
     /// HTML content (if requested).
@@ -30,6 +34,305 @@ pub struct ExportDocumentResponse {
     pub doctags_content: Option<String>,
 }
 
+impl ExportDocumentResponse {
+    /// Parse `json_content` into a typed [`DoclingDocument`].
+    ///
+    /// Returns `Ok(None)` if JSON output was not requested.
+    pub fn docling_document(&self) -> Result<Option<DoclingDocument>, DoclingError> {
+        self.json_content
+            .as_ref()
+            .map(|json| DoclingDocument::deserialize(json).map_err(DoclingError::from))
+            .transpose()
+    }
+
+    /// Parse `json_content` into a typed [`DoclingDocument`], treating a
+    /// missing `json_content` as an error.
+    ///
+    /// Returns [`DoclingError::MissingContent`] if JSON output was not
+    /// requested, and [`DoclingError::Json`] if it does not match the
+    /// document schema.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use docling_rs::models::responses::ExportDocumentResponse;
+    /// # fn example(doc: &ExportDocumentResponse) -> Result<(), docling_rs::DoclingError> {
+    /// let document = doc.parse_json()?;
+    /// for table in &document.tables {
+    ///     println!("{}: {} rows", table.self_ref, table.data.num_rows);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_json(&self) -> Result<DoclingDocument, DoclingError> {
+        self.docling_document()?
+            .ok_or(DoclingError::MissingContent {
+                format: OutputFormat::Json,
+            })
+    }
+
+    /// The textual content for an output format, if the server returned it.
+    ///
+    /// `Md`, `Html`, `Text`, and `Doctags` map to their `*_content` fields;
+    /// `HtmlSplitPage` output is also returned in `html_content`. `Json` is
+    /// not textual (use [`parse_json`](Self::parse_json) or `json_content`)
+    /// and `Yaml` has no response field, so both return `None`.
+    pub fn content(&self, format: OutputFormat) -> Option<&str> {
+        match format {
+            OutputFormat::Md => self.md_content.as_deref(),
+            OutputFormat::Html | OutputFormat::HtmlSplitPage => self.html_content.as_deref(),
+            OutputFormat::Text => self.text_content.as_deref(),
+            OutputFormat::Doctags => self.doctags_content.as_deref(),
+            OutputFormat::Json | OutputFormat::Yaml => None,
+        }
+    }
+
+    /// The output formats whose content fields are populated, including
+    /// `Json` when `json_content` is present.
+    ///
+    /// Populated `html_content` is reported as [`OutputFormat::Html`].
+    pub fn available_formats(&self) -> Vec<OutputFormat> {
+        [
+            (OutputFormat::Md, self.md_content.is_some()),
+            (OutputFormat::Json, self.json_content.is_some()),
+            (OutputFormat::Html, self.html_content.is_some()),
+            (OutputFormat::Text, self.text_content.is_some()),
+            (OutputFormat::Doctags, self.doctags_content.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(format, present)| present.then_some(format))
+        .collect()
+    }
+
+    /// Write every populated content field to `dir` and return the paths
+    /// written.
+    ///
+    /// Files are named `{stem}.md`, `{stem}.json` (pretty-printed),
+    /// `{stem}.html`, `{stem}.txt`, and `{stem}.doctags`, in that order.
+    /// Fields that are `None` are skipped rather than written as empty files.
+    /// `dir` is created if it does not exist, and existing files are
+    /// overwritten.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use docling_rs::models::responses::ExportDocumentResponse;
+    /// # fn example(doc: &ExportDocumentResponse) -> std::io::Result<()> {
+    /// let written = doc.write_to_dir(std::path::Path::new("out"), "report")?;
+    /// for path in written {
+    ///     println!("wrote {}", path.display());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_to_dir(&self, dir: &Path, stem: &str) -> std::io::Result<Vec<PathBuf>> {
+        let json = self
+            .json_content
+            .as_ref()
+            .map(serde_json::to_string_pretty)
+            .transpose()
+            .map_err(std::io::Error::other)?;
+        let files = [
+            ("md", self.md_content.as_deref()),
+            ("json", json.as_deref()),
+            ("html", self.html_content.as_deref()),
+            ("txt", self.text_content.as_deref()),
+            ("doctags", self.doctags_content.as_deref()),
+        ];
+
+        std::fs::create_dir_all(dir)?;
+        let mut written = Vec::new();
+        for (extension, content) in files {
+            let Some(content) = content else {
+                continue;
+            };
+            let path = dir.join(format!("{}.{}", stem, extension));
+            std::fs::write(&path, content)?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+
+    /// Return `md_content` with the given client-side cleanup applied.
+    ///
+    /// Returns `None` if the response has no markdown content. The steps run
+    /// in a fixed order: strip the page-break placeholder, trim trailing
+    /// whitespace, then collapse blank lines.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use docling_rs::models::responses::{ExportDocumentResponse, MarkdownCleanup};
+    /// # fn example(doc: &ExportDocumentResponse) {
+    /// let cleanup = MarkdownCleanup {
+    ///     strip_page_break: Some("<!-- page-break -->".to_string()),
+    ///     collapse_blank_lines: true,
+    ///     ..Default::default()
+    /// };
+    /// let md = doc.markdown_normalized(cleanup);
+    /// # }
+    /// ```
+    pub fn markdown_normalized(&self, opts: MarkdownCleanup) -> Option<String> {
+        let md = self.md_content.as_deref()?;
+
+        let md = match opts.strip_page_break.as_deref() {
+            Some(placeholder) if !placeholder.is_empty() => md.replace(placeholder, ""),
+            _ => md.to_string(),
+        };
+
+        let mut out = String::with_capacity(md.len());
+        let mut previous_blank = false;
+        for line in md.lines() {
+            let line = if opts.trim_trailing_whitespace {
+                line.trim_end()
+            } else {
+                line
+            };
+            let blank = line.trim().is_empty();
+            if opts.collapse_blank_lines && blank && previous_blank {
+                continue;
+            }
+            previous_blank = blank;
+            out.push_str(line);
+            out.push('\n');
+        }
+
+        // `lines()` drops the final newline; only keep one if the input had it.
+        if !md.ends_with('\n') {
+            out.pop();
+        }
+        Some(out)
+    }
+
+    /// Split `md_content` into per-page markdown on the page-break placeholder.
+    ///
+    /// Pass the same string sent as `md_page_break_placeholder` in the
+    /// conversion options. Leading and trailing newlines are trimmed from
+    /// each page. If the placeholder does not occur (or is empty) the
+    /// whole document is returned as a single page; if there is no markdown
+    /// content at all, the result is empty.
+    pub fn markdown_pages(&self, placeholder: &str) -> Vec<String> {
+        let Some(md) = self.md_content.as_deref() else {
+            return Vec::new();
+        };
+        let trim = |page: &str| page.trim_matches(|c| c == '\n' || c == '\r').to_string();
+        if placeholder.is_empty() {
+            return vec![trim(md)];
+        }
+        md.split(placeholder).map(trim).collect()
+    }
+
+    /// Decode images embedded as base64 `data:` URIs.
+    ///
+    /// Scans `md_content` for `![alt](data:...)` and `html_content` for
+    /// `<img src="data:..." alt="...">`, as produced with
+    /// [`ImageRefMode::Embedded`]. Returns `(alt, bytes, mime)` for each image
+    /// in document order, markdown first. Data URIs that are not base64 or
+    /// fail to decode are skipped.
+    pub fn extract_embedded_images(&self) -> Vec<(String, Vec<u8>, String)> {
+        let mut images = Vec::new();
+        if let Some(md) = &self.md_content {
+            images.extend(markdown_data_images(md));
+        }
+        if let Some(html) = &self.html_content {
+            images.extend(html_data_images(html));
+        }
+        images
+    }
+}
+
+/// Decode a `data:<mime>;base64,<payload>` URI into `(bytes, mime)`.
+fn decode_data_uri(uri: &str) -> Option<(Vec<u8>, String)> {
+    use base64::Engine;
+
+    let (meta, payload) = uri.trim().strip_prefix("data:")?.split_once(',')?;
+    let mime = meta.strip_suffix(";base64")?;
+    let payload: String = payload.split_ascii_whitespace().collect();
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(payload)
+        .ok()?;
+    Some((bytes, mime.to_string()))
+}
+
+/// Images written as `![alt](data:...)`.
+fn markdown_data_images(md: &str) -> Vec<(String, Vec<u8>, String)> {
+    let mut images = Vec::new();
+    let mut rest = md;
+    while let Some(start) = rest.find("![") {
+        rest = &rest[start + 2..];
+        let Some((alt, after)) = rest.split_once("](") else {
+            break;
+        };
+        if alt.contains('\n') || !after.starts_with("data:") {
+            continue;
+        }
+        let Some(end) = after.find(')') else {
+            break;
+        };
+        if let Some((bytes, mime)) = decode_data_uri(&after[..end]) {
+            images.push((alt.to_string(), bytes, mime));
+        }
+        rest = &after[end + 1..];
+    }
+    images
+}
+
+/// Images written as `<img src="data:..." alt="...">`.
+fn html_data_images(html: &str) -> Vec<(String, Vec<u8>, String)> {
+    let mut images = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find("<img") {
+        rest = &rest[start + 4..];
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..end];
+        rest = &rest[end + 1..];
+        let Some(src) = html_attribute(tag, "src") else {
+            continue;
+        };
+        if let Some((bytes, mime)) = decode_data_uri(src) {
+            let alt = html_attribute(tag, "alt").unwrap_or_default();
+            images.push((alt.to_string(), bytes, mime));
+        }
+    }
+    images
+}
+
+/// Value of a quoted attribute inside an HTML tag.
+fn html_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = tag;
+    while let Some(pos) = rest.find(name) {
+        let preceded_by_space = rest[..pos].ends_with(char::is_whitespace);
+        let after = rest[pos + name.len()..].trim_start();
+        rest = &rest[pos + name.len()..];
+        let Some(value) = after.strip_prefix('=') else {
+            continue;
+        };
+        if !preceded_by_space {
+            continue;
+        }
+        let value = value.trim_start();
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &value[1..];
+        return value.find(quote).map(|end| &value[..end]);
+    }
+    None
+}
+
+/// Client-side cleanup steps for [`ExportDocumentResponse::markdown_normalized`].
+///
+/// All steps are off by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MarkdownCleanup {
+    /// Collapse runs of blank lines into a single blank line.
+    pub collapse_blank_lines: bool,
+
+    /// Remove every occurrence of this page-break placeholder (the value sent
+    /// as `md_page_break_placeholder`).
+    pub strip_page_break: Option<String>,
+
+    /// Trim trailing whitespace from every line.
+    pub trim_trailing_whitespace: bool,
+}
+
 /// An error that occurred during conversion.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ErrorItem {
@@ -38,6 +341,18 @@ pub struct ErrorItem {
     pub error_message: String,
 }
 
+impl std::fmt::Display for ErrorItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{}: {}",
+            self.component_type, self.module_name, self.error_message
+        )
+    }
+}
+
+impl std::error::Error for ErrorItem {}
+
 /// Profiling information for a conversion step.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ProfilingItem {
@@ -50,7 +365,34 @@ pub struct ProfilingItem {
     pub start_timestamps: Vec<String>,
 }
 
+impl ProfilingItem {
+    /// Sum of all recorded [`times`](Self::times), in seconds; `0.0` if none
+    /// were recorded.
+    pub fn total(&self) -> f64 {
+        self.times.iter().sum()
+    }
+
+    /// Average of the recorded times in seconds, or `None` if there are none.
+    pub fn mean(&self) -> Option<f64> {
+        if self.times.is_empty() {
+            return None;
+        }
+        Some(self.total() / self.times.len() as f64)
+    }
+
+    /// Longest recorded time in seconds, or `None` if there are none.
+    pub fn max(&self) -> Option<f64> {
+        self.times.iter().copied().reduce(f64::max)
+    }
+}
+
 /// Response from `POST /v1/convert/source` (synchronous conversion).
+///
+/// Docling Serve does not echo the options it actually applied, so there is
+/// no way to tell from the response whether an option (e.g. an `ocr_engine`
+/// that is not installed) was ignored or replaced. Stage names in
+/// [`timings`](Self::timings) show which pipeline steps ran, but not which
+/// engine or backend ran them.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ConvertDocumentResponse {
     /// The converted document.
@@ -71,6 +413,123 @@ pub struct ConvertDocumentResponse {
     pub timings: HashMap<String, ProfilingItem>,
 }
 
+impl ConvertDocumentResponse {
+    /// [`processing_time`](Self::processing_time) as a [`Duration`](std::time::Duration).
+    /// A negative or non-finite value yields zero.
+    pub fn processing_duration(&self) -> std::time::Duration {
+        std::time::Duration::try_from_secs_f64(self.processing_time).unwrap_or_default()
+    }
+
+    /// Whether every part of the conversion succeeded.
+    pub fn is_success(&self) -> bool {
+        self.status == ConversionStatus::Success
+    }
+
+    /// Whether a document was produced, possibly with some
+    /// [`warnings`](Self::warnings) (`success` or `partial_success`).
+    pub fn is_ok_enough(&self) -> bool {
+        matches!(
+            self.status,
+            ConversionStatus::Success | ConversionStatus::PartialSuccess
+        )
+    }
+
+    /// The errors the server reported. For a `partial_success` these are the
+    /// parts of the document that could not be converted.
+    pub fn warnings(&self) -> &[ErrorItem] {
+        &self.errors
+    }
+
+    /// Take the document, or return [`DoclingError::ConversionFailed`] if
+    /// the status is `failure`. Any other status yields the document; check
+    /// [`warnings`](Self::warnings) first if partial results matter.
+    pub fn into_result(self) -> Result<ExportDocumentResponse, DoclingError> {
+        if self.status == ConversionStatus::Failure {
+            return Err(DoclingError::ConversionFailed {
+                filename: self.document.filename,
+                errors: self.errors,
+            });
+        }
+        Ok(self.document)
+    }
+
+    /// Stage names from [`timings`](Self::timings) with their
+    /// [`total`](ProfilingItem::total) time, slowest first.
+    pub fn timings_sorted_by_total(&self) -> Vec<(&str, f64)> {
+        let mut totals: Vec<(&str, f64)> = self
+            .timings
+            .iter()
+            .map(|(name, item)| (name.as_str(), item.total()))
+            .collect();
+        totals.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        totals
+    }
+}
+
+#[cfg(feature = "datetime")]
+impl ConvertDocumentResponse {
+    /// Flatten [`timings`](Self::timings) into Gantt-friendly spans.
+    ///
+    /// Each `start_timestamps[i]` is paired with `times[i]`; extra entries in
+    /// the longer of the two vectors and unparseable timestamps are skipped.
+    /// Timestamps with an offset are converted to UTC, naive ones are assumed
+    /// to already be UTC. Spans are sorted by start, and
+    /// [`TimingSpan::offset`] is measured from the earliest one.
+    pub fn timing_spans(&self) -> Vec<TimingSpan> {
+        let mut raw: Vec<(String, chrono::DateTime<chrono::Utc>, f64)> = Vec::new();
+        for (name, item) in &self.timings {
+            for (ts, secs) in item.start_timestamps.iter().zip(&item.times) {
+                if let Some(start) = parse_timestamp(ts) {
+                    raw.push((name.clone(), start, *secs));
+                }
+            }
+        }
+        raw.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+
+        let Some(origin) = raw.first().map(|(_, start, _)| *start) else {
+            return Vec::new();
+        };
+        raw.into_iter()
+            .map(|(name, start, secs)| TimingSpan {
+                name,
+                start,
+                offset: (start - origin).to_std().unwrap_or_default(),
+                duration: std::time::Duration::try_from_secs_f64(secs).unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+/// Parse a profiling timestamp as RFC 3339, falling back to a naive
+/// ISO 8601 datetime interpreted as UTC.
+#[cfg(feature = "datetime")]
+fn parse_timestamp(ts: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(ts) {
+        return Some(dt.with_timezone(&chrono::Utc));
+    }
+    chrono::NaiveDateTime::parse_from_str(ts, "%Y-%m-%dT%H:%M:%S%.f")
+        .or_else(|_| chrono::NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S%.f"))
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+/// One timed stage from [`ConvertDocumentResponse::timing_spans`].
+#[cfg(feature = "datetime")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimingSpan {
+    /// Name of the profiling stage (the key in `timings`).
+    pub name: String,
+
+    /// Absolute start time, in UTC.
+    pub start: chrono::DateTime<chrono::Utc>,
+
+    /// Start relative to the earliest span in the response.
+    pub offset: std::time::Duration,
+
+    /// How long the stage took.
+    pub duration: std::time::Duration,
+}
+
 /// Response when target is S3/presigned URL (not in-body).
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PresignedUrlConvertDocumentResponse {
@@ -80,13 +539,128 @@ pub struct PresignedUrlConvertDocumentResponse {
     pub num_failed: i64,
 }
 
+/// Conversion results for several documents from one request.
+///
+/// Returned by
+/// [`DoclingClient::convert_documents`](crate::DoclingClient::convert_documents).
+/// A single-document [`ConvertDocumentResponse`] converts into this type
+/// with `From`, so code that handles both can work with this one only.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConvertDocumentsResponse {
+    /// The converted documents, in the order the server returned them.
+    pub documents: Vec<ExportDocumentResponse>,
+
+    /// Aggregate status of the whole request.
+    pub status: ConversionStatus,
+
+    /// Errors encountered while converting any of the documents.
+    #[serde(default)]
+    pub errors: Vec<ErrorItem>,
+
+    /// Total processing time in seconds.
+    pub processing_time: f64,
+
+    /// Detailed profiling timings.
+    #[serde(default)]
+    pub timings: HashMap<String, ProfilingItem>,
+}
+
+impl From<ConvertDocumentResponse> for ConvertDocumentsResponse {
+    fn from(response: ConvertDocumentResponse) -> Self {
+        Self {
+            documents: vec![response.document],
+            status: response.status,
+            errors: response.errors,
+            processing_time: response.processing_time,
+            timings: response.timings,
+        }
+    }
+}
+
+// ============================================================================
+// Chunking types
+// ============================================================================
+
+/// A chunk of a document produced by Docling's chunkers.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Chunk {
+    /// Filename of the source document.
+    #[serde(default)]
+    pub filename: String,
+
+    /// Position of the chunk within its document.
+    #[serde(default)]
+    pub chunk_index: usize,
+
+    /// Chunk text.
+    pub text: String,
+
+    /// Chunk text before serialization (if requested).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_text: Option<String>,
+
+    /// Number of tokens in `text` according to the chunker's tokenizer
+    /// (if the server reports it).
+    #[serde(rename = "num_tokens", default)]
+    pub token_count: Option<usize>,
+
+    /// Section headings the chunk belongs to, outermost first.
+    #[serde(default)]
+    pub headings: Option<Vec<String>>,
+
+    /// Captions of tables or pictures in the chunk.
+    #[serde(default)]
+    pub captions: Option<Vec<String>>,
+
+    /// Pages the chunk spans.
+    #[serde(default)]
+    pub page_numbers: Option<Vec<i64>>,
+}
+
+impl Chunk {
+    /// Text to feed to an embedding model.
+    ///
+    /// With `include_headings`, the chunk's headings are prepended, one per
+    /// line, so the embedding carries the section context.
+    pub fn serialize_for_embedding(&self, include_headings: bool) -> String {
+        match &self.headings {
+            Some(headings) if include_headings && !headings.is_empty() => {
+                format!("{}\n{}", headings.join("\n"), self.text)
+            }
+            _ => self.text.clone(),
+        }
+    }
+}
+
+/// Response from `POST /v1/chunk/hybrid/source` and from the result of a
+/// chunking task.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ChunkDocumentResponse {
+    /// Chunks of every converted document, in document order.
+    pub chunks: Vec<Chunk>,
+
+    /// The converted documents, when `include_converted_doc` was requested.
+    #[serde(default)]
+    pub documents: Vec<serde_json::Value>,
+
+    /// Server-side processing time in seconds.
+    #[serde(default)]
+    pub processing_time: f64,
+}
+
 // ============================================================================
 // Async task types
 // ============================================================================
 
 /// Processing metadata for an async task.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+///
+/// Every count defaults to `0` when the server omits it, so a minimal or
+/// partial `task_meta` still parses; use
+/// [`is_meaningful`](Self::is_meaningful) to tell that default apart from
+/// real progress.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct TaskProcessingMeta {
+    #[serde(default)]
     pub num_docs: i64,
     #[serde(default)]
     pub num_processed: i64,
@@ -94,6 +668,39 @@ pub struct TaskProcessingMeta {
     pub num_succeeded: i64,
     #[serde(default)]
     pub num_failed: i64,
+    /// Documents skipped by the converter (e.g. unsupported format).
+    /// Docling Serve does not report this yet, in which case it is `0`.
+    #[serde(default)]
+    pub num_skipped: i64,
+}
+
+impl TaskProcessingMeta {
+    /// Whether the server reported any count, i.e. this is not just the
+    /// all-zero default.
+    pub fn is_meaningful(&self) -> bool {
+        self.num_docs != 0
+            || self.num_processed != 0
+            || self.num_succeeded != 0
+            || self.num_failed != 0
+            || self.num_skipped != 0
+    }
+
+    /// Documents not yet processed: `num_docs - num_processed`.
+    pub fn num_pending(&self) -> i64 {
+        (self.num_docs - self.num_processed).max(0)
+    }
+
+    /// Processed documents not counted as succeeded, failed, or skipped:
+    /// `num_processed - num_succeeded - num_failed - num_skipped`.
+    ///
+    /// The counts are related by `num_docs = num_pending + num_processed`
+    /// and `num_processed = num_succeeded + num_failed + num_skipped +
+    /// num_unaccounted`. A non-zero value usually means skipped or
+    /// partially successful documents on a server that does not report
+    /// `num_skipped`.
+    pub fn num_unaccounted(&self) -> i64 {
+        (self.num_processed - self.num_succeeded - self.num_failed - self.num_skipped).max(0)
+    }
 }
 
 /// Response from async task submission and status polling.
@@ -105,8 +712,8 @@ pub struct TaskStatusResponse {
     /// Type of task (convert or chunk).
     pub task_type: TaskType,
 
-    /// Current status string (e.g. "PENDING", "SUCCESS", "FAILURE").
-    pub task_status: String,
+    /// Current status of the task.
+    pub task_status: TaskStatus,
 
     /// Position in queue (if waiting).
     pub task_position: Option<i64>,
@@ -115,6 +722,37 @@ pub struct TaskStatusResponse {
     pub task_meta: Option<TaskProcessingMeta>,
 }
 
+/// A slice of a task's raw result body, from
+/// [`get_task_result_range`](crate::DoclingClient::get_task_result_range).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultRange {
+    /// The bytes received.
+    pub bytes: Vec<u8>,
+
+    /// Offset of the first byte within the full result.
+    pub start: u64,
+
+    /// Size of the full result, if known.
+    pub total: Option<u64>,
+
+    /// `true` for a `206 Partial Content` answer; `false` when the server
+    /// ignored the range and sent the whole body.
+    pub partial: bool,
+}
+
+/// Outcome of [`convert_source_adaptive`](crate::DoclingClient::convert_source_adaptive).
+///
+/// The conversion either finished within the synchronous budget, or it was
+/// re-submitted as an async task that the caller can poll.
+#[derive(Debug, Clone)]
+pub enum AdaptiveOutcome {
+    /// The synchronous conversion finished within the budget.
+    Completed(ConvertDocumentResponse),
+
+    /// The budget elapsed; the document was submitted for async conversion.
+    Pending(TaskStatusResponse),
+}
+
 // ============================================================================
 // Health / version
 // ============================================================================
@@ -130,6 +768,26 @@ fn default_health_status() -> String {
     "ok".to_string()
 }
 
+/// Response from `GET /version`.
+///
+/// Only `version` and `docling` are named; the versions of other components
+/// the server reports (e.g. `docling-core`, `python`) are kept in `extra`.
+/// Missing named keys deserialize as empty strings.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct VersionResponse {
+    /// Version of Docling Serve itself.
+    #[serde(default)]
+    pub version: String,
+
+    /// Version of the `docling` library the server runs.
+    #[serde(default)]
+    pub docling: String,
+
+    /// All other keys in the response.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
 // ============================================================================
 // Validation error types (HTTP 422 responses)
 // ============================================================================
@@ -149,3 +807,45 @@ pub struct HttpValidationError {
     #[serde(default)]
     pub detail: Vec<ValidationErrorDetail>,
 }
+
+/// How many detail entries [`HttpValidationError`]'s `Display` lists before
+/// summarizing the rest as "and N more".
+const VALIDATION_DETAILS_SHOWN: usize = 3;
+
+impl std::fmt::Display for ValidationErrorDetail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let loc: Vec<String> = self
+            .loc
+            .iter()
+            .map(|part| match part {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .collect();
+        write!(f, "{}: {}", loc.join("."), self.msg)
+    }
+}
+
+impl std::fmt::Display for HttpValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, detail) in self
+            .detail
+            .iter()
+            .take(VALIDATION_DETAILS_SHOWN)
+            .enumerate()
+        {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", detail)?;
+        }
+        if self.detail.len() > VALIDATION_DETAILS_SHOWN {
+            write!(
+                f,
+                " (and {} more)",
+                self.detail.len() - VALIDATION_DETAILS_SHOWN
+            )?;
+        }
+        Ok(())
+    }
+}
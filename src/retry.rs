@@ -0,0 +1,162 @@
+//! Automatic retries for transient server errors.
+//!
+//! A [`RetryPolicy`] set with
+//! [`DoclingClientBuilder::retry_policy`](crate::DoclingClientBuilder::retry_policy)
+//! makes the client resend idempotent `GET`s and async submissions that get
+//! HTTP 429, 500, 502, 503, or 504, waiting with exponential backoff and
+//! jitter between attempts. Without a policy, every response is returned
+//! as-is.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// When and how often to retry a request that got a transient error status.
+///
+/// The delay before retry `n` (zero-based) is `base_delay * 2^n`, capped at
+/// `max_delay`, with up to half of it replaced by random jitter. A
+/// `Retry-After` header on the response (typically a 429), in seconds or as
+/// an HTTP date, is used instead. The client never retries sooner than the
+/// server asked: if `Retry-After` exceeds `max_delay`, the response is
+/// returned as is (a 429 becomes
+/// [`DoclingError::RateLimited`](crate::DoclingError::RateLimited)).
+///
+/// Only idempotent `GET` requests and the async submit endpoints are
+/// retried. Synchronous conversions are never replayed, since the server
+/// would redo the whole conversion.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use docling_rs::{DoclingClient, RetryPolicy};
+///
+/// let client = DoclingClient::builder("http://127.0.0.1:5001")
+///     .retry_policy(RetryPolicy::new(3).base_delay(Duration::from_millis(250)))
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt. `0` disables retries.
+    pub max_retries: u32,
+
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+
+    /// Upper bound for any single delay.
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Create a policy with `max_retries` retries and default delays.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Default::default()
+        }
+    }
+
+    /// Set the delay before the first retry.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the upper bound for any single delay.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// The backoff before the retry with the given zero-based index, before
+    /// jitter is applied.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let scaled = self.base_delay.as_secs_f64() * 2f64.powi(retry.min(63) as i32);
+        Duration::try_from_secs_f64(scaled)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+
+    /// The actual delay before a retry: the server's `Retry-After` if given,
+    /// otherwise the backoff with jitter. `None` if `Retry-After` is longer
+    /// than `max_delay`, meaning the request should not be retried.
+    pub(crate) fn delay(&self, retry: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if let Some(retry_after) = retry_after {
+            return (retry_after <= self.max_delay).then_some(retry_after);
+        }
+        let backoff = self.backoff(retry);
+        let half = backoff / 2;
+        Some(half + half.mul_f64(random_fraction()))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Whether a response status is worth retrying.
+pub(crate) fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    matches!(status.as_u16(), 429 | 500 | 502 | 503 | 504)
+}
+
+/// A random value in `[0, 1)`, seeded from the standard library's per-hasher
+/// random keys so no RNG dependency is needed.
+fn random_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_and_caps() {
+        let policy = RetryPolicy::new(5)
+            .base_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(5));
+        assert_eq!(policy.backoff(0), Duration::from_secs(1));
+        assert_eq!(policy.backoff(1), Duration::from_secs(2));
+        assert_eq!(policy.backoff(2), Duration::from_secs(4));
+        assert_eq!(policy.backoff(3), Duration::from_secs(5));
+        assert_eq!(policy.backoff(1000), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn delay_jitters_within_upper_half() {
+        let policy = RetryPolicy::new(3).base_delay(Duration::from_secs(4));
+        for _ in 0..100 {
+            let delay = policy.delay(0, None).unwrap();
+            assert!(delay >= Duration::from_secs(2), "{delay:?}");
+            assert!(delay <= Duration::from_secs(4), "{delay:?}");
+        }
+    }
+
+    #[test]
+    fn delay_prefers_retry_after_up_to_max() {
+        let policy = RetryPolicy::new(3).max_delay(Duration::from_secs(10));
+        assert_eq!(
+            policy.delay(0, Some(Duration::from_secs(7))),
+            Some(Duration::from_secs(7))
+        );
+        assert_eq!(policy.delay(0, Some(Duration::from_secs(60))), None);
+    }
+
+    #[test]
+    fn retryable_statuses() {
+        for code in [429, 500, 502, 503, 504] {
+            assert!(is_retryable_status(
+                reqwest::StatusCode::from_u16(code).unwrap()
+            ));
+        }
+        for code in [200, 400, 404, 422, 501] {
+            assert!(!is_retryable_status(
+                reqwest::StatusCode::from_u16(code).unwrap()
+            ));
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Input format detection from file content, for files whose name has no
+//! usable extension.
+
+use crate::models::enums::InputFormat;
+
+/// Bytes of a file inspected by [`detect_input_format`]. Reading more does
+/// not help: every signature sits at the start of the file.
+pub(crate) const SNIFF_BYTES: usize = 512;
+
+/// Guess a document's input format from its first bytes ("magic bytes").
+///
+/// Recognizes PDF, PNG, JPEG, TIFF, BMP and WebP images, WAV and MP3 audio,
+/// WebVTT, and HTML. DOCX, PPTX and XLSX files are ZIP archives; they are
+/// recognized only when the archive's first entry lives in the folder that
+/// identifies the format (`word/`, `ppt/`, `xl/`). Any other ZIP, like any
+/// unknown content, yields `None` rather than a wrong guess.
+///
+/// ```rust
+/// use docling_rs::{InputFormat, detect_input_format};
+///
+/// assert_eq!(detect_input_format(b"%PDF-1.7\n"), Some(InputFormat::Pdf));
+/// assert_eq!(detect_input_format(b"plain text"), None);
+/// ```
+pub fn detect_input_format(bytes: &[u8]) -> Option<InputFormat> {
+    let format = match content_mime(bytes)? {
+        "application/pdf" => InputFormat::Pdf,
+        "text/html" => InputFormat::Html,
+        "text/vtt" => InputFormat::Vtt,
+        DOCX_MIME => InputFormat::Docx,
+        PPTX_MIME => InputFormat::Pptx,
+        XLSX_MIME => InputFormat::Xlsx,
+        mime if mime.starts_with("image/") => InputFormat::Image,
+        mime if mime.starts_with("audio/") => InputFormat::Audio,
+        _ => return None,
+    };
+    Some(format)
+}
+
+const DOCX_MIME: &str = "application/vnd.openxmlformats-officedocument.wordprocessingml.document";
+const PPTX_MIME: &str = "application/vnd.openxmlformats-officedocument.presentationml.presentation";
+const XLSX_MIME: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+
+/// The MIME type of a file judged by its first bytes, or `None` if the
+/// content is unknown or ambiguous.
+pub(crate) fn content_mime(bytes: &[u8]) -> Option<&'static str> {
+    let mime = match bytes {
+        [b'%', b'P', b'D', b'F', b'-', ..] => "application/pdf",
+        [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n', ..] => "image/png",
+        [0xff, 0xd8, 0xff, ..] => "image/jpeg",
+        [b'I', b'I', b'*', 0, ..] | [b'M', b'M', 0, b'*', ..] => "image/tiff",
+        [b'B', b'M', ..] if bytes.len() >= 14 => "image/bmp",
+        [b'R', b'I', b'F', b'F', _, _, _, _, form @ ..] => return riff_mime(form),
+        [b'I', b'D', b'3', ..] => "audio/mpeg",
+        // An MPEG audio frame header: 11 sync bits, then a layer other than
+        // the reserved one.
+        [0xff, second, ..] if second & 0xe0 == 0xe0 && second & 0x06 != 0 => "audio/mpeg",
+        [b'P', b'K', 3, 4, ..] => return office_mime(bytes),
+        _ => return text_mime(bytes),
+    };
+    Some(mime)
+}
+
+/// WebP and WAV share the RIFF container; the form type after the chunk
+/// size tells them apart.
+fn riff_mime(form: &[u8]) -> Option<&'static str> {
+    match form {
+        [b'W', b'E', b'B', b'P', ..] => Some("image/webp"),
+        [b'W', b'A', b'V', b'E', ..] => Some("audio/wav"),
+        _ => None,
+    }
+}
+
+/// Tell DOCX, PPTX and XLSX apart by the path of the ZIP archive's first
+/// entry. Writers usually put `[Content_Types].xml` first, which says
+/// nothing about the format, so this often returns `None`.
+fn office_mime(zip: &[u8]) -> Option<&'static str> {
+    // Local file header: the name length is at offset 26, the name at 30.
+    let name_len = u16::from_le_bytes([*zip.get(26)?, *zip.get(27)?]) as usize;
+    let name = zip.get(30..30 + name_len)?;
+    if name.starts_with(b"word/") {
+        Some(DOCX_MIME)
+    } else if name.starts_with(b"ppt/") {
+        Some(PPTX_MIME)
+    } else if name.starts_with(b"xl/") {
+        Some(XLSX_MIME)
+    } else {
+        None
+    }
+}
+
+/// Recognize WebVTT and HTML, skipping a UTF-8 byte order mark and, for
+/// HTML, leading whitespace.
+fn text_mime(bytes: &[u8]) -> Option<&'static str> {
+    let text = bytes.strip_prefix(b"\xef\xbb\xbf").unwrap_or(bytes);
+    if text.starts_with(b"WEBVTT") {
+        return Some("text/vtt");
+    }
+    let start = text.iter().position(|b| !b.is_ascii_whitespace())?;
+    let text = &text[start..];
+    let html = [b"<!doctype html".as_slice(), b"<html"]
+        .iter()
+        .any(|tag| text.len() >= tag.len() && text[..tag.len()].eq_ignore_ascii_case(tag));
+    html.then_some("text/html")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A ZIP local file header whose first entry is `name`.
+    fn zip_with_first_entry(name: &str) -> Vec<u8> {
+        let mut zip = b"PK\x03\x04".to_vec();
+        zip.resize(26, 0);
+        zip.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        zip.extend_from_slice(&[0, 0]);
+        zip.extend_from_slice(name.as_bytes());
+        zip
+    }
+
+    #[test]
+    fn detects_each_signature() {
+        let cases: &[(&[u8], InputFormat)] = &[
+            (b"%PDF-1.4\n%\xe2\xe3", InputFormat::Pdf),
+            (b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR", InputFormat::Image),
+            (b"\xff\xd8\xff\xe0\0\x10JFIF", InputFormat::Image),
+            (b"II*\0\x08\0\0\0", InputFormat::Image),
+            (b"MM\0*\0\0\0\x08", InputFormat::Image),
+            (b"BM\x36\0\x0c\0\0\0\0\0\x36\0\0\0", InputFormat::Image),
+            (b"RIFF\x24\0\0\0WEBPVP8 ", InputFormat::Image),
+            (b"RIFF\x24\0\0\0WAVEfmt ", InputFormat::Audio),
+            (b"ID3\x04\0\0\0\0\0\0", InputFormat::Audio),
+            (b"\xff\xfb\x90\x64", InputFormat::Audio),
+            (
+                b"\xef\xbb\xbfWEBVTT\n\n00:00.000 --> 00:01.000",
+                InputFormat::Vtt,
+            ),
+            (b"\n  <!DOCTYPE html><html>", InputFormat::Html),
+            (b"<HTML><body>", InputFormat::Html),
+        ];
+        for (bytes, format) in cases {
+            assert_eq!(
+                detect_input_format(bytes).as_ref(),
+                Some(format),
+                "{:?}",
+                bytes
+            );
+        }
+    }
+
+    #[test]
+    fn office_formats_need_a_telling_first_entry() {
+        let docx = zip_with_first_entry("word/document.xml");
+        let pptx = zip_with_first_entry("ppt/presentation.xml");
+        let xlsx = zip_with_first_entry("xl/workbook.xml");
+        assert_eq!(detect_input_format(&docx), Some(InputFormat::Docx));
+        assert_eq!(detect_input_format(&pptx), Some(InputFormat::Pptx));
+        assert_eq!(detect_input_format(&xlsx), Some(InputFormat::Xlsx));
+
+        let ambiguous = zip_with_first_entry("[Content_Types].xml");
+        assert_eq!(detect_input_format(&ambiguous), None);
+        assert_eq!(content_mime(&ambiguous), None);
+        assert_eq!(detect_input_format(b"PK\x03\x04"), None);
+    }
+
+    #[test]
+    fn unknown_or_short_content_is_not_guessed() {
+        assert_eq!(detect_input_format(b""), None);
+        assert_eq!(detect_input_format(b"BM"), None);
+        assert_eq!(detect_input_format(b"%PD"), None);
+        assert_eq!(
+            detect_input_format(b"{\"schema_name\": \"DoclingDocument\"}"),
+            None
+        );
+        assert_eq!(detect_input_format(b"# Markdown title"), None);
+        assert_eq!(detect_input_format(b"   "), None);
+    }
+}
@@ -0,0 +1,118 @@
+//! A handle to a submitted async conversion task.
+
+use std::time::Duration;
+
+use crate::client::DoclingClient;
+use crate::error::DoclingError;
+use crate::models::responses::{ConvertDocumentResponse, TaskStatusResponse};
+use crate::wait::WaitConfig;
+
+/// An async conversion task together with the client that submitted it.
+///
+/// Returned by [`DoclingClient::submit_source`], [`DoclingClient::submit`],
+/// and [`DoclingClient::submit_file`]. The handle owns a clone of the
+/// client, which shares its connection pool and settings, so it can be
+/// stored or moved to another task without borrowing the original.
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # async fn example() -> Result<(), docling_rs::DoclingError> {
+/// let client = docling_rs::DoclingClient::new("http://127.0.0.1:5001");
+/// let task = client.submit_source("https://example.com/doc.pdf", None).await?;
+/// println!("submitted {}", task.task_id());
+/// let result = task
+///     .wait(Duration::from_secs(300), Duration::from_secs(5))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TaskHandle {
+    client: DoclingClient,
+    task_id: String,
+}
+
+impl TaskHandle {
+    /// Attach to an existing task by id, e.g. one whose id was persisted
+    /// before a restart.
+    pub fn new(client: DoclingClient, task_id: impl Into<String>) -> Self {
+        Self {
+            client,
+            task_id: task_id.into(),
+        }
+    }
+
+    /// The server-assigned task id.
+    pub fn task_id(&self) -> &str {
+        &self.task_id
+    }
+
+    /// The client this handle sends requests with.
+    pub fn client(&self) -> &DoclingClient {
+        &self.client
+    }
+
+    /// Check the task's current status without waiting.
+    ///
+    /// See [`DoclingClient::poll_task_status`].
+    pub async fn status(&self) -> Result<TaskStatusResponse, DoclingError> {
+        self.client.poll_task_status(&self.task_id, None).await
+    }
+
+    /// Poll until the task finishes and return its result.
+    ///
+    /// Each status poll asks the server to hold the request for up to
+    /// `interval`. Behaves like the `wait_for_*` methods: returns
+    /// [`DoclingError::TaskFailed`] if the task fails and
+    /// [`DoclingError::Timeout`] after `timeout`.
+    pub async fn wait(
+        &self,
+        timeout: Duration,
+        interval: Duration,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        self.wait_with(&WaitConfig::new(timeout).poll(interval))
+            .await
+    }
+
+    /// Poll until the task finishes using a [`WaitConfig`].
+    pub async fn wait_with(
+        &self,
+        config: &WaitConfig,
+    ) -> Result<ConvertDocumentResponse, DoclingError> {
+        self.client.poll_until_complete(&self.task_id, config).await
+    }
+
+    /// Fetch the result of a completed task.
+    ///
+    /// See [`DoclingClient::get_task_result`].
+    pub async fn result(&self) -> Result<ConvertDocumentResponse, DoclingError> {
+        self.client.get_task_result(&self.task_id).await
+    }
+
+    /// Ask the server to cancel the task.
+    ///
+    /// See [`DoclingClient::cancel_task`].
+    pub async fn cancel(&self) -> Result<(), DoclingError> {
+        self.client.cancel_task(&self.task_id).await
+    }
+}
+
+impl std::fmt::Debug for TaskHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskHandle")
+            .field("task_id", &self.task_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The result of [`DoclingClient::restart_task`].
+#[derive(Debug, Clone)]
+pub struct RestartOutcome {
+    /// The task created by re-submitting the request.
+    pub new_task: TaskStatusResponse,
+
+    /// Whether the server accepted the cancellation of the old task. `false`
+    /// means it answered `404` or `405` to [`DoclingClient::cancel_task`], so
+    /// the old task may still be running.
+    pub old_cancelled: bool,
+}
@@ -0,0 +1,203 @@
+//! Configuration for waiting on async tasks.
+//!
+//! [`WaitConfig`] gathers every knob used by the `wait_for_*` methods of
+//! [`DoclingClient`](crate::DoclingClient): the overall timeout, how each poll
+//! waits, and limits on the number of polls and tolerated poll errors.
+//! [`WaitStats`] reports how a finished wait went.
+
+use std::time::Duration;
+
+/// How long each status poll asks the server to wait.
+///
+/// Docling Serve supports long-polling: `GET /v1/status/poll/{task_id}?wait=N`
+/// holds the connection open for up to `N` seconds until the task changes.
+///
+/// If a poll returns within half a second, for example because the wait is
+/// zero or the server ignores it, the client sleeps for the rest of the wait
+/// (at least half a second) before polling again.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PollStrategy {
+    /// Use the same long-poll wait for every poll.
+    LongPoll(Duration),
+
+    /// Start with `initial` and multiply the wait by `factor` after every
+    /// poll, capped at `max`. Useful when most tasks finish quickly but some
+    /// run for a long time.
+    Backoff {
+        initial: Duration,
+        max: Duration,
+        factor: f64,
+    },
+}
+
+impl PollStrategy {
+    /// The long-poll wait for the poll with the given zero-based index.
+    pub fn wait_for(&self, poll_index: u32) -> Duration {
+        match self {
+            PollStrategy::LongPoll(wait) => *wait,
+            PollStrategy::Backoff {
+                initial,
+                max,
+                factor,
+            } => {
+                let scaled = initial.as_secs_f64() * factor.max(1.0).powi(poll_index as i32);
+                Duration::try_from_secs_f64(scaled)
+                    .unwrap_or(*max)
+                    .min(*max)
+            }
+        }
+    }
+}
+
+impl Default for PollStrategy {
+    fn default() -> Self {
+        PollStrategy::LongPoll(Duration::from_secs(5))
+    }
+}
+
+impl From<Duration> for PollStrategy {
+    fn from(wait: Duration) -> Self {
+        PollStrategy::LongPoll(wait)
+    }
+}
+
+/// Settings for the `wait_for_*` methods.
+///
+/// The default matches the historical behavior: a 300 second timeout,
+/// 5 second long-polls, no poll limit, and no tolerance for poll errors.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use docling_rs::WaitConfig;
+///
+/// let config = WaitConfig::new(Duration::from_secs(600))
+///     .poll(Duration::from_secs(10))
+///     .max_poll_errors(3);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaitConfig {
+    /// Maximum total time to wait for the task to finish.
+    pub timeout: Duration,
+
+    /// How long each status poll asks the server to wait.
+    pub poll: PollStrategy,
+
+    /// Give up with [`DoclingError::Timeout`](crate::DoclingError::Timeout)
+    /// after this many polls. `None` means no limit.
+    pub max_polls: Option<u32>,
+
+    /// Number of consecutive failed polls to tolerate before returning the
+    /// error. Only errors worth retrying count (timeouts, connection errors,
+    /// HTTP 429 and 5xx; see
+    /// [`DoclingError::is_retryable`](crate::DoclingError::is_retryable));
+    /// any other error fails at once. `0` fails on the first error.
+    pub max_poll_errors: u32,
+}
+
+impl WaitConfig {
+    /// Create a config with the given overall timeout and default polling.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            ..Default::default()
+        }
+    }
+
+    /// Set how each status poll waits.
+    pub fn poll(mut self, poll: impl Into<PollStrategy>) -> Self {
+        self.poll = poll.into();
+        self
+    }
+
+    /// Limit the number of status polls.
+    pub fn max_polls(mut self, max_polls: u32) -> Self {
+        self.max_polls = Some(max_polls);
+        self
+    }
+
+    /// Tolerate up to this many consecutive failed polls.
+    pub fn max_poll_errors(mut self, max_poll_errors: u32) -> Self {
+        self.max_poll_errors = max_poll_errors;
+        self
+    }
+
+    /// Build a config from the legacy `timeout` / `poll_interval_secs` pair
+    /// taken by `wait_for_conversion` and `wait_for_file_conversion`.
+    pub(crate) fn from_legacy(timeout: Duration, poll_interval_secs: Option<f64>) -> Self {
+        let poll = poll_interval_secs
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+            .map(PollStrategy::LongPoll)
+            .unwrap_or_default();
+        Self {
+            timeout,
+            poll,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for WaitConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(300),
+            poll: PollStrategy::default(),
+            max_polls: None,
+            max_poll_errors: 0,
+        }
+    }
+}
+
+impl From<Duration> for WaitConfig {
+    fn from(timeout: Duration) -> Self {
+        WaitConfig::new(timeout)
+    }
+}
+
+/// What happened while waiting for a task, returned by
+/// [`wait_for_conversion_detailed`](crate::DoclingClient::wait_for_conversion_detailed).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaitStats {
+    /// Number of status polls sent, including failed ones.
+    pub polls: u32,
+
+    /// Time from the first poll until the result was fetched.
+    pub waited: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_matches_legacy_behavior() {
+        let config = WaitConfig::default();
+        assert_eq!(config.timeout, Duration::from_secs(300));
+        assert_eq!(config.poll, PollStrategy::LongPoll(Duration::from_secs(5)));
+        assert_eq!(config.max_polls, None);
+        assert_eq!(config.max_poll_errors, 0);
+    }
+
+    #[test]
+    fn from_legacy_uses_poll_interval() {
+        let config = WaitConfig::from_legacy(Duration::from_secs(30), Some(1.5));
+        assert_eq!(config.timeout, Duration::from_secs(30));
+        assert_eq!(config.poll.wait_for(0), Duration::from_millis(1500));
+
+        let config = WaitConfig::from_legacy(Duration::from_secs(30), None);
+        assert_eq!(config.poll.wait_for(0), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn backoff_grows_and_caps() {
+        let poll = PollStrategy::Backoff {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(5),
+            factor: 2.0,
+        };
+        assert_eq!(poll.wait_for(0), Duration::from_secs(1));
+        assert_eq!(poll.wait_for(1), Duration::from_secs(2));
+        assert_eq!(poll.wait_for(2), Duration::from_secs(4));
+        assert_eq!(poll.wait_for(3), Duration::from_secs(5));
+        assert_eq!(poll.wait_for(100), Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,76 @@
+//! Tests for unpacking ZIP target results (requires the `zip` feature).
+#![cfg(feature = "zip")]
+
+use std::io::{Cursor, Write};
+
+use docling_rs::{DoclingError, unpack_zip};
+use zip::write::SimpleFileOptions;
+
+fn build_zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, contents) in entries {
+        writer
+            .start_file(*name, SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(contents).unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
+
+#[test]
+fn unpack_zip_reads_every_file() {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    writer
+        .add_directory("images/", SimpleFileOptions::default())
+        .unwrap();
+    writer
+        .start_file("doc.md", SimpleFileOptions::default())
+        .unwrap();
+    writer.write_all(b"# Title").unwrap();
+    writer
+        .start_file("images/fig1.png", SimpleFileOptions::default())
+        .unwrap();
+    writer.write_all(b"\x89PNG").unwrap();
+    let bytes = writer.finish().unwrap().into_inner();
+
+    let files = unpack_zip(&bytes).unwrap();
+
+    assert_eq!(files.len(), 2);
+    assert_eq!(files["doc.md"], b"# Title");
+    assert_eq!(files["images/fig1.png"], b"\x89PNG");
+}
+
+#[test]
+fn unpack_zip_empty_archive_is_empty_map() {
+    let bytes = build_zip(&[]);
+    assert!(unpack_zip(&bytes).unwrap().is_empty());
+}
+
+#[test]
+fn unpack_zip_duplicate_names_keep_last_entry() {
+    // Rename the second entry to the first one's name (same length), since
+    // ZipWriter refuses to write duplicates itself.
+    let mut bytes = build_zip(&[("doc.md", b"first"), ("dup.md", b"second")]);
+    let needle = b"dup.md";
+    let positions: Vec<usize> = bytes
+        .windows(needle.len())
+        .enumerate()
+        .filter(|(_, window)| window == needle)
+        .map(|(i, _)| i)
+        .collect();
+    assert!(!positions.is_empty());
+    for i in positions {
+        bytes[i..i + needle.len()].copy_from_slice(b"doc.md");
+    }
+
+    let files = unpack_zip(&bytes).unwrap();
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(files["doc.md"], b"second");
+}
+
+#[test]
+fn unpack_zip_rejects_corrupt_archive() {
+    let err = unpack_zip(b"not a zip file").unwrap_err();
+    assert!(matches!(err, DoclingError::Io(_)));
+}
@@ -0,0 +1,107 @@
+//! Mock tests for the blocking client, including use from async contexts.
+
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use docling_rs::blocking::DoclingClient;
+
+async fn health_server() -> (mockito::ServerGuard, mockito::Mock) {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/health")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::health_response_json()).unwrap())
+        .create_async()
+        .await;
+    (server, mock)
+}
+
+#[tokio::test]
+async fn blocking_client_works_inside_current_thread_runtime() {
+    let (server, mock) = health_server().await;
+
+    let client = DoclingClient::new(server.url());
+    let health = client.health().unwrap();
+
+    assert_eq!(health.status, "ok");
+    // Dropping the client's own runtime here must not panic either.
+    drop(client);
+    mock.assert_async().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn blocking_client_works_inside_multi_thread_runtime() {
+    let (server, mock) = health_server().await;
+
+    let client = DoclingClient::new(server.url());
+    assert_eq!(client.health().unwrap().status, "ok");
+    mock.assert_async().await;
+}
+
+#[test]
+fn from_handle_reuses_an_existing_runtime() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let (server, mock) = runtime.block_on(health_server());
+
+    let client = DoclingClient::from_handle(runtime.handle().clone(), server.url());
+    assert_eq!(client.health().unwrap().status, "ok");
+    mock.assert();
+}
+
+#[test]
+fn clones_share_the_client_across_threads() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut server = runtime.block_on(mockito::Server::new_async());
+    let mock = server
+        .mock("GET", "/health")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::health_response_json()).unwrap())
+        .expect(2)
+        .create();
+    drop(runtime);
+
+    let client = DoclingClient::new(server.url());
+    let clone = client.clone();
+    let handle = std::thread::spawn(move || clone.health().unwrap().status);
+
+    assert_eq!(client.health().unwrap().status, "ok");
+    assert_eq!(handle.join().unwrap(), "ok");
+    mock.assert();
+}
+
+/// A path type that is neither `Send` nor `Sync`.
+struct LocalPath(std::path::PathBuf, std::marker::PhantomData<*const ()>);
+
+impl AsRef<std::path::Path> for LocalPath {
+    fn as_ref(&self) -> &std::path::Path {
+        &self.0
+    }
+}
+
+#[test]
+fn file_methods_accept_paths_that_are_not_sync() {
+    use std::io::Write;
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut server = runtime.block_on(mockito::Server::new_async());
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create();
+    drop(runtime);
+
+    let mut file = tempfile::Builder::new().suffix(".pdf").tempfile().unwrap();
+    file.write_all(b"%PDF-1.7").unwrap();
+    let path = LocalPath(file.path().to_path_buf(), std::marker::PhantomData);
+
+    let client = DoclingClient::new(server.url());
+    let result = client.convert_file(&[path], None, None).unwrap();
+
+    assert_eq!(result.document.filename, "test.pdf");
+    mock.assert();
+}
@@ -1,9 +1,11 @@
-//! Mock tests for async conversion lifecycle: submit, poll, result, wait_for_conversion.
+//! Mock tests for async conversion lifecycle: submit, poll, watch, result, wait_for_conversion.
 
 mod common;
 
 use std::time::Duration;
 
+use futures::StreamExt;
+
 #[tokio::test]
 async fn convert_source_async_returns_task_status() {
     let mut server = mockito::Server::new_async().await;
@@ -93,6 +95,81 @@ async fn get_task_result_success() {
     mock.assert_async().await;
 }
 
+#[tokio::test]
+async fn get_task_result_to_writer_streams_markdown() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/result/task-004")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let mut out = Vec::new();
+    client
+        .get_task_result_to_writer("task-004", &mut out)
+        .await
+        .unwrap();
+
+    assert_eq!(out, b"# Hello World\n\nThis is a test document.");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn task_id_is_percent_encoded_in_paths() {
+    let mut server = mockito::Server::new_async().await;
+
+    let poll = server
+        .mock("GET", "/v1/status/poll/odd%2Fid%20here")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("odd/id here", "STARTED")).unwrap(),
+        )
+        .create_async()
+        .await;
+    let result = server
+        .mock("GET", "/v1/result/odd%2Fid%20here")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let status = client.poll_task_status("odd/id here", None).await.unwrap();
+    assert_eq!(status.task_id, "odd/id here");
+    client.get_task_result("odd/id here").await.unwrap();
+
+    poll.assert_async().await;
+    result.assert_async().await;
+}
+
+#[tokio::test]
+async fn dot_segment_task_ids_are_rejected() {
+    let mut server = mockito::Server::new_async().await;
+
+    let any_request = server
+        .mock("GET", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    for id in [".", ".."] {
+        match client.get_task_result(id).await.unwrap_err() {
+            docling_rs::DoclingError::InvalidOptions { .. } => {}
+            other => panic!("Expected InvalidOptions, got: {:?}", other),
+        }
+        assert!(client.poll_task_status(id, None).await.is_err());
+    }
+
+    any_request.assert_async().await;
+}
+
 #[tokio::test]
 async fn wait_for_conversion_happy_path() {
     let mut server = mockito::Server::new_async().await;
@@ -147,6 +224,105 @@ async fn wait_for_conversion_happy_path() {
     result_mock.assert_async().await;
 }
 
+#[tokio::test]
+async fn wait_for_conversion_cancellable_stops_when_cancelled() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _submit = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-stop", "PENDING")).unwrap(),
+        )
+        .create_async()
+        .await;
+
+    // The task never finishes.
+    let _poll = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"/v1/status/poll/task-stop.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-stop", "STARTED")).unwrap(),
+        )
+        .expect_at_least(1)
+        .create_async()
+        .await;
+
+    let cancel = docling_rs::CancellationToken::new();
+    let stop = cancel.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        stop.cancel();
+    });
+
+    let client = common::test_client(&server.url());
+    let started = std::time::Instant::now();
+    let result = client
+        .wait_for_conversion_cancellable(
+            "https://example.com/doc.pdf",
+            None,
+            Duration::from_secs(30),
+            Some(1.0),
+            cancel,
+        )
+        .await;
+
+    match result {
+        Err(docling_rs::DoclingError::Cancelled { task_id }) => assert_eq!(task_id, "task-stop"),
+        other => panic!("Expected Cancelled, got: {:?}", other),
+    }
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn wait_for_conversion_cancellable_already_cancelled_skips_polling() {
+    let mut server = mockito::Server::new_async().await;
+
+    let submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-early", "PENDING")).unwrap(),
+        )
+        .create_async()
+        .await;
+    let poll_mock = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"/v1/status/poll/.*".to_string()),
+        )
+        .expect(0)
+        .create_async()
+        .await;
+
+    let cancel = docling_rs::CancellationToken::new();
+    cancel.cancel();
+
+    let client = common::test_client(&server.url());
+    let err = client
+        .wait_for_conversion_cancellable(
+            "https://example.com/doc.pdf",
+            None,
+            Duration::from_secs(30),
+            None,
+            cancel,
+        )
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, docling_rs::DoclingError::Cancelled { ref task_id } if task_id == "task-early")
+    );
+    submit_mock.assert_async().await;
+    poll_mock.assert_async().await;
+}
+
 #[tokio::test]
 async fn wait_for_conversion_task_failure() {
     let mut server = mockito::Server::new_async().await;
@@ -196,3 +372,902 @@ async fn wait_for_conversion_task_failure() {
     submit_mock.assert_async().await;
     poll_mock.assert_async().await;
 }
+
+#[tokio::test]
+async fn convert_source_adaptive_completes_within_budget() {
+    let mut server = mockito::Server::new_async().await;
+
+    let sync_mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let async_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let outcome = client
+        .convert_source_adaptive("https://example.com/doc.pdf", None, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    match outcome {
+        docling_rs::AdaptiveOutcome::Completed(result) => {
+            assert_eq!(result.document.filename, "test.pdf");
+        }
+        other => panic!("Expected Completed, got: {:?}", other),
+    }
+    sync_mock.assert_async().await;
+    async_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_source_adaptive_falls_back_to_async() {
+    let mut server = mockito::Server::new_async().await;
+
+    // Sync endpoint stalls past the budget
+    let sync_mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_millis(500));
+            w.write_all(
+                serde_json::to_string(&common::convert_response_json())
+                    .unwrap()
+                    .as_bytes(),
+            )
+        })
+        .create_async()
+        .await;
+
+    let async_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-slow", "PENDING")).unwrap(),
+        )
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let outcome = client
+        .convert_source_adaptive(
+            "https://example.com/doc.pdf",
+            None,
+            Duration::from_millis(100),
+        )
+        .await
+        .unwrap();
+
+    match outcome {
+        docling_rs::AdaptiveOutcome::Pending(task) => {
+            assert_eq!(task.task_id, "task-slow");
+            assert_eq!(task.task_status, "PENDING");
+        }
+        other => panic!("Expected Pending, got: {:?}", other),
+    }
+    sync_mock.assert_async().await;
+    async_mock.assert_async().await;
+}
+
+fn url_request() -> docling_rs::ConvertDocumentsRequest {
+    docling_rs::ConvertDocumentsRequest {
+        sources: vec![docling_rs::Source::Http {
+            url: "https://example.com/doc.pdf".to_string(),
+            headers: None,
+        }],
+        options: None,
+        target: None,
+    }
+}
+
+#[tokio::test]
+async fn restart_task_cancels_then_resubmits() {
+    let mut server = mockito::Server::new_async().await;
+
+    let cancel_mock = server
+        .mock("DELETE", "/v1/result/task-stuck")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-new", "PENDING")).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let outcome = client
+        .restart_task("task-stuck", &url_request())
+        .await
+        .unwrap();
+
+    assert_eq!(outcome.new_task.task_id, "task-new");
+    assert!(outcome.old_cancelled);
+    cancel_mock.assert_async().await;
+    submit_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn restart_task_resubmits_when_cancel_unsupported() {
+    let mut server = mockito::Server::new_async().await;
+
+    let cancel_mock = server
+        .mock("DELETE", "/v1/result/task-stuck")
+        .with_status(405)
+        .create_async()
+        .await;
+
+    let submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-new", "PENDING")).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let outcome = client
+        .restart_task("task-stuck", &url_request())
+        .await
+        .unwrap();
+
+    assert_eq!(outcome.new_task.task_id, "task-new");
+    assert!(!outcome.old_cancelled);
+    cancel_mock.assert_async().await;
+    submit_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn restart_task_propagates_cancel_server_error() {
+    let mut server = mockito::Server::new_async().await;
+
+    let cancel_mock = server
+        .mock("DELETE", "/v1/result/task-stuck")
+        .with_status(500)
+        .with_body("boom")
+        .create_async()
+        .await;
+
+    let submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let result = client.restart_task("task-stuck", &url_request()).await;
+
+    match result.unwrap_err() {
+        docling_rs::DoclingError::Api { status_code, .. } => assert_eq!(status_code, 500),
+        other => panic!("Expected DoclingError::Api, got: {:?}", other),
+    }
+    cancel_mock.assert_async().await;
+    submit_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn wait_for_conversion_with_stops_after_max_polls() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-slow", "PENDING")).unwrap(),
+        )
+        .create_async()
+        .await;
+
+    let poll_mock = server
+        .mock("GET", "/v1/status/poll/task-slow?wait=0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-slow", "STARTED")).unwrap(),
+        )
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let config = docling_rs::WaitConfig::new(Duration::from_secs(30))
+        .poll(Duration::ZERO)
+        .max_polls(2);
+    let result = client
+        .wait_for_conversion_with("https://example.com/doc.pdf", None, &config)
+        .await;
+
+    match result.unwrap_err() {
+        docling_rs::DoclingError::Timeout { task_id, .. } => assert_eq!(task_id, "task-slow"),
+        other => panic!("Expected Timeout, got: {:?}", other),
+    }
+    poll_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn timeout_reports_last_status_and_queue_position() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-queued", "PENDING")).unwrap(),
+        )
+        .create_async()
+        .await;
+
+    let mut queued = common::task_status_json("task-queued", "PENDING");
+    queued["task_position"] = serde_json::json!(4);
+    let _poll_mock = server
+        .mock("GET", "/v1/status/poll/task-queued?wait=0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&queued).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let config = docling_rs::WaitConfig::new(Duration::from_secs(30))
+        .poll(Duration::ZERO)
+        .max_polls(1);
+    let err = client
+        .wait_for_conversion_with("https://example.com/doc.pdf", None, &config)
+        .await
+        .unwrap_err();
+
+    assert!(
+        err.to_string()
+            .ends_with("(last status: PENDING, queue position 4)"),
+        "{err}"
+    );
+    match err {
+        docling_rs::DoclingError::Timeout {
+            last_status,
+            last_position,
+            ..
+        } => {
+            assert_eq!(last_status, Some(docling_rs::TaskStatus::Pending));
+            assert_eq!(last_position, Some(4));
+        }
+        other => panic!("Expected Timeout, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn wait_for_conversion_with_tolerates_poll_errors() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-flaky", "PENDING")).unwrap(),
+        )
+        .create_async()
+        .await;
+
+    // First poll fails with 503, the next one succeeds.
+    let error_mock = server
+        .mock("GET", "/v1/status/poll/task-flaky?wait=0")
+        .with_status(503)
+        .with_body("unavailable")
+        .expect(1)
+        .create_async()
+        .await;
+    let poll_mock = server
+        .mock("GET", "/v1/status/poll/task-flaky?wait=0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-flaky", "SUCCESS")).unwrap(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let result_mock = server
+        .mock("GET", "/v1/result/task-flaky")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let config = docling_rs::WaitConfig::new(Duration::from_secs(30))
+        .poll(Duration::ZERO)
+        .max_poll_errors(1);
+    let result = client
+        .wait_for_conversion_with("https://example.com/doc.pdf", None, &config)
+        .await
+        .unwrap();
+
+    assert_eq!(result.document.filename, "test.pdf");
+    error_mock.assert_async().await;
+    poll_mock.assert_async().await;
+    result_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn wait_for_conversion_with_tolerates_rate_limited_polls() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-busy", "PENDING")).unwrap(),
+        )
+        .create_async()
+        .await;
+
+    // First poll is rate limited, the next one succeeds.
+    let limited_mock = server
+        .mock("GET", "/v1/status/poll/task-busy?wait=0")
+        .with_status(429)
+        .with_body("slow down")
+        .expect(1)
+        .create_async()
+        .await;
+    let poll_mock = server
+        .mock("GET", "/v1/status/poll/task-busy?wait=0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-busy", "SUCCESS")).unwrap(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let _result_mock = server
+        .mock("GET", "/v1/result/task-busy")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let config = docling_rs::WaitConfig::new(Duration::from_secs(30))
+        .poll(Duration::ZERO)
+        .max_poll_errors(1);
+    let result = client
+        .wait_for_conversion_with("https://example.com/doc.pdf", None, &config)
+        .await
+        .unwrap();
+
+    assert_eq!(result.document.filename, "test.pdf");
+    limited_mock.assert_async().await;
+    poll_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn wait_for_conversion_detailed_reports_polls_and_wait_time() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-stats", "PENDING")).unwrap(),
+        )
+        .create_async()
+        .await;
+    let _pending_mock = server
+        .mock("GET", "/v1/status/poll/task-stats?wait=0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-stats", "STARTED")).unwrap(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let _success_mock = server
+        .mock("GET", "/v1/status/poll/task-stats?wait=0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-stats", "SUCCESS")).unwrap(),
+        )
+        .create_async()
+        .await;
+    let _result_mock = server
+        .mock("GET", "/v1/result/task-stats")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let config = docling_rs::WaitConfig::new(Duration::from_secs(30)).poll(Duration::ZERO);
+    let (result, stats) = client
+        .wait_for_conversion_detailed("https://example.com/doc.pdf", None, &config)
+        .await
+        .unwrap();
+
+    assert_eq!(result.document.filename, "test.pdf");
+    assert_eq!(stats.polls, 2);
+    // The early STARTED poll is paced by the client's minimum poll interval.
+    assert!(stats.waited >= Duration::from_millis(400), "{:?}", stats);
+}
+
+#[tokio::test]
+async fn wait_for_conversion_with_fails_on_poll_error_by_default() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-down", "PENDING")).unwrap(),
+        )
+        .create_async()
+        .await;
+
+    let _poll_mock = server
+        .mock("GET", "/v1/status/poll/task-down?wait=0")
+        .with_status(503)
+        .with_body("unavailable")
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let config = docling_rs::WaitConfig::new(Duration::from_secs(30)).poll(Duration::ZERO);
+    let result = client
+        .wait_for_conversion_with("https://example.com/doc.pdf", None, &config)
+        .await;
+
+    match result.unwrap_err() {
+        docling_rs::DoclingError::Api { status_code, .. } => assert_eq!(status_code, 503),
+        other => panic!("Expected Api, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn get_task_result_range_returns_partial_content() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/result/task-zip")
+        .match_header("range", "bytes=4-7")
+        .with_status(206)
+        .with_header("content-type", "application/zip")
+        .with_header("content-range", "bytes 4-7/12")
+        .with_body("5678")
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let range = client
+        .get_task_result_range("task-zip", 4..8)
+        .await
+        .unwrap();
+
+    assert_eq!(range.bytes, b"5678");
+    assert_eq!(range.start, 4);
+    assert_eq!(range.total, Some(12));
+    assert!(range.partial);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn get_task_result_range_handles_ignored_range() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/result/task-zip")
+        .with_status(200)
+        .with_header("content-type", "application/zip")
+        .with_body("123456789abc")
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let range = client
+        .get_task_result_range("task-zip", 4..8)
+        .await
+        .unwrap();
+
+    assert_eq!(range.bytes, b"123456789abc");
+    assert_eq!(range.start, 0);
+    assert_eq!(range.total, Some(12));
+    assert!(!range.partial);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn get_task_result_range_rejects_empty_range() {
+    let client = common::test_client("http://127.0.0.1:1");
+    let err = client
+        .get_task_result_range("task-zip", 5..5)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        docling_rs::DoclingError::InvalidOptions { .. }
+    ));
+}
+
+#[tokio::test]
+async fn request_timeout_fails_slow_requests() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _mock = server
+        .mock("GET", "/v1/result/task-slow")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_millis(500));
+            w.write_all(
+                serde_json::to_string(&common::convert_response_json())
+                    .unwrap()
+                    .as_bytes(),
+            )
+        })
+        .create_async()
+        .await;
+
+    let client = docling_rs::DoclingClient::builder(server.url())
+        .request_timeout(Duration::from_millis(100))
+        .build();
+    let err = client.get_task_result("task-slow").await.unwrap_err();
+
+    assert!(matches!(err, docling_rs::DoclingError::Http(_)));
+    assert!(err.is_timeout());
+}
+
+#[tokio::test]
+async fn request_timeout_is_extended_for_long_polls() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/status/poll/task-lp?wait=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_millis(300));
+            w.write_all(
+                serde_json::to_string(&common::task_status_json("task-lp", "STARTED"))
+                    .unwrap()
+                    .as_bytes(),
+            )
+        })
+        .create_async()
+        .await;
+
+    let client = docling_rs::DoclingClient::builder(server.url())
+        .request_timeout(Duration::from_millis(100))
+        .build();
+    let status = client.poll_task_status("task-lp", Some(1.0)).await.unwrap();
+
+    assert_eq!(status.task_status, "STARTED");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn watch_task_yields_updates_until_terminal_status() {
+    let mut server = mockito::Server::new_async().await;
+
+    let started = server
+        .mock("GET", "/v1/status/poll/task-watch")
+        .match_query(mockito::Matcher::UrlEncoded("wait".into(), "1".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-watch", "STARTED")).unwrap(),
+        )
+        .expect(2)
+        .create_async()
+        .await;
+    let done = server
+        .mock("GET", "/v1/status/poll/task-watch")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-watch", "SUCCESS")).unwrap(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let statuses: Vec<_> = client
+        .watch_task("task-watch", Duration::from_secs(1))
+        .map(|status| status.unwrap().task_status.to_string())
+        .collect()
+        .await;
+
+    assert_eq!(statuses, ["STARTED", "STARTED", "SUCCESS"]);
+    started.assert_async().await;
+    done.assert_async().await;
+}
+
+#[tokio::test]
+async fn watch_task_ends_after_error_and_stops_when_dropped() {
+    let mut server = mockito::Server::new_async().await;
+
+    let missing = server
+        .mock("GET", "/v1/status/poll/task-gone")
+        .match_query(mockito::Matcher::Any)
+        .with_status(404)
+        .expect(1)
+        .create_async()
+        .await;
+    let pending = server
+        .mock("GET", "/v1/status/poll/task-slow")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-slow", "PENDING")).unwrap(),
+        )
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let results: Vec<_> = client
+        .watch_task("task-gone", Duration::ZERO)
+        .collect()
+        .await;
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_err());
+
+    // Only the consumed items are polled.
+    let taken: Vec<_> = client
+        .watch_task("task-slow", Duration::ZERO)
+        .take(2)
+        .collect()
+        .await;
+    assert_eq!(taken.len(), 2);
+
+    missing.assert_async().await;
+    pending.assert_async().await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn watch_task_paces_polls_that_return_early() {
+    let mut server = mockito::Server::new_async().await;
+
+    let started = server
+        .mock("GET", "/v1/status/poll/task-fast")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-fast", "STARTED")).unwrap(),
+        )
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let start = tokio::time::Instant::now();
+    let taken: Vec<_> = client
+        .watch_task("task-fast", Duration::ZERO)
+        .take(3)
+        .collect()
+        .await;
+
+    assert_eq!(taken.len(), 3);
+    assert!(start.elapsed() >= Duration::from_millis(1000));
+    started.assert_async().await;
+}
+
+#[tokio::test]
+async fn wait_for_conversion_with_zero_wait_does_not_busy_loop() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-spin", "PENDING")).unwrap(),
+        )
+        .create_async()
+        .await;
+
+    // Without client-side pacing this would see hundreds of polls.
+    let poll_mock = server
+        .mock("GET", "/v1/status/poll/task-spin?wait=0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-spin", "STARTED")).unwrap(),
+        )
+        .expect_at_least(2)
+        .expect_at_most(4)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let config = docling_rs::WaitConfig::new(Duration::from_millis(1200)).poll(Duration::ZERO);
+    let result = client
+        .wait_for_conversion_with("https://example.com/doc.pdf", None, &config)
+        .await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        docling_rs::DoclingError::Timeout { .. }
+    ));
+    poll_mock.assert_async().await;
+}
+
+/// Run `wait_for_conversion_with` against a task whose first poll reports
+/// `status`, returning the outcome and whether the result was fetched.
+async fn wait_with_terminal_status(
+    status: &str,
+) -> (
+    Result<docling_rs::ConvertDocumentResponse, docling_rs::DoclingError>,
+    bool,
+) {
+    let mut server = mockito::Server::new_async().await;
+
+    let _submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-t", "PENDING")).unwrap())
+        .create_async()
+        .await;
+    let poll_mock = server
+        .mock("GET", "/v1/status/poll/task-t?wait=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-t", status)).unwrap())
+        .expect(1)
+        .create_async()
+        .await;
+    let result_mock = server
+        .mock("GET", "/v1/result/task-t")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let config = docling_rs::WaitConfig::new(Duration::from_secs(30)).poll(Duration::from_secs(1));
+    let result = client
+        .wait_for_conversion_with("https://example.com/doc.pdf", None, &config)
+        .await;
+
+    poll_mock.assert_async().await;
+    (result, result_mock.matched_async().await)
+}
+
+#[tokio::test]
+async fn wait_for_conversion_returns_result_on_partial_success() {
+    let (result, fetched) = wait_with_terminal_status("PARTIAL_SUCCESS").await;
+    assert_eq!(result.unwrap().document.filename, "test.pdf");
+    assert!(fetched);
+}
+
+#[tokio::test]
+async fn wait_for_conversion_fails_on_skipped_and_failure() {
+    for status in ["SKIPPED", "FAILURE"] {
+        let (result, fetched) = wait_with_terminal_status(status).await;
+        match result.unwrap_err() {
+            docling_rs::DoclingError::TaskFailed {
+                task_id,
+                status: reported,
+            } => {
+                assert_eq!(task_id, "task-t");
+                assert_eq!(reported, status);
+            }
+            other => panic!("Expected TaskFailed, got: {:?}", other),
+        }
+        assert!(!fetched);
+    }
+}
+
+// ============================================================================
+// TaskHandle
+// ============================================================================
+
+#[tokio::test]
+async fn submit_source_returns_handle_for_status_wait_and_cancel() {
+    let mut server = mockito::Server::new_async().await;
+
+    let submit = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-h", "PENDING")).unwrap())
+        .create_async()
+        .await;
+    let status = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex("^/v1/status/poll/task-h".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-h", "SUCCESS")).unwrap())
+        .expect(2)
+        .create_async()
+        .await;
+    let result = server
+        .mock("GET", "/v1/result/task-h")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .expect(2)
+        .create_async()
+        .await;
+    let cancel = server
+        .mock("DELETE", "/v1/result/task-h")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let task = client
+        .submit_source("https://example.com/doc.pdf", None)
+        .await
+        .unwrap();
+    // The handle owns its client, so the original can go away.
+    drop(client);
+
+    assert_eq!(task.task_id(), "task-h");
+    assert_eq!(task.status().await.unwrap().task_status, "SUCCESS");
+    let done = task
+        .wait(Duration::from_secs(5), Duration::from_secs(1))
+        .await
+        .unwrap();
+    assert_eq!(done.document.filename, "test.pdf");
+    assert_eq!(task.result().await.unwrap().document.filename, "test.pdf");
+    task.cancel().await.unwrap();
+
+    submit.assert_async().await;
+    status.assert_async().await;
+    result.assert_async().await;
+    cancel.assert_async().await;
+}
+
+#[tokio::test]
+async fn task_handle_can_reattach_by_id_from_another_task() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/status/poll/task-saved")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::to_string(&common::task_status_json("task-saved", "STARTED")).unwrap(),
+        )
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let task = docling_rs::TaskHandle::new(client.clone(), "task-saved");
+    let status = tokio::spawn(async move { task.status().await })
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(status.task_status, "STARTED");
+    mock.assert_async().await;
+}
@@ -2,8 +2,38 @@
 
 mod common;
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use docling_rs::{Auth, DoclingError, StaticToken, TokenFuture, TokenProvider};
 use mockito::Matcher;
 
+/// Hands out `token-1`, `token-2`, ... and counts how often it was asked.
+#[derive(Default)]
+struct RotatingToken {
+    calls: AtomicUsize,
+}
+
+impl TokenProvider for RotatingToken {
+    fn token(&self) -> TokenFuture<'_> {
+        Box::pin(async move {
+            let n = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(format!("token-{}", n))
+        })
+    }
+}
+
+struct FailingToken;
+
+impl TokenProvider for FailingToken {
+    fn token(&self) -> TokenFuture<'_> {
+        Box::pin(async {
+            Err(DoclingError::InvalidOptions {
+                reason: "token endpoint unavailable".to_string(),
+            })
+        })
+    }
+}
+
 /// Health/version are NOT secured endpoints per the OpenAPI spec, so even when
 /// an API key is configured the client must NOT send an Authorization header.
 #[tokio::test]
@@ -131,3 +161,339 @@ async fn api_key_sent_on_poll_and_result_endpoints() {
     poll_mock.assert_async().await;
     result_mock.assert_async().await;
 }
+
+#[tokio::test]
+async fn injected_http_client_keeps_its_config_and_api_key() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_header("authorization", "Bearer injected-key")
+        .match_header("x-service", "ingest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-service", "ingest".parse().unwrap());
+    let http = reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .unwrap();
+    let client =
+        docling_rs::DoclingClient::with_http_client_and_key(server.url(), http, "injected-key");
+    client
+        .convert_source("https://example.com/doc.pdf", None)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn builder_user_agent_is_sent() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/health")
+        .match_header("user-agent", "ingest-worker/2.1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::health_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = docling_rs::DoclingClient::builder(format!("{}/", server.url()))
+        .user_agent("ingest-worker/2.1")
+        .build();
+    client.health().await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn default_user_agent_names_sdk_version() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/health")
+        .match_header(
+            "user-agent",
+            concat!("docling-rs/", env!("CARGO_PKG_VERSION")),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::health_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    client.health().await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn cloned_clients_keep_api_key_across_tasks() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/status/poll/task-c")
+        .match_header("authorization", "Bearer clone-key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-c", "STARTED")).unwrap())
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = common::test_client_with_key(&server.url(), "clone-key");
+    let handles: Vec<_> = (0..3)
+        .map(|_| {
+            let client = client.clone();
+            tokio::spawn(async move { client.poll_task_status("task-c", None).await })
+        })
+        .collect();
+    drop(client);
+
+    for handle in handles {
+        assert_eq!(handle.await.unwrap().unwrap().task_id, "task-c");
+    }
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn default_headers_are_sent_alongside_bearer_auth() {
+    use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
+
+    let mut server = mockito::Server::new_async().await;
+
+    let health = server
+        .mock("GET", "/health")
+        .match_header("x-tenant-id", "acme")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::health_response_json()).unwrap())
+        .create_async()
+        .await;
+    let poll = server
+        .mock("GET", "/v1/status/poll/task-1")
+        .match_header("x-tenant-id", "acme")
+        .match_header("authorization", "Bearer real-key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-1", "STARTED")).unwrap())
+        .create_async()
+        .await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-tenant-id", HeaderValue::from_static("acme"));
+    // A default must not replace the client's own bearer token.
+    headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer default"));
+    let client = docling_rs::DoclingClient::builder(server.url())
+        .api_key("real-key")
+        .default_headers(headers)
+        .build();
+
+    client.health().await.unwrap();
+    client.poll_task_status("task-1", None).await.unwrap();
+
+    health.assert_async().await;
+    poll.assert_async().await;
+}
+
+#[tokio::test]
+async fn proxy_receives_every_request() {
+    let mut proxy = mockito::Server::new_async().await;
+
+    // A forward proxy sees the absolute target URL.
+    let mock = proxy
+        .mock("GET", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::health_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = docling_rs::DoclingClient::builder("http://docling.invalid:5001")
+        .proxy(reqwest::Proxy::http(proxy.url()).unwrap())
+        .build();
+    let health = client.health().await.unwrap();
+
+    assert_eq!(health.status, "ok");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn token_provider_is_asked_for_each_secured_request() {
+    let mut server = mockito::Server::new_async().await;
+
+    let first = server
+        .mock("GET", "/v1/status/poll/task-1")
+        .match_query(Matcher::Any)
+        .match_header("authorization", "Bearer token-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-1", "STARTED")).unwrap())
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/v1/status/poll/task-1")
+        .match_query(Matcher::Any)
+        .match_header("authorization", "Bearer token-2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-1", "SUCCESS")).unwrap())
+        .create_async()
+        .await;
+    let health = server
+        .mock("GET", "/health")
+        .match_header("authorization", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::health_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = docling_rs::DoclingClient::builder(server.url())
+        .api_key("static-key")
+        .token_provider(RotatingToken::default())
+        .build();
+    client.poll_task_status("task-1", None).await.unwrap();
+    client.health().await.unwrap();
+    client.poll_task_status("task-1", None).await.unwrap();
+
+    first.assert_async().await;
+    second.assert_async().await;
+    health.assert_async().await;
+}
+
+#[tokio::test]
+async fn token_provider_error_is_returned_without_sending() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = docling_rs::DoclingClient::builder(server.url())
+        .token_provider(FailingToken)
+        .build();
+    let err = client.get_task_result("task-1").await.unwrap_err();
+
+    match err {
+        DoclingError::InvalidOptions { reason } => assert_eq!(reason, "token endpoint unavailable"),
+        other => panic!("Expected InvalidOptions, got: {:?}", other),
+    }
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn static_token_behaves_like_api_key() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("DELETE", "/v1/result/task-1")
+        .match_header("authorization", "Bearer fixed")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = docling_rs::DoclingClient::builder(server.url())
+        .token_provider(StaticToken::new("fixed"))
+        .build();
+    client.cancel_task("task-1").await.unwrap();
+
+    assert_eq!(
+        format!("{:?}", StaticToken::new("fixed")),
+        r#"StaticToken("[REDACTED]")"#
+    );
+    mock.assert_async().await;
+}
+
+async fn poll_with_auth(auth: Auth, header: &str, expected: &str) {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/status/poll/task-1")
+        .match_query(Matcher::Any)
+        .match_header(header, expected)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-1", "STARTED")).unwrap())
+        .create_async()
+        .await;
+
+    let client = docling_rs::DoclingClient::with_auth(server.url(), auth);
+    client.poll_task_status("task-1", None).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn auth_schemes_set_expected_headers() {
+    poll_with_auth(Auth::bearer("tok"), "authorization", "Bearer tok").await;
+    // base64("user:pass")
+    poll_with_auth(
+        Auth::basic("user", "pass"),
+        "authorization",
+        "Basic dXNlcjpwYXNz",
+    )
+    .await;
+    poll_with_auth(Auth::header("X-Api-Key", "k-123"), "x-api-key", "k-123").await;
+}
+
+#[tokio::test]
+async fn custom_auth_header_replaces_authorization() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/result/task-1")
+        .match_header("authorization", Matcher::Missing)
+        .match_header("x-api-key", "k-123")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = docling_rs::DoclingClient::builder(server.url())
+        .api_key("ignored")
+        .auth(Auth::header("X-Api-Key", "k-123"))
+        .build();
+    client.get_task_result("task-1").await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn invalid_auth_header_name_fails_locally() {
+    let client = docling_rs::DoclingClient::with_auth(
+        "http://127.0.0.1:1",
+        Auth::header("bad header", "value"),
+    );
+    let err = client.get_task_result("task-1").await.unwrap_err();
+
+    assert!(
+        matches!(err, DoclingError::InvalidOptions { .. }),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn auth_debug_hides_secrets() {
+    let debug = format!(
+        "{:?} {:?} {:?}",
+        Auth::bearer("secret-1"),
+        Auth::basic("alice", "secret-2"),
+        Auth::header("X-Api-Key", "secret-3")
+    );
+
+    assert!(debug.contains("alice") && debug.contains("X-Api-Key"));
+    assert!(!debug.contains("secret-"), "{}", debug);
+}
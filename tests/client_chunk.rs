@@ -0,0 +1,244 @@
+//! Mock tests for hybrid chunking.
+
+mod common;
+
+use std::time::Duration;
+
+use serde_json::json;
+
+use docling_rs::{ChunkingOptions, DoclingError, Source};
+
+fn http_source(url: &str) -> Source {
+    Source::Http {
+        url: url.to_string(),
+        headers: None,
+    }
+}
+
+fn chunk_response_json() -> serde_json::Value {
+    json!({
+        "chunks": [
+            {
+                "filename": "test.pdf",
+                "chunk_index": 0,
+                "text": "Introduction text.",
+                "num_tokens": 3,
+                "headings": ["Introduction"],
+                "captions": null,
+                "page_numbers": [1]
+            },
+            {
+                "filename": "test.pdf",
+                "chunk_index": 1,
+                "text": "Results text.",
+                "num_tokens": 3,
+                "headings": ["Results"],
+                "captions": null,
+                "page_numbers": [2]
+            }
+        ],
+        "documents": [],
+        "processing_time": 0.8
+    })
+}
+
+#[tokio::test]
+async fn chunk_hybrid_sends_options_and_parses_chunks() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/chunk/hybrid/source")
+        .match_body(mockito::Matcher::Json(json!({
+            "sources": [{"kind": "http", "url": "https://example.com/doc.pdf"}],
+            "chunking_options": {
+                "tokenizer": "sentence-transformers/all-MiniLM-L6-v2",
+                "max_tokens": 256,
+                "merge_peers": false
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(chunk_response_json().to_string())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let options = ChunkingOptions {
+        tokenizer: Some("sentence-transformers/all-MiniLM-L6-v2".to_string()),
+        max_tokens: Some(256),
+        merge_peers: Some(false),
+        ..Default::default()
+    };
+    let response = client
+        .chunk_hybrid(http_source("https://example.com/doc.pdf"), options)
+        .await
+        .unwrap();
+
+    assert_eq!(response.chunks.len(), 2);
+    assert_eq!(response.chunks[1].text, "Results text.");
+    assert_eq!(response.chunks[1].token_count, Some(3));
+    assert_eq!(
+        response.chunks[0].headings,
+        Some(vec!["Introduction".to_string()])
+    );
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn chunk_hybrid_async_returns_chunk_task() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mut task = common::task_status_json("chunk-1", "pending");
+    task["task_type"] = json!("chunk");
+    let mock = server
+        .mock("POST", "/v1/chunk/hybrid/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(task.to_string())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let status = client
+        .chunk_hybrid_async(
+            http_source("https://example.com/doc.pdf"),
+            ChunkingOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(status.task_id, "chunk-1");
+    assert_eq!(status.task_type, docling_rs::models::enums::TaskType::Chunk);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn chunk_rejects_empty_sources_before_sending() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let request = docling_rs::ChunkDocumentsRequest {
+        sources: vec![],
+        convert_options: None,
+        chunking_options: None,
+        include_converted_doc: None,
+    };
+    let err = client.chunk(&request).await.unwrap_err();
+
+    assert!(matches!(err, DoclingError::InvalidOptions { .. }));
+    mock.assert_async().await;
+}
+
+fn chunk_task_json(task_id: &str, status: &str) -> serde_json::Value {
+    let mut task = common::task_status_json(task_id, status);
+    task["task_type"] = json!("chunk");
+    task
+}
+
+#[tokio::test]
+async fn get_chunk_result_parses_chunks() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/result/chunk-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(chunk_response_json().to_string())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let response = client.get_chunk_result("chunk-1").await.unwrap();
+
+    assert_eq!(response.chunks.len(), 2);
+    assert_eq!(response.chunks[0].chunk_index, 0);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn wait_for_chunking_polls_then_fetches_chunks() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _submit_mock = server
+        .mock("POST", "/v1/chunk/hybrid/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(chunk_task_json("chunk-2", "PENDING").to_string())
+        .create_async()
+        .await;
+    let poll_mock = server
+        .mock("GET", "/v1/status/poll/chunk-2?wait=0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(chunk_task_json("chunk-2", "SUCCESS").to_string())
+        .create_async()
+        .await;
+    let result_mock = server
+        .mock("GET", "/v1/result/chunk-2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(chunk_response_json().to_string())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let config = docling_rs::WaitConfig::new(Duration::from_secs(30)).poll(Duration::ZERO);
+    let response = client
+        .wait_for_chunking(
+            http_source("https://example.com/doc.pdf"),
+            ChunkingOptions::default(),
+            &config,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.chunks[1].text, "Results text.");
+    poll_mock.assert_async().await;
+    result_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn waiting_for_a_conversion_rejects_a_chunk_task() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _submit_mock = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::task_status_json("chunk-3", "PENDING").to_string())
+        .create_async()
+        .await;
+    let _poll_mock = server
+        .mock("GET", "/v1/status/poll/chunk-3?wait=0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(chunk_task_json("chunk-3", "SUCCESS").to_string())
+        .create_async()
+        .await;
+    let result_mock = server
+        .mock("GET", "/v1/result/chunk-3")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let config = docling_rs::WaitConfig::new(Duration::from_secs(30)).poll(Duration::ZERO);
+    let err = client
+        .wait_for_conversion_with("https://example.com/doc.pdf", None, &config)
+        .await
+        .unwrap_err();
+
+    match err {
+        DoclingError::InvalidOptions { reason } => {
+            assert_eq!(reason, "task chunk-3 is a chunk task, not a convert task");
+        }
+        other => panic!("Expected InvalidOptions, got: {:?}", other),
+    }
+    result_mock.assert_async().await;
+}
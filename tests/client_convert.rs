@@ -94,7 +94,7 @@ async fn convert_full_request() {
 }
 
 #[tokio::test]
-async fn convert_source_422_returns_api_error() {
+async fn convert_source_422_returns_validation_error() {
     let mut server = mockito::Server::new_async().await;
 
     let error_body = json!({
@@ -119,10 +119,43 @@ async fn convert_source_422_returns_api_error() {
         .await;
 
     assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "validation error (HTTP 422): body.sources: field required"
+    );
+    match err {
+        docling_rs::DoclingError::Validation(validation) => {
+            assert_eq!(validation.detail.len(), 1);
+            assert_eq!(validation.detail[0].msg, "field required");
+            assert_eq!(validation.detail[0].error_type, "missing");
+        }
+        other => panic!("Expected DoclingError::Validation, got: {:?}", other),
+    }
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_source_422_unstructured_body_returns_api_error() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(422)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"detail": "Unsupported source"}"#)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let result = client
+        .convert_source("https://example.com/doc.pdf", None)
+        .await;
+
     match result.unwrap_err() {
         docling_rs::DoclingError::Api { status_code, body } => {
             assert_eq!(status_code, 422);
-            assert!(body.contains("field required"));
+            assert!(body.contains("Unsupported source"));
         }
         other => panic!("Expected DoclingError::Api, got: {:?}", other),
     }
@@ -154,3 +187,1147 @@ async fn convert_source_500_empty_body() {
     }
     mock.assert_async().await;
 }
+
+#[tokio::test]
+async fn convert_source_raw_keeps_unmodeled_fields() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mut body = common::convert_response_json();
+    body["future_field"] = json!({"nested": [1, 2, 3]});
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "sources": [{"kind": "http", "url": "https://example.com/doc.pdf"}]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&body).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let raw = client
+        .convert_source_raw("https://example.com/doc.pdf", None)
+        .await
+        .unwrap();
+
+    assert_eq!(raw["future_field"]["nested"], json!([1, 2, 3]));
+    assert_eq!(raw["document"]["filename"], "test.pdf");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_source_with_headers_returns_response_headers() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-request-id", "req-42")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let (result, headers) = client
+        .convert_source_with_headers("https://example.com/doc.pdf", None)
+        .await
+        .unwrap();
+
+    assert_eq!(result.document.filename, "test.pdf");
+    assert_eq!(headers.get("x-request-id").unwrap(), "req-42");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_source_to_writer_streams_markdown() {
+    let mut server = mockito::Server::new_async().await;
+
+    let markdown = "# Big \"book\"\n\nChapter \u{1F4D6}\n".repeat(500);
+    let mut body = common::convert_response_json();
+    body["document"]["md_content"] = json!(markdown);
+    body["document"]["json_content"] = json!({"md_content": "not the markdown"});
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&body).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let mut out = Vec::new();
+    let written = client
+        .convert_source_to_writer("https://example.com/book.pdf", None, &mut out)
+        .await
+        .unwrap();
+
+    assert_eq!(String::from_utf8(out).unwrap(), markdown);
+    assert_eq!(written, markdown.len() as u64);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_source_to_writer_reports_missing_markdown() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mut body = common::convert_response_json();
+    body["document"]["md_content"] = json!(null);
+    let _mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&body).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let err = client
+        .convert_source_to_writer("https://example.com/doc.pdf", None, Vec::new())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        docling_rs::DoclingError::MissingContent {
+            format: docling_rs::OutputFormat::Md
+        }
+    ));
+}
+
+#[tokio::test]
+async fn convert_sources_sends_one_source_per_url() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "sources": [
+                {"kind": "http", "url": "https://example.com/a.pdf"},
+                {"kind": "http", "url": "https://example.com/b.pdf"}
+            ]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let result = client
+        .convert_sources(
+            &["https://example.com/a.pdf", "https://example.com/b.pdf"],
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.document.filename, "test.pdf");
+    mock.assert_async().await;
+}
+
+fn two_source_request() -> docling_rs::ConvertDocumentsRequest {
+    docling_rs::ConvertDocumentsRequest {
+        sources: vec![
+            docling_rs::Source::Http {
+                url: "https://example.com/a.pdf".to_string(),
+                headers: None,
+            },
+            docling_rs::Source::Http {
+                url: "https://example.com/b.pdf".to_string(),
+                headers: None,
+            },
+        ],
+        options: None,
+        target: None,
+    }
+}
+
+#[tokio::test]
+async fn convert_documents_parses_every_document() {
+    let mut server = mockito::Server::new_async().await;
+
+    let document = common::convert_response_json()["document"].clone();
+    let mut second = document.clone();
+    second["filename"] = json!("b.pdf");
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "documents": [document, second],
+                "status": "partial_success",
+                "errors": [],
+                "processing_time": 2.5
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let result = client
+        .convert_documents(&two_source_request())
+        .await
+        .unwrap();
+
+    let names: Vec<_> = result
+        .documents
+        .iter()
+        .map(|d| d.filename.as_str())
+        .collect();
+    assert_eq!(names, ["test.pdf", "b.pdf"]);
+    assert_eq!(result.status, docling_rs::ConversionStatus::PartialSuccess);
+    assert_eq!(result.processing_time, 2.5);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_documents_adapts_single_document_response() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let result = client
+        .convert_documents(&two_source_request())
+        .await
+        .unwrap();
+
+    assert_eq!(result.documents.len(), 1);
+    assert_eq!(result.documents[0].filename, "test.pdf");
+    assert_eq!(result.processing_time, 1.234);
+}
+
+#[tokio::test]
+async fn convert_documents_reports_zip_response_as_unexpected() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/zip")
+        .with_body("PK")
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    match client
+        .convert_documents(&two_source_request())
+        .await
+        .unwrap_err()
+    {
+        docling_rs::DoclingError::UnexpectedResponse { reason } => {
+            assert!(reason.contains("convert_zip"), "{}", reason);
+        }
+        other => panic!("Expected UnexpectedResponse, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn build_convert_body_matches_the_posted_json() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mut request = two_source_request();
+    request.options = Some(docling_rs::ConvertDocumentsRequestOptions {
+        do_ocr: Some(false),
+        ..Default::default()
+    });
+
+    let client = common::test_client(&server.url());
+    let body = client.build_convert_body(&request).unwrap();
+    assert_eq!(body["sources"][1]["url"], "https://example.com/b.pdf");
+    assert_eq!(body["options"], json!({"do_ocr": false}));
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_body(mockito::Matcher::Json(body))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    client.convert(&request).await.unwrap();
+    mock.assert_async().await;
+}
+
+#[test]
+fn build_convert_body_rejects_empty_sources() {
+    let client = common::test_client("http://127.0.0.1:5001");
+    let mut request = two_source_request();
+    request.sources.clear();
+    assert!(matches!(
+        client.build_convert_body(&request),
+        Err(docling_rs::DoclingError::InvalidOptions { .. })
+    ));
+}
+
+#[tokio::test]
+async fn convert_sources_rejects_multi_document_zip() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/zip")
+        .with_body("PK")
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let err = client
+        .convert_sources(
+            &["https://example.com/a.pdf", "https://example.com/b.pdf"],
+            None,
+        )
+        .await
+        .unwrap_err();
+
+    match err {
+        docling_rs::DoclingError::UnexpectedResponse { reason } => {
+            assert!(reason.contains("convert_zip"), "{}", reason);
+        }
+        other => panic!("Expected UnexpectedResponse, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn convert_sources_rejects_empty_list_locally() {
+    let client = common::test_client("http://127.0.0.1:1");
+    let err = client.convert_sources(&[], None).await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        docling_rs::DoclingError::InvalidOptions { .. }
+    ));
+}
+
+#[tokio::test]
+async fn convert_rejects_empty_sources_locally() {
+    // No server: the request must fail before anything is sent.
+    let client = common::test_client("http://127.0.0.1:1");
+    let request = docling_rs::ConvertDocumentsRequest {
+        sources: vec![],
+        options: None,
+        target: None,
+    };
+
+    for result in [
+        client.convert(&request).await.map(|_| ()),
+        client.convert_async(&request).await.map(|_| ()),
+    ] {
+        match result.unwrap_err() {
+            docling_rs::DoclingError::InvalidOptions { reason } => {
+                assert_eq!(reason, "no sources provided");
+            }
+            other => panic!("Expected InvalidOptions, got: {:?}", other),
+        }
+    }
+}
+
+#[tokio::test]
+async fn convert_source_zip_returns_archive_bytes() {
+    let mut server = mockito::Server::new_async().await;
+
+    let archive = b"PK\x03\x04fake zip".to_vec();
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "sources": [{"kind": "http", "url": "https://example.com/doc.pdf"}],
+            "target": {"kind": "zip"}
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/zip")
+        .with_body(archive.clone())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let bytes = client
+        .convert_source_zip("https://example.com/doc.pdf", None)
+        .await
+        .unwrap();
+
+    assert_eq!(bytes, archive);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_with_zip_target_points_to_convert_zip() {
+    // No server: the request must fail before anything is sent.
+    let client = common::test_client("http://127.0.0.1:1");
+    let request = docling_rs::ConvertDocumentsRequest {
+        sources: vec![docling_rs::Source::Http {
+            url: "https://example.com/doc.pdf".to_string(),
+            headers: None,
+        }],
+        options: None,
+        target: Some(docling_rs::Target::Zip),
+    };
+
+    match client.convert(&request).await.unwrap_err() {
+        docling_rs::DoclingError::InvalidOptions { reason } => {
+            assert!(reason.contains("convert_zip"), "{reason}");
+        }
+        other => panic!("Expected InvalidOptions, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn convert_to_s3_sends_s3_target_and_parses_counts() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "target": {"kind": "s3", "endpoint": "minio:9000", "bucket": "docs"}
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "processing_time": 2.5,
+                "num_converted": 1,
+                "num_succeeded": 1,
+                "num_failed": 0
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let request = docling_rs::ConvertDocumentsRequest {
+        sources: vec![docling_rs::Source::Http {
+            url: "https://example.com/doc.pdf".to_string(),
+            headers: None,
+        }],
+        options: None,
+        target: None,
+    };
+    let target = docling_rs::S3Target::new("minio:9000", "docs", "key", "secret");
+    let result = client.convert_to_s3(&request, target).await.unwrap();
+
+    assert_eq!(result.num_succeeded, 1);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn compress_requests_gzips_json_body() {
+    use std::io::Read;
+
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_header("content-encoding", "gzip")
+        .match_header("content-type", "application/json")
+        .match_request(|request| {
+            let Ok(body) = request.body() else {
+                return false;
+            };
+            let mut decoded = String::new();
+            if flate2::read::GzDecoder::new(body.as_slice())
+                .read_to_string(&mut decoded)
+                .is_err()
+            {
+                return false;
+            }
+            serde_json::from_str::<serde_json::Value>(&decoded).ok()
+                == Some(json!({
+                    "sources": [{"kind": "http", "url": "https://example.com/doc.pdf"}]
+                }))
+        })
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = docling_rs::DoclingClient::builder(server.url())
+        .compress_requests(true)
+        .build();
+    let result = client
+        .convert_source("https://example.com/doc.pdf", None)
+        .await
+        .unwrap();
+
+    assert_eq!(result.document.filename, "test.pdf");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_url_stream_converts_every_url() {
+    use futures::StreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+
+    // Mocks are matched in creation order, so the specific one goes first.
+    let missing_mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_body(mockito::Matcher::Regex("missing".to_string()))
+        .with_status(404)
+        .with_body("not found")
+        .expect(1)
+        .create_async()
+        .await;
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let urls = futures::stream::iter(
+        ["a", "b", "missing", "c"].map(|name| format!("https://example.com/{}.pdf", name)),
+    );
+    let results: Vec<_> = client.convert_url_stream(urls, None, 2).collect().await;
+
+    assert_eq!(results.len(), 4);
+    let mut indices: Vec<_> = results.iter().map(|(index, _)| *index).collect();
+    indices.sort();
+    assert_eq!(indices, [0, 1, 2, 3]);
+    for (index, result) in &results {
+        if *index == 2 {
+            assert!(matches!(
+                result,
+                Err(docling_rs::DoclingError::Api {
+                    status_code: 404,
+                    ..
+                })
+            ));
+        } else {
+            assert!(result.is_ok());
+        }
+    }
+    mock.assert_async().await;
+    missing_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_many_keeps_input_order_and_per_item_errors() {
+    let mut server = mockito::Server::new_async().await;
+
+    let missing_mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_body(mockito::Matcher::Regex("missing".to_string()))
+        .with_status(404)
+        .with_body("not found")
+        .expect(1)
+        .create_async()
+        .await;
+    let mut named_mocks = Vec::new();
+    for name in ["a", "b", "c"] {
+        let mut body = common::convert_response_json();
+        body["document"]["filename"] = json!(format!("{}.pdf", name));
+        named_mocks.push(
+            server
+                .mock("POST", "/v1/convert/source")
+                .match_body(mockito::Matcher::Regex(format!("/{}\\.pdf", name)))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(serde_json::to_string(&body).unwrap())
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+
+    let client = common::test_client(&server.url());
+    let urls: Vec<String> = ["a", "missing", "b", "c"]
+        .map(|name| format!("https://example.com/{}.pdf", name))
+        .to_vec();
+    let results = client.convert_many(&urls, None, 3).await;
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap().document.filename, "a.pdf");
+    assert!(matches!(
+        results[1],
+        Err(docling_rs::DoclingError::Api {
+            status_code: 404,
+            ..
+        })
+    ));
+    assert_eq!(results[2].as_ref().unwrap().document.filename, "b.pdf");
+    assert_eq!(results[3].as_ref().unwrap().document.filename, "c.pdf");
+    missing_mock.assert_async().await;
+    for mock in named_mocks {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn convert_many_stream_yields_indexed_results() {
+    use futures::StreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+
+    let missing_mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_body(mockito::Matcher::Regex("missing".to_string()))
+        .with_status(404)
+        .with_body("not found")
+        .create_async()
+        .await;
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let urls: Vec<String> = ["a", "missing", "b"]
+        .map(|name| format!("https://example.com/{}.pdf", name))
+        .to_vec();
+    let mut results: Vec<_> = client.convert_many_stream(&urls, None, 2).collect().await;
+    results.sort_by_key(|(index, _)| *index);
+
+    let indices: Vec<usize> = results.iter().map(|(index, _)| *index).collect();
+    assert_eq!(indices, vec![0, 1, 2]);
+    assert!(results[0].1.is_ok());
+    assert!(results[1].1.is_err());
+    assert!(results[2].1.is_ok());
+    mock.assert_async().await;
+    missing_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_many_stream_does_not_run_ahead_of_consumer() {
+    use futures::StreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .expect_at_most(2)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let urls: Vec<String> = (0..20)
+        .map(|i| format!("https://example.com/{}.pdf", i))
+        .collect();
+    let first: Vec<_> = client
+        .convert_many_stream(&urls, None, 2)
+        .take(1)
+        .collect()
+        .await;
+
+    assert_eq!(first.len(), 1);
+    mock.assert_async().await;
+}
+
+fn batch_request() -> docling_rs::ConvertDocumentsRequest {
+    docling_rs::ConvertDocumentsRequest {
+        sources: vec![
+            docling_rs::Source::Http {
+                url: "https://example.com/a.pdf".to_string(),
+                headers: None,
+            },
+            docling_rs::Source::Http {
+                url: "https://example.com/b.pdf".to_string(),
+                headers: None,
+            },
+        ],
+        options: None,
+        target: None,
+    }
+}
+
+#[tokio::test]
+async fn convert_batch_stream_parses_ndjson_lines() {
+    use futures::StreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+
+    let line = serde_json::to_string(&common::convert_response_json()).unwrap();
+    // Two good lines, one malformed line, and a final line without a newline
+    let body = format!("{line}\n{{\"not\": \"a response\"}}\n\n{line}\n{line}");
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_header(
+            "accept",
+            mockito::Matcher::Regex("application/x-ndjson".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/x-ndjson")
+        .with_body(body)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let request = batch_request();
+    let results: Vec<_> = client.convert_batch_stream(&request).collect().await;
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap().document.filename, "test.pdf");
+    assert!(matches!(results[1], Err(docling_rs::DoclingError::Json(_))));
+    assert!(results[2].is_ok());
+    assert!(results[3].is_ok());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_batch_stream_falls_back_to_buffered_json() {
+    use futures::StreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let request = batch_request();
+    let results: Vec<_> = client.convert_batch_stream(&request).collect().await;
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].as_ref().unwrap().document.filename, "test.pdf");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_batch_stream_yields_api_error_once() {
+    use futures::StreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(503)
+        .with_body("unavailable")
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let request = batch_request();
+    let results: Vec<_> = client.convert_batch_stream(&request).collect().await;
+
+    assert_eq!(results.len(), 1);
+    assert!(matches!(
+        results[0],
+        Err(docling_rs::DoclingError::Api {
+            status_code: 503,
+            ..
+        })
+    ));
+    mock.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn convert_source_memoized_hits_server_once() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let first = client
+        .convert_source_memoized("https://example.com/doc.pdf", None)
+        .await
+        .unwrap();
+    let second = client
+        .convert_source_memoized("https://example.com/doc.pdf", None)
+        .await
+        .unwrap();
+
+    assert_eq!(first.document.filename, second.document.filename);
+    mock.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn convert_source_memoized_cache_is_shared_by_clones() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let clone = client.clone();
+    client
+        .convert_source_memoized("https://example.com/doc.pdf", None)
+        .await
+        .unwrap();
+    clone
+        .convert_source_memoized("https://example.com/doc.pdf", None)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn convert_source_memoized_keys_on_options() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let options = docling_rs::ConvertDocumentsRequestOptions {
+        do_ocr: Some(false),
+        ..Default::default()
+    };
+    client
+        .convert_source_memoized("https://example.com/doc.pdf", None)
+        .await
+        .unwrap();
+    client
+        .convert_source_memoized("https://example.com/doc.pdf", Some(options))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn convert_source_memoized_does_not_store_failures() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mut failed = common::convert_response_json();
+    failed["status"] = json!("failure");
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&failed).unwrap())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    for _ in 0..2 {
+        let response = client
+            .convert_source_memoized("https://example.com/doc.pdf", None)
+            .await
+            .unwrap();
+        assert_eq!(response.status, docling_rs::ConversionStatus::Failure);
+    }
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn to_markdown_requests_md_and_returns_string() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "options": {"to_formats": ["md"]}
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let md = client
+        .to_markdown("https://example.com/doc.pdf")
+        .await
+        .unwrap();
+
+    assert_eq!(md, "# Hello World\n\nThis is a test document.");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn to_html_without_html_content_returns_missing_content() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let err = client
+        .to_html("https://example.com/doc.pdf")
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.to_string(), "response has no html content");
+    assert!(matches!(
+        err,
+        docling_rs::DoclingError::MissingContent {
+            format: docling_rs::OutputFormat::Html
+        }
+    ));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn to_json_returns_json_content() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mut body = common::convert_response_json();
+    body["document"]["json_content"] = json!({"name": "doc", "texts": []});
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "options": {"to_formats": ["json"]}
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&body).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let json = client.to_json("https://example.com/doc.pdf").await.unwrap();
+
+    assert_eq!(json["name"], "doc");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn get_raw_applies_base_url_and_auth() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/memory/stats")
+        .match_header("authorization", "Bearer raw-key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"rss": 1024}"#)
+        .create_async()
+        .await;
+
+    let client = common::test_client_with_key(&server.url(), "raw-key");
+    let resp = client.get_raw("v1/memory/stats").await.unwrap();
+    let body: serde_json::Value = resp.json().await.unwrap();
+
+    assert_eq!(body["rss"], 1024);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn post_raw_json_sends_body_and_maps_errors() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/clear/results")
+        .match_header("content-type", "application/json")
+        .match_body(mockito::Matcher::Json(json!({"older_than": 3600})))
+        .with_status(503)
+        .with_body("busy")
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let err = client
+        .post_raw_json("/v1/clear/results", &json!({"older_than": 3600}))
+        .await
+        .unwrap_err();
+
+    match err {
+        docling_rs::DoclingError::Api { status_code, body } => {
+            assert_eq!(status_code, 503);
+            assert_eq!(body, "busy");
+        }
+        other => panic!("Expected DoclingError::Api, got: {:?}", other),
+    }
+    mock.assert_async().await;
+}
+
+#[derive(Debug, serde::Deserialize, PartialEq)]
+struct CustomStats {
+    documents: u32,
+    backend: String,
+}
+
+#[tokio::test]
+async fn get_json_deserializes_custom_endpoint() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/custom/stats")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"documents": 12, "backend": "docling", "ignored": true}"#)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let stats: CustomStats = client.get_json("/v1/custom/stats").await.unwrap();
+
+    assert_eq!(
+        stats,
+        CustomStats {
+            documents: 12,
+            backend: "docling".to_string(),
+        }
+    );
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn post_json_sends_body_and_deserializes_response() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/custom/stats")
+        .match_body(mockito::Matcher::Json(json!({"window": "1h"})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"documents": 3, "backend": "docling"}"#)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let stats: CustomStats = client
+        .post_json("v1/custom/stats", &json!({"window": "1h"}))
+        .await
+        .unwrap();
+
+    assert_eq!(stats.documents, 3);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn get_json_maps_non_success_to_api_error() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/custom/stats")
+        .with_status(404)
+        .with_body("not found")
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let err = client
+        .get_json::<CustomStats>("/v1/custom/stats")
+        .await
+        .unwrap_err();
+
+    match err {
+        docling_rs::DoclingError::Api { status_code, .. } => assert_eq!(status_code, 404),
+        other => panic!("Expected DoclingError::Api, got: {:?}", other),
+    }
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn conflicting_options_are_rejected_before_sending() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let options = docling_rs::ConvertDocumentsRequestOptions {
+        picture_description_local: Some(json!({"repo_id": "model"})),
+        picture_description_api: Some(json!({"url": "http://vlm"})),
+        ..Default::default()
+    };
+
+    let client = common::test_client(&server.url());
+    let err = client
+        .convert_source("https://example.com/doc.pdf", Some(options.clone()))
+        .await
+        .unwrap_err();
+    match err {
+        docling_rs::DoclingError::InvalidOptions { reason } => {
+            assert!(reason.contains("picture_description_local"), "{reason}");
+            assert!(reason.contains("picture_description_api"), "{reason}");
+        }
+        other => panic!("Expected DoclingError::InvalidOptions, got: {:?}", other),
+    }
+
+    let err = client
+        .convert_bytes("doc.pdf", b"%PDF".to_vec(), Some(&options), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        docling_rs::DoclingError::InvalidOptions { .. }
+    ));
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_source_rejects_local_paths_and_non_http_urls() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    for source in ["./doc.pdf", "file:///tmp/doc.pdf"] {
+        let err = client.convert_source(source, None).await.unwrap_err();
+        match err {
+            docling_rs::DoclingError::InvalidOptions { reason } => {
+                assert!(reason.contains(source), "{reason}");
+                assert!(reason.contains("convert_file"), "{reason}");
+            }
+            other => panic!("Expected DoclingError::InvalidOptions, got: {:?}", other),
+        }
+    }
+
+    let err = client
+        .convert_source_async("ftp://example.com/doc.pdf", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        docling_rs::DoclingError::InvalidOptions { .. }
+    ));
+
+    mock.assert_async().await;
+}
@@ -1,4 +1,4 @@
-//! Mock tests for file upload endpoints (convert_file, convert_file_async).
+//! Mock tests for file upload endpoints (convert_file, convert_file_async, convert_bytes, convert_file_zip).
 
 mod common;
 
@@ -175,3 +175,593 @@ async fn wait_for_file_conversion_happy_path() {
     poll_mock.assert_async().await;
     result_mock.assert_async().await;
 }
+
+#[tokio::test]
+async fn convert_file_sends_unicode_filename_as_utf8() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .match_request(|request| {
+            let Ok(body) = request.body() else {
+                return false;
+            };
+            let expected =
+                "Content-Disposition: form-data; name=\"files\"; filename=\"résumé.pdf\"";
+            body.windows(expected.len())
+                .any(|window| window == expected.as_bytes())
+        })
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("résumé.pdf");
+    std::fs::write(&path, b"fake pdf content").unwrap();
+
+    let client = common::test_client(&server.url());
+    client.convert_file(&[&path], None, None).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn recommended_upload_mode_uses_encoded_size_threshold() {
+    let mut tmpfile = tempfile::NamedTempFile::new().unwrap();
+    tmpfile.write_all(&[0u8; 300]).unwrap();
+
+    // 300 bytes encode to 400 base64 bytes.
+    let client = docling_rs::DoclingClient::builder("http://127.0.0.1:1")
+        .inline_upload_threshold(400)
+        .build();
+    assert_eq!(
+        client
+            .recommended_upload_mode(tmpfile.path())
+            .await
+            .unwrap(),
+        docling_rs::UploadMode::Inline
+    );
+
+    let client = docling_rs::DoclingClient::builder("http://127.0.0.1:1")
+        .inline_upload_threshold(399)
+        .build();
+    assert_eq!(
+        client
+            .recommended_upload_mode(tmpfile.path())
+            .await
+            .unwrap(),
+        docling_rs::UploadMode::Multipart
+    );
+}
+
+#[tokio::test]
+async fn recommended_upload_mode_missing_file_is_io_error() {
+    let client = common::test_client("http://127.0.0.1:1");
+    let err = client
+        .recommended_upload_mode("/nonexistent/file.pdf")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, docling_rs::DoclingError::Io(_)));
+}
+
+#[tokio::test]
+async fn convert_file_with_progress_reports_bytes_sent() {
+    use std::sync::{Arc, Mutex};
+
+    let mut server = mockito::Server::new_async().await;
+
+    let content = vec![b'x'; 150 * 1024];
+    let expected_body = content.clone();
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .match_request(move |request| {
+            request.body().is_ok_and(|body| {
+                body.windows(expected_body.len())
+                    .any(|window| window == expected_body.as_slice())
+            })
+        })
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let mut tmpfile = tempfile::NamedTempFile::new().unwrap();
+    tmpfile.write_all(&content).unwrap();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&calls);
+    let client = common::test_client(&server.url());
+    client
+        .convert_file_with_progress(&[tmpfile.path()], None, None, move |sent, total| {
+            recorded.lock().unwrap().push((sent, total));
+        })
+        .await
+        .unwrap();
+
+    let calls = calls.lock().unwrap().clone();
+    let total = content.len() as u64;
+    assert!(calls.len() > 1, "expected several chunks, got {:?}", calls);
+    assert!(calls.windows(2).all(|w| w[0].0 < w[1].0));
+    assert!(calls.iter().all(|(_, t)| *t == Some(total)));
+    assert_eq!(calls.last(), Some(&(total, Some(total))));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_file_with_headers_returns_response_headers() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-ratelimit-remaining", "7")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let mut tmpfile = tempfile::NamedTempFile::new().unwrap();
+    tmpfile.write_all(b"fake pdf content").unwrap();
+
+    let client = common::test_client(&server.url());
+    let (result, headers) = client
+        .convert_file_with_headers(&[tmpfile.path()], None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(result.document.filename, "test.pdf");
+    assert_eq!(headers.get("x-ratelimit-remaining").unwrap(), "7");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_file_streams_large_file_intact() {
+    let mut server = mockito::Server::new_async().await;
+
+    // Several megabytes with a non-repeating byte pattern, so a dropped or
+    // reordered chunk would be caught.
+    let content: Vec<u8> = (0..8 * 1024 * 1024 + 17).map(|i| (i % 251) as u8).collect();
+    let expected = content.clone();
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .match_request(move |request| {
+            let Ok(body) = request.body() else {
+                return false;
+            };
+            let Some(header_end) = body.windows(4).position(|w| w == b"\r\n\r\n") else {
+                return false;
+            };
+            let start = header_end + 4;
+            let end = start + expected.len();
+            body.len() > end
+                && body[start..end] == expected[..]
+                && body[end..].starts_with(b"\r\n--")
+        })
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let mut tmpfile = tempfile::NamedTempFile::new().unwrap();
+    tmpfile.write_all(&content).unwrap();
+
+    let client = common::test_client(&server.url());
+    let result = client
+        .convert_file(&[tmpfile.path()], None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(result.document.filename, "test.pdf");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_file_sends_lowercase_boolean_fields() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .match_request(|request| {
+            let Ok(body) = request.body() else {
+                return false;
+            };
+            let body = String::from_utf8_lossy(body);
+            body.contains("name=\"do_ocr\"\r\n\r\ntrue\r\n")
+                && body.contains("name=\"force_ocr\"\r\n\r\nfalse\r\n")
+        })
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let mut tmpfile = tempfile::NamedTempFile::new().unwrap();
+    tmpfile.write_all(b"fake pdf content").unwrap();
+
+    let opts = docling_rs::ConvertDocumentsRequestOptions {
+        do_ocr: Some(true),
+        force_ocr: Some(false),
+        ..Default::default()
+    };
+    let client = common::test_client(&server.url());
+    client
+        .convert_file(&[tmpfile.path().to_str().unwrap()], Some(&opts), None)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[test]
+fn build_file_form_fields_lists_target_and_options() {
+    let client = common::test_client("http://127.0.0.1:5001");
+    let opts = docling_rs::ConvertDocumentsRequestOptions {
+        to_formats: Some(vec![
+            docling_rs::OutputFormat::Md,
+            docling_rs::OutputFormat::Json,
+        ]),
+        do_ocr: Some(true),
+        ..Default::default()
+    };
+
+    let fields = client
+        .build_file_form_fields(Some(&opts), Some(&docling_rs::TargetName::Zip))
+        .unwrap();
+
+    let field = |name: &str, value: &str| (name.to_string(), value.to_string());
+    assert_eq!(
+        fields,
+        vec![
+            field("target_type", "zip"),
+            field("do_ocr", "true"),
+            field("to_formats", "md"),
+            field("to_formats", "json"),
+        ]
+    );
+    assert!(
+        client
+            .build_file_form_fields(None, None)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn convert_bytes_uploads_in_memory_part_with_options() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .match_request(|request| {
+            let Ok(body) = request.body() else {
+                return false;
+            };
+            let body = String::from_utf8_lossy(body);
+            body.contains("filename=\"generated.pdf\"")
+                && body.contains("Content-Type: application/pdf")
+                && body.contains("%PDF-1.7 in memory")
+                && body.contains("name=\"to_formats\"")
+        })
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let options = docling_rs::ConvertDocumentsRequestOptions {
+        to_formats: Some(vec![docling_rs::OutputFormat::Md]),
+        ..Default::default()
+    };
+    let client = common::test_client(&server.url());
+    let result = client
+        .convert_bytes(
+            "generated.pdf",
+            b"%PDF-1.7 in memory".to_vec(),
+            Some(&options),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.document.filename, "test.pdf");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_file_uppercase_extension_gets_pdf_mime() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .match_request(|request| {
+            let Ok(body) = request.body() else {
+                return false;
+            };
+            let body = String::from_utf8_lossy(body);
+            body.contains("filename=\"something.PDF\"")
+                && body.contains("Content-Type: application/pdf")
+        })
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("something.PDF");
+    std::fs::write(&path, b"%PDF-1.7").unwrap();
+
+    let client = common::test_client(&server.url());
+    client.convert_file(&[&path], None, None).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_file_without_extension_sniffs_mime_from_content() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .match_request(|request| {
+            let Ok(body) = request.body() else {
+                return false;
+            };
+            let body = String::from_utf8_lossy(body);
+            body.contains("filename=\"scan\"")
+                && body.contains("Content-Type: application/pdf")
+                && body.contains("%PDF-1.7 full body")
+        })
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("scan");
+    std::fs::write(&path, b"%PDF-1.7 full body").unwrap();
+
+    let client = common::test_client(&server.url());
+    client.convert_file(&[&path], None, None).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_file_with_mimes_overrides_guess() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .match_request(|request| {
+            let Ok(body) = request.body() else {
+                return false;
+            };
+            let body = String::from_utf8_lossy(body);
+            body.contains("filename=\"upload-3f2a\"")
+                && body.contains("Content-Type: application/pdf")
+        })
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("upload-3f2a");
+    std::fs::write(&path, b"%PDF-1.7").unwrap();
+
+    let client = common::test_client(&server.url());
+    client
+        .convert_file_with_mimes(&[(path, "application/pdf")], None, None)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_file_with_invalid_mime_returns_error() {
+    let server = mockito::Server::new_async().await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("upload.pdf");
+    std::fs::write(&path, b"%PDF-1.7").unwrap();
+
+    let client = common::test_client(&server.url());
+    let err = client
+        .convert_file_with_mimes(&[(path, "not a mime")], None, None)
+        .await
+        .unwrap_err();
+
+    match err {
+        docling_rs::DoclingError::InvalidMime(mime) => assert_eq!(mime, "not a mime"),
+        other => panic!("Expected InvalidMime, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn convert_bytes_async_without_extension_uses_octet_stream() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/file/async")
+        .match_request(|request| {
+            let Ok(body) = request.body() else {
+                return false;
+            };
+            let body = String::from_utf8_lossy(body);
+            body.contains("filename=\"blob\"")
+                && body.contains("Content-Type: application/octet-stream")
+        })
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-1", "pending")).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let task = client
+        .convert_bytes_async("blob", b"data".to_vec(), None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(task.task_id, "task-1");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_file_zip_sends_zip_target_and_returns_bytes() {
+    let mut server = mockito::Server::new_async().await;
+
+    let archive = b"PK\x03\x04fake zip".to_vec();
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .match_request(|request| {
+            let Ok(body) = request.body() else {
+                return false;
+            };
+            let body = String::from_utf8_lossy(body);
+            body.contains("name=\"target_type\"\r\n\r\nzip")
+        })
+        .with_status(200)
+        .with_header("content-type", "application/zip")
+        .with_body(archive.clone())
+        .create_async()
+        .await;
+
+    let mut tmpfile = tempfile::NamedTempFile::new().unwrap();
+    tmpfile.write_all(b"fake pdf content").unwrap();
+
+    let client = common::test_client(&server.url());
+    let bytes = client
+        .convert_file_zip(&[tmpfile.path()], None)
+        .await
+        .unwrap();
+
+    assert_eq!(bytes, archive);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_file_with_zip_target_points_to_zip_method() {
+    let mut tmpfile = tempfile::NamedTempFile::new().unwrap();
+    tmpfile.write_all(b"fake pdf content").unwrap();
+
+    // No server: the request must fail before anything is sent.
+    let client = common::test_client("http://127.0.0.1:1");
+    let result = client
+        .convert_file(&[tmpfile.path()], None, Some(&docling_rs::TargetName::Zip))
+        .await;
+
+    match result.unwrap_err() {
+        docling_rs::DoclingError::InvalidOptions { reason } => {
+            assert!(reason.contains("convert_file_zip"), "{reason}");
+        }
+        other => panic!("Expected InvalidOptions, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn convert_input_uploads_files_and_bytes_as_multipart() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .match_header(
+            "content-type",
+            mockito::Matcher::Regex("multipart/form-data".to_string()),
+        )
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex(r#"filename="notes.md""#.to_string()),
+            mockito::Matcher::Regex(r#"filename="scan.pdf""#.to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("notes.md");
+    std::fs::write(&path, "# Notes").unwrap();
+
+    let client = common::test_client(&server.url());
+    let inputs = vec![
+        docling_rs::Input::path(&path),
+        docling_rs::Input::bytes("scan.pdf", b"%PDF".to_vec()),
+    ];
+    let result = client.convert_input(inputs, None, None).await.unwrap();
+
+    assert_eq!(result.document.filename, "test.pdf");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_input_with_urls_only_uses_source_endpoint() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "sources": [{"kind": "http", "url": "https://example.com/doc.pdf"}]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let inputs = vec![docling_rs::Input::url("https://example.com/doc.pdf")];
+    client.convert_input(inputs, None, None).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_input_mixed_base64_encodes_files_next_to_urls() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "sources": [
+                {"kind": "http", "url": "https://example.com/doc.pdf"},
+                {"kind": "file", "filename": "scan.pdf", "base64_string": "JVBERg=="}
+            ]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::convert_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let inputs = vec![
+        docling_rs::Input::url("https://example.com/doc.pdf"),
+        docling_rs::Input::bytes("scan.pdf", b"%PDF".to_vec()),
+    ];
+    client.convert_input(inputs, None, None).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn convert_input_rejects_empty_inputs() {
+    let client = common::test_client("http://127.0.0.1:1");
+    let err = client
+        .convert_input(Vec::new(), None, None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        docling_rs::DoclingError::InvalidOptions { .. }
+    ));
+}
@@ -2,6 +2,8 @@
 
 mod common;
 
+use std::time::Duration;
+
 use mockito::Matcher;
 
 #[tokio::test]
@@ -23,6 +25,29 @@ async fn health_returns_ok() {
     mock.assert_async().await;
 }
 
+#[tokio::test]
+async fn health_uses_base_url_path_prefix() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/docling/health")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::health_response_json()).unwrap())
+        .expect(2)
+        .create_async()
+        .await;
+
+    for base in [
+        format!("{}/docling", server.url()),
+        format!("{}/docling/", server.url()),
+    ] {
+        let client = common::test_client(&base);
+        assert_eq!(client.health().await.unwrap().status, "ok");
+    }
+    mock.assert_async().await;
+}
+
 #[tokio::test]
 async fn health_server_error_returns_api_error() {
     let mut server = mockito::Server::new_async().await;
@@ -68,6 +93,30 @@ async fn version_returns_hashmap() {
     mock.assert_async().await;
 }
 
+#[tokio::test]
+async fn version_typed_parses_named_fields_and_extra() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mut body = common::version_response_json();
+    body["docling-core"] = serde_json::json!("2.28.0");
+    let mock = server
+        .mock("GET", "/version")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&body).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let version = client.version_typed().await.unwrap();
+
+    assert_eq!(version.version, "1.12.0");
+    assert_eq!(version.docling, "2.31.0");
+    assert_eq!(version.extra["docling-core"], "2.28.0");
+    assert!(!version.extra.contains_key("version"));
+    mock.assert_async().await;
+}
+
 #[tokio::test]
 async fn health_does_not_send_auth_header() {
     let mut server = mockito::Server::new_async().await;
@@ -87,3 +136,133 @@ async fn health_does_not_send_auth_header() {
 
     mock.assert_async().await;
 }
+
+#[tokio::test]
+async fn max_concurrent_requests_limits_in_flight_requests() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    // A minimal HTTP server that answers /health after a delay and records
+    // how many requests it was handling at once. mockito answers instantly,
+    // so it cannot observe overlap.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_seen = Arc::new(AtomicUsize::new(0));
+    {
+        let in_flight = Arc::clone(&in_flight);
+        let max_seen = Arc::clone(&max_seen);
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let in_flight = Arc::clone(&in_flight);
+                let max_seen = Arc::clone(&max_seen);
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buf = [0u8; 1024];
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        let n = socket.read(&mut buf).await.unwrap();
+                        if n == 0 {
+                            return;
+                        }
+                        request.extend_from_slice(&buf[..n]);
+                    }
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_seen.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    let body = r#"{"status":"ok"}"#;
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+                    socket.write_all(response.as_bytes()).await.unwrap();
+                });
+            }
+        });
+    }
+
+    let client = docling_rs::DoclingClient::builder(format!("http://{}", addr))
+        .max_concurrent_requests(2)
+        .build();
+    let results = futures::future::join_all((0..6).map(|_| client.health())).await;
+
+    assert!(results.iter().all(|r| r.is_ok()));
+    assert_eq!(max_seen.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn wait_until_healthy_retries_until_ok() {
+    let mut server = mockito::Server::new_async().await;
+
+    let starting = server
+        .mock("GET", "/health")
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+    let ok = server
+        .mock("GET", "/health")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::health_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    client
+        .wait_until_healthy(Duration::from_secs(5), Duration::from_millis(10))
+        .await
+        .unwrap();
+
+    starting.assert_async().await;
+    ok.assert_async().await;
+}
+
+#[tokio::test]
+async fn wait_until_healthy_times_out_on_connection_refused() {
+    // Grab a free port, then close it so nothing is listening there.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+
+    let client = common::test_client(&format!("http://{}", addr));
+    let err = client
+        .wait_until_healthy(Duration::from_millis(200), Duration::from_millis(20))
+        .await
+        .unwrap_err();
+
+    match err {
+        docling_rs::DoclingError::Timeout { task_id, .. } => assert_eq!(task_id, "health"),
+        other => panic!("Expected Timeout, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn wait_until_healthy_returns_client_errors() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/health")
+        .with_status(404)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let err = client
+        .wait_until_healthy(Duration::from_secs(5), Duration::from_millis(10))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        docling_rs::DoclingError::Api {
+            status_code: 404,
+            ..
+        }
+    ));
+    mock.assert_async().await;
+}
@@ -0,0 +1,355 @@
+//! Mock tests for automatic retries with a RetryPolicy.
+
+mod common;
+
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use docling_rs::{DoclingClient, DoclingError, RetryPolicy};
+
+fn retrying_client(server_url: &str, max_retries: u32) -> DoclingClient {
+    DoclingClient::builder(server_url)
+        .retry_policy(RetryPolicy::new(max_retries).base_delay(Duration::from_millis(1)))
+        .build()
+}
+
+fn api_status(err: DoclingError) -> u16 {
+    match err {
+        DoclingError::Api { status_code, .. } => status_code,
+        other => panic!("Expected Api error, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn retries_transient_status_until_success() {
+    let mut server = mockito::Server::new_async().await;
+
+    // Mockito serves the first matching mock until its expectation is used up.
+    let unavailable = server
+        .mock("GET", "/health")
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+    let ok = server
+        .mock("GET", "/health")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::health_response_json()).unwrap())
+        .create_async()
+        .await;
+
+    let client = retrying_client(&server.url(), 3);
+    let health = client.health().await.unwrap();
+
+    assert_eq!(health.status, "ok");
+    unavailable.assert_async().await;
+    ok.assert_async().await;
+}
+
+#[tokio::test]
+async fn no_retry_without_policy() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/health")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    let err = client.health().await.unwrap_err();
+
+    assert_eq!(api_status(err), 503);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn gives_up_after_max_retries() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/health")
+        .with_status(502)
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = retrying_client(&server.url(), 2);
+    let err = client.health().await.unwrap_err();
+
+    assert_eq!(api_status(err), 502);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn retries_submit_on_429_with_retry_after() {
+    let mut server = mockito::Server::new_async().await;
+
+    let limited = server
+        .mock("POST", "/v1/convert/source/async")
+        .with_status(429)
+        .with_header("retry-after", "0")
+        .expect(1)
+        .create_async()
+        .await;
+    let accepted = server
+        .mock("POST", "/v1/convert/source/async")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "sources": [{"kind": "http", "url": "https://example.com/doc.pdf"}]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&common::task_status_json("task-1", "pending")).unwrap())
+        .create_async()
+        .await;
+
+    // A large base delay shows that Retry-After was used instead.
+    let client = DoclingClient::builder(server.url())
+        .retry_policy(RetryPolicy::new(1).base_delay(Duration::from_secs(60)))
+        .build();
+    let task = client
+        .convert_source_async("https://example.com/doc.pdf", None)
+        .await
+        .unwrap();
+
+    assert_eq!(task.task_id, "task-1");
+    limited.assert_async().await;
+    accepted.assert_async().await;
+}
+
+#[tokio::test]
+async fn does_not_retry_client_errors() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/status/poll/missing")
+        .match_query(mockito::Matcher::Any)
+        .with_status(404)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = retrying_client(&server.url(), 3);
+    let err = client.poll_task_status("missing", None).await.unwrap_err();
+
+    assert_eq!(api_status(err), 404);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn multipart_uploads_are_sent_once() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/file")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut tmpfile = tempfile::NamedTempFile::new().unwrap();
+    tmpfile.write_all(b"fake pdf content").unwrap();
+
+    let client = retrying_client(&server.url(), 3);
+    let err = client
+        .convert_file(&[tmpfile.path()], None, None)
+        .await
+        .unwrap_err();
+
+    assert_eq!(api_status(err), 503);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn sync_conversions_are_not_retried() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/convert/source")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = retrying_client(&server.url(), 3);
+    let err = client
+        .convert_source("https://example.com/doc.pdf", None)
+        .await
+        .unwrap_err();
+
+    assert_eq!(api_status(err), 503);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn retry_after_beyond_max_delay_returns_rate_limited() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/health")
+        .with_status(429)
+        .with_header("retry-after", "120")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = DoclingClient::builder(server.url())
+        .retry_policy(RetryPolicy::new(3).max_delay(Duration::from_secs(10)))
+        .build();
+    let started = Instant::now();
+    let err = client.health().await.unwrap_err();
+
+    match err {
+        DoclingError::RateLimited { retry_after } => {
+            assert_eq!(retry_after, Some(Duration::from_secs(120)));
+        }
+        other => panic!("Expected RateLimited, got: {:?}", other),
+    }
+    assert!(started.elapsed() < Duration::from_secs(5));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn retry_that_would_overrun_request_timeout_is_skipped() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/health")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = DoclingClient::builder(server.url())
+        .retry_policy(RetryPolicy::new(3).base_delay(Duration::from_secs(10)))
+        .request_timeout(Duration::from_secs(2))
+        .build();
+    let started = Instant::now();
+    let err = client.health().await.unwrap_err();
+
+    assert_eq!(api_status(err), 503);
+    assert!(started.elapsed() < Duration::from_secs(2));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn error_accessors_classify_api_errors() {
+    let mut server = mockito::Server::new_async().await;
+
+    let _busy = server
+        .mock("GET", "/health")
+        .with_status(429)
+        .create_async()
+        .await;
+    let _missing = server
+        .mock("GET", "/v1/status/poll/missing")
+        .match_query(mockito::Matcher::Any)
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+
+    let err = client.health().await.unwrap_err();
+    assert_eq!(err.status_code(), Some(429));
+    assert!(err.is_retryable());
+
+    let err = client.poll_task_status("missing", None).await.unwrap_err();
+    assert_eq!(err.status_code(), Some(404));
+    assert!(!err.is_retryable());
+}
+
+async fn rate_limited_error(retry_after: &str) -> DoclingError {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/health")
+        .with_status(429)
+        .with_header("retry-after", retry_after)
+        .with_body("slow down")
+        .create_async()
+        .await;
+
+    let client = common::test_client(&server.url());
+    client.health().await.unwrap_err()
+}
+
+#[tokio::test]
+async fn rate_limit_parses_retry_after_seconds() {
+    let err = rate_limited_error("5").await;
+
+    match &err {
+        DoclingError::RateLimited { retry_after } => {
+            assert_eq!(*retry_after, Some(Duration::from_secs(5)));
+        }
+        other => panic!("Expected RateLimited, got: {:?}", other),
+    }
+    assert_eq!(err.status_code(), Some(429));
+    assert!(err.is_retryable());
+    assert_eq!(err.to_string(), "rate limited (HTTP 429), retry after 5s");
+}
+
+#[tokio::test]
+async fn rate_limit_parses_retry_after_http_date() {
+    let at = std::time::SystemTime::now() + Duration::from_secs(120);
+    let err = rate_limited_error(&httpdate::fmt_http_date(at)).await;
+
+    match err {
+        DoclingError::RateLimited {
+            retry_after: Some(delay),
+        } => {
+            // The date has one-second resolution.
+            assert!(delay > Duration::from_secs(100), "{:?}", delay);
+            assert!(delay <= Duration::from_secs(120), "{:?}", delay);
+        }
+        other => panic!("Expected RateLimited with a delay, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn rate_limit_with_past_or_invalid_retry_after() {
+    let err = rate_limited_error("Wed, 21 Oct 2015 07:28:00 GMT").await;
+    assert!(matches!(
+        err,
+        DoclingError::RateLimited {
+            retry_after: Some(Duration::ZERO)
+        }
+    ));
+
+    let err = rate_limited_error("soon").await;
+    assert!(matches!(
+        err,
+        DoclingError::RateLimited { retry_after: None }
+    ));
+    assert_eq!(err.to_string(), "rate limited (HTTP 429)");
+}
+
+#[tokio::test]
+async fn connection_errors_are_retryable_without_status() {
+    // Bind and drop a listener so the port is very likely closed.
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+
+    let client = common::test_client(&format!("http://{}", addr));
+    let err = client.health().await.unwrap_err();
+
+    assert!(matches!(err, DoclingError::Http(_)));
+    assert_eq!(err.status_code(), None);
+    assert!(err.is_retryable());
+}
+
+#[test]
+fn wait_timeouts_are_not_retryable() {
+    let err = DoclingError::Timeout {
+        task_id: "task-1".to_string(),
+        elapsed_secs: 1.0,
+        last_status: None,
+        last_position: None,
+    };
+
+    assert_eq!(err.status_code(), None);
+    assert!(!err.is_retryable());
+}
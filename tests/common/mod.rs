@@ -4,7 +4,7 @@
 //! `dead_code` warnings for the entire module.
 #![allow(dead_code)]
 
-use serde_json::{json, Value};
+use serde_json::{Value, json};
 
 use docling_rs::DoclingClient;
 
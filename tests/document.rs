@@ -0,0 +1,183 @@
+//! Tests for the typed `DoclingDocument` model and its markdown renderer.
+
+use serde_json::json;
+
+use docling_rs::models::document::*;
+use docling_rs::models::responses::ExportDocumentResponse;
+
+fn prov(page_no: i64) -> serde_json::Value {
+    json!([{
+        "page_no": page_no,
+        "bbox": {"l": 0.0, "t": 10.0, "r": 100.0, "b": 0.0, "coord_origin": "BOTTOMLEFT"},
+        "charspan": [0, 10]
+    }])
+}
+
+/// A two-page document: title, heading, paragraph, list, table, picture.
+fn document_json() -> serde_json::Value {
+    json!({
+        "schema_name": "DoclingDocument",
+        "version": "1.5.0",
+        "name": "report",
+        "furniture": {"self_ref": "#/furniture", "children": [], "name": "_root_", "label": "unspecified"},
+        "body": {
+            "self_ref": "#/body",
+            "children": [
+                {"$ref": "#/texts/0"},
+                {"$ref": "#/texts/1"},
+                {"$ref": "#/texts/2"},
+                {"$ref": "#/groups/0"},
+                {"$ref": "#/tables/0"},
+                {"$ref": "#/pictures/0"}
+            ],
+            "name": "_root_",
+            "label": "unspecified"
+        },
+        "groups": [{
+            "self_ref": "#/groups/0",
+            "parent": {"$ref": "#/body"},
+            "children": [{"$ref": "#/texts/3"}, {"$ref": "#/texts/4"}],
+            "name": "list",
+            "label": "list"
+        }],
+        "texts": [
+            {"self_ref": "#/texts/0", "label": "title", "prov": prov(1), "orig": "Report", "text": "Report"},
+            {"self_ref": "#/texts/1", "label": "section_header", "level": 1, "prov": prov(1), "orig": "Intro", "text": "Intro"},
+            {"self_ref": "#/texts/2", "label": "text", "prov": prov(1), "orig": "Hello.", "text": "Hello."},
+            {"self_ref": "#/texts/3", "label": "list_item", "prov": prov(1), "orig": "one", "text": "one"},
+            {"self_ref": "#/texts/4", "label": "list_item", "prov": prov(2), "orig": "two", "text": "two"},
+            {"self_ref": "#/texts/5", "label": "caption", "prov": prov(2), "orig": "Figure 1", "text": "Figure 1"}
+        ],
+        "tables": [{
+            "self_ref": "#/tables/0",
+            "label": "table",
+            "prov": prov(2),
+            "captions": [],
+            "data": {
+                "num_rows": 2,
+                "num_cols": 2,
+                "table_cells": [
+                    {"text": "A", "start_row_offset_idx": 0, "end_row_offset_idx": 1, "start_col_offset_idx": 0, "end_col_offset_idx": 1, "column_header": true},
+                    {"text": "B", "start_row_offset_idx": 0, "end_row_offset_idx": 1, "start_col_offset_idx": 1, "end_col_offset_idx": 2, "column_header": true},
+                    {"text": "1", "start_row_offset_idx": 1, "end_row_offset_idx": 2, "start_col_offset_idx": 0, "end_col_offset_idx": 1},
+                    {"text": "2", "start_row_offset_idx": 1, "end_row_offset_idx": 2, "start_col_offset_idx": 1, "end_col_offset_idx": 2}
+                ]
+            }
+        }],
+        "pictures": [{
+            "self_ref": "#/pictures/0",
+            "label": "picture",
+            "prov": prov(2),
+            "captions": [{"$ref": "#/texts/5"}]
+        }],
+        "key_value_items": [],
+        "form_items": [],
+        "pages": {"1": {"size": {"width": 612.0, "height": 792.0}, "page_no": 1}}
+    })
+}
+
+#[test]
+fn docling_document_from_export_response() {
+    let export = ExportDocumentResponse {
+        filename: "report.pdf".to_string(),
+        md_content: None,
+        json_content: Some(document_json()),
+        html_content: None,
+        text_content: None,
+        doctags_content: None,
+    };
+
+    let doc = export.docling_document().unwrap().unwrap();
+    assert_eq!(doc.name, "report");
+    assert_eq!(doc.texts.len(), 6);
+    assert_eq!(doc.texts[1].level, Some(1));
+    assert_eq!(doc.texts[0].prov[0].page_no, 1);
+    assert_eq!(doc.pages["1"].size.width, 612.0);
+}
+
+#[test]
+fn docling_document_absent_without_json_content() {
+    let export = ExportDocumentResponse {
+        filename: "report.pdf".to_string(),
+        md_content: Some("# Report".to_string()),
+        json_content: None,
+        html_content: None,
+        text_content: None,
+        doctags_content: None,
+    };
+    assert!(export.docling_document().unwrap().is_none());
+}
+
+#[test]
+fn parse_json_returns_typed_document() {
+    let export = ExportDocumentResponse {
+        filename: "report.pdf".to_string(),
+        md_content: None,
+        json_content: Some(document_json()),
+        html_content: None,
+        text_content: None,
+        doctags_content: None,
+    };
+
+    let doc = export.parse_json().unwrap();
+    assert_eq!(doc.body.children[0].cref, "#/texts/0");
+    assert_eq!(doc.tables.len(), 1);
+    assert_eq!(doc.pictures.len(), 1);
+    // Unmodeled top-level fields are kept rather than dropped.
+    assert!(doc.extra.contains_key("furniture"));
+}
+
+#[test]
+fn parse_json_errors_without_json_content() {
+    let export = ExportDocumentResponse {
+        filename: "report.pdf".to_string(),
+        md_content: Some("# Report".to_string()),
+        json_content: None,
+        html_content: None,
+        text_content: None,
+        doctags_content: None,
+    };
+
+    let err = export.parse_json().unwrap_err();
+    assert!(matches!(
+        err,
+        docling_rs::DoclingError::MissingContent {
+            format: docling_rs::OutputFormat::Json
+        }
+    ));
+}
+
+#[test]
+fn unknown_item_fields_round_trip_through_extra() {
+    let mut json = document_json();
+    json["pictures"][0]["annotations"] = json!([{"kind": "classification"}]);
+
+    let doc: DoclingDocument = serde_json::from_value(json).unwrap();
+    assert!(doc.pictures[0].extra.contains_key("annotations"));
+
+    let back = serde_json::to_value(&doc).unwrap();
+    assert_eq!(
+        back["pictures"][0]["annotations"][0]["kind"],
+        "classification"
+    );
+    assert_eq!(back["furniture"]["self_ref"], "#/furniture");
+}
+
+#[test]
+fn markdown_with_provenance_marks_pages() {
+    let doc: DoclingDocument = serde_json::from_value(document_json()).unwrap();
+    let md = doc.markdown_with_provenance();
+
+    assert_eq!(
+        md,
+        "<!-- page 1 -->\n\n\
+         # Report\n\n\
+         ## Intro\n\n\
+         Hello.\n\n\
+         - one\n\n\
+         <!-- page 2 -->\n\n\
+         - two\n\n\
+         | A | B |\n|---|---|\n| 1 | 2 |\n\n\
+         <!-- image -->\n\nFigure 1"
+    );
+}
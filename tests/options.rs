@@ -0,0 +1,367 @@
+//! Tests for client-side helpers on request types (`ConvertDocumentsRequestOptions`, `ConvertDocumentsRequest`, `Source`).
+
+use docling_rs::DoclingError;
+use docling_rs::models::enums::*;
+use docling_rs::models::requests::*;
+
+/// Set environment variables for a test. Each test uses its own prefix so
+/// parallel tests never observe each other's variables.
+fn set_env(vars: &[(&str, &str)]) {
+    for (key, value) in vars {
+        // SAFETY: keys are unique per test, so no other thread reads them.
+        unsafe { std::env::set_var(key, value) };
+    }
+}
+
+// ============================================================================
+// from_env
+// ============================================================================
+
+#[test]
+fn from_env_parses_typed_fields() {
+    set_env(&[
+        ("ENVTEST_A_TO_FORMATS", "md, json"),
+        ("ENVTEST_A_OCR_ENGINE", "tesseract"),
+        ("ENVTEST_A_OCR_LANG", "en,fr"),
+        ("ENVTEST_A_DO_OCR", "false"),
+        ("ENVTEST_A_FORCE_OCR", "1"),
+        ("ENVTEST_A_TABLE_MODE", "fast"),
+        ("ENVTEST_A_PDF_BACKEND", "dlparse_v4"),
+        ("ENVTEST_A_PAGE_RANGE", "2-5"),
+        ("ENVTEST_A_IMAGES_SCALE", "1.5"),
+    ]);
+
+    let opts = ConvertDocumentsRequestOptions::from_env("ENVTEST_A").unwrap();
+
+    assert_eq!(
+        opts.to_formats,
+        Some(vec![OutputFormat::Md, OutputFormat::Json])
+    );
+    assert_eq!(opts.ocr_engine, Some(OcrEngine::Tesseract));
+    assert_eq!(
+        opts.ocr_lang,
+        Some(vec!["en".to_string(), "fr".to_string()])
+    );
+    assert_eq!(opts.do_ocr, Some(false));
+    assert_eq!(opts.force_ocr, Some(true));
+    assert_eq!(opts.table_mode, Some(TableFormerMode::Fast));
+    assert_eq!(opts.pdf_backend, Some(PdfBackend::DlparseV4));
+    assert_eq!(opts.page_range, Some((2, 5)));
+    assert_eq!(opts.images_scale, Some(1.5));
+}
+
+#[test]
+fn from_env_ignores_unset_and_empty_vars() {
+    set_env(&[("ENVTEST_B_OCR_ENGINE", ""), ("ENVTEST_B_DO_OCR", "  ")]);
+
+    let opts = ConvertDocumentsRequestOptions::from_env("ENVTEST_B").unwrap();
+
+    assert!(opts.ocr_engine.is_none());
+    assert!(opts.do_ocr.is_none());
+    assert!(opts.to_formats.is_none());
+    // Nothing set means nothing serialized — server defaults apply
+    assert_eq!(serde_json::to_value(&opts).unwrap(), serde_json::json!({}));
+}
+
+#[test]
+fn from_env_accepts_trailing_underscore_in_prefix() {
+    set_env(&[("ENVTEST_C_PIPELINE", "vlm")]);
+
+    let opts = ConvertDocumentsRequestOptions::from_env("ENVTEST_C_").unwrap();
+    assert_eq!(opts.pipeline, Some(ProcessingPipeline::Vlm));
+}
+
+#[test]
+fn from_env_rejects_unknown_enum_value() {
+    set_env(&[("ENVTEST_D_OCR_ENGINE", "not_an_engine")]);
+
+    match ConvertDocumentsRequestOptions::from_env("ENVTEST_D").unwrap_err() {
+        DoclingError::InvalidOptions { reason } => {
+            assert!(reason.contains("ENVTEST_D_OCR_ENGINE"), "{reason}");
+            assert!(reason.contains("not_an_engine"), "{reason}");
+        }
+        other => panic!("Expected InvalidOptions, got: {:?}", other),
+    }
+}
+
+#[test]
+fn from_env_rejects_invalid_boolean() {
+    set_env(&[("ENVTEST_E_DO_OCR", "maybe")]);
+
+    let err = ConvertDocumentsRequestOptions::from_env("ENVTEST_E").unwrap_err();
+    assert!(matches!(err, DoclingError::InvalidOptions { .. }));
+}
+
+// ============================================================================
+// warnings
+// ============================================================================
+
+#[test]
+fn warnings_empty_for_default_options() {
+    assert!(
+        ConvertDocumentsRequestOptions::default()
+            .warnings()
+            .is_empty()
+    );
+}
+
+#[test]
+fn warnings_flag_json_and_markdown_sharing_image_mode() {
+    let opts = ConvertDocumentsRequestOptions {
+        to_formats: Some(vec![OutputFormat::Md, OutputFormat::Json]),
+        image_export_mode: Some(ImageRefMode::Embedded),
+        ..Default::default()
+    };
+    assert_eq!(
+        opts.warnings(),
+        vec![OptionWarning::SharedImageExportMode {
+            mode: Some(ImageRefMode::Embedded)
+        }]
+    );
+    assert!(opts.warnings()[0].to_string().contains("embedded"));
+}
+
+#[test]
+fn warnings_flag_server_default_image_mode() {
+    let opts = ConvertDocumentsRequestOptions {
+        to_formats: Some(vec![OutputFormat::Html, OutputFormat::Json]),
+        ..Default::default()
+    };
+    assert_eq!(
+        opts.warnings(),
+        vec![OptionWarning::SharedImageExportMode { mode: None }]
+    );
+}
+
+#[test]
+fn warnings_allow_placeholder_or_single_format() {
+    let placeholder = ConvertDocumentsRequestOptions {
+        to_formats: Some(vec![OutputFormat::Md, OutputFormat::Json]),
+        image_export_mode: Some(ImageRefMode::Placeholder),
+        ..Default::default()
+    };
+    assert!(placeholder.warnings().is_empty());
+
+    let markdown_only = ConvertDocumentsRequestOptions {
+        to_formats: Some(vec![OutputFormat::Md, OutputFormat::Text]),
+        image_export_mode: Some(ImageRefMode::Referenced),
+        ..Default::default()
+    };
+    assert!(markdown_only.warnings().is_empty());
+}
+
+fn http(url: &str) -> Source {
+    Source::Http {
+        url: url.to_string(),
+        headers: None,
+    }
+}
+
+fn request_with_backend(sources: Vec<Source>) -> ConvertDocumentsRequest {
+    ConvertDocumentsRequest {
+        sources,
+        options: Some(ConvertDocumentsRequestOptions {
+            pdf_backend: Some(PdfBackend::DlparseV4),
+            ..Default::default()
+        }),
+        target: None,
+    }
+}
+
+#[test]
+fn warnings_flag_pdf_backend_excluded_by_from_formats() {
+    let opts = ConvertDocumentsRequestOptions {
+        from_formats: Some(vec![InputFormat::Image]),
+        pdf_backend: Some(PdfBackend::DlparseV4),
+        ..Default::default()
+    };
+    assert_eq!(
+        opts.warnings(),
+        vec![OptionWarning::PdfBackendWithoutPdf {
+            backend: PdfBackend::DlparseV4
+        }]
+    );
+}
+
+#[test]
+fn request_warnings_flag_pdf_backend_without_pdf_sources() {
+    let request = request_with_backend(vec![
+        http("https://example.com/scan.png?raw=1"),
+        Source::File {
+            base64_string: String::new(),
+            filename: "report.DOCX".to_string(),
+        },
+    ]);
+    let warnings = request.warnings();
+    assert_eq!(
+        warnings,
+        vec![OptionWarning::PdfBackendWithoutPdf {
+            backend: PdfBackend::DlparseV4
+        }]
+    );
+    assert!(warnings[0].to_string().contains("dlparse_v4"));
+}
+
+#[test]
+fn request_warnings_allow_pdf_or_unknown_sources() {
+    let with_pdf = request_with_backend(vec![
+        http("https://example.com/scan.png"),
+        http("https://example.com/paper.pdf"),
+    ]);
+    assert!(with_pdf.warnings().is_empty());
+
+    // No recognizable extension: could be a PDF, so no warning.
+    let unknown = request_with_backend(vec![http("https://arxiv.org/pdf/2206.01062")]);
+    assert!(unknown.warnings().is_empty());
+}
+
+// ============================================================================
+// Source::encoded_size
+// ============================================================================
+
+#[test]
+fn encoded_size_matches_base64_length() {
+    assert_eq!(Source::encoded_size(0), 0);
+    assert_eq!(Source::encoded_size(1), 4);
+    assert_eq!(Source::encoded_size(3), 4);
+    assert_eq!(Source::encoded_size(4), 8);
+    assert_eq!(Source::encoded_size(300), 400);
+}
+
+// ============================================================================
+// presets
+// ============================================================================
+
+#[test]
+fn presets_set_only_their_documented_fields() {
+    assert_eq!(
+        serde_json::to_value(ConvertDocumentsRequestOptions::fast()).unwrap(),
+        serde_json::json!({
+            "table_mode": "fast",
+            "do_ocr": false,
+            "pipeline": "standard",
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(ConvertDocumentsRequestOptions::accurate()).unwrap(),
+        serde_json::json!({
+            "table_mode": "accurate",
+            "do_ocr": true,
+            "pdf_backend": "dlparse_v4",
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(ConvertDocumentsRequestOptions::markdown_only()).unwrap(),
+        serde_json::json!({
+            "to_formats": ["md"],
+            "include_images": false,
+        })
+    );
+}
+
+// ============================================================================
+// builder
+// ============================================================================
+
+#[test]
+fn builder_sets_fields_and_skips_unset() {
+    let options = ConvertDocumentsRequestOptions::builder()
+        .to_formats([OutputFormat::Md])
+        .do_ocr(true)
+        .ocr_lang(["en", "fr"])
+        .page_range(1, 10)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&options).unwrap(),
+        serde_json::json!({
+            "to_formats": ["md"],
+            "do_ocr": true,
+            "ocr_lang": ["en", "fr"],
+            "page_range": [1, 10],
+        })
+    );
+}
+
+#[test]
+fn builder_pages_accepts_page_range_variants_and_tuples() {
+    let cases = [
+        (PageRange::From(5), [5, i64::MAX]),
+        (PageRange::Single(3), [3, 3]),
+        (PageRange::Range(1, 10), [1, 10]),
+        ((2, 4).into(), [2, 4]),
+    ];
+    for (range, expected) in cases {
+        let options = ConvertDocumentsRequestOptions::builder()
+            .pages(range)
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&options).unwrap()["page_range"],
+            serde_json::json!(expected),
+            "{range:?}"
+        );
+    }
+
+    let options = ConvertDocumentsRequestOptions::builder()
+        .pages((7, 9))
+        .build()
+        .unwrap();
+    assert_eq!(options.page_range, Some((7, 9)));
+}
+
+#[test]
+fn builder_rejects_both_picture_description_sources() {
+    let result = ConvertDocumentsRequestOptions::builder()
+        .picture_description_local(serde_json::json!({"repo_id": "model"}))
+        .picture_description_api(serde_json::json!({"url": "http://vlm"}))
+        .build();
+
+    match result.unwrap_err() {
+        DoclingError::InvalidOptions { reason } => {
+            assert!(reason.contains("picture_description_local"), "{reason}");
+        }
+        other => panic!("Expected InvalidOptions, got: {:?}", other),
+    }
+}
+
+#[test]
+fn validate_names_only_the_conflicting_fields() {
+    let options = ConvertDocumentsRequestOptions {
+        vlm_pipeline_model_local: Some(serde_json::json!({"repo_id": "model"})),
+        vlm_pipeline_model_api: Some(serde_json::json!({"url": "http://vlm"})),
+        ..Default::default()
+    };
+
+    match options.validate().unwrap_err() {
+        DoclingError::InvalidOptions { reason } => assert_eq!(
+            reason,
+            "vlm_pipeline_model_local and vlm_pipeline_model_api are mutually exclusive"
+        ),
+        other => panic!("Expected InvalidOptions, got: {:?}", other),
+    }
+
+    let options = ConvertDocumentsRequestOptions {
+        vlm_pipeline_model_api: Some(serde_json::json!({"url": "http://vlm"})),
+        picture_description_api: Some(serde_json::json!({"url": "http://vlm"})),
+        ..Default::default()
+    };
+    assert!(options.validate().is_ok());
+}
+
+#[test]
+fn builder_rejects_multiple_vlm_models_and_reversed_page_range() {
+    let err = ConvertDocumentsRequestOptions::builder()
+        .vlm_pipeline_model(VlmModelType::Smoldocling)
+        .vlm_pipeline_model_api(serde_json::json!({"url": "http://vlm"}))
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, DoclingError::InvalidOptions { .. }));
+
+    let err = ConvertDocumentsRequestOptions::builder()
+        .page_range(5, 2)
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, DoclingError::InvalidOptions { .. }));
+}
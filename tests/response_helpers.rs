@@ -0,0 +1,429 @@
+//! Tests for client-side helpers on response types (no server involved).
+
+use docling_rs::models::enums::OutputFormat;
+use docling_rs::models::responses::*;
+
+fn doc_with_markdown(md: &str) -> ExportDocumentResponse {
+    ExportDocumentResponse {
+        filename: "test.pdf".to_string(),
+        md_content: Some(md.to_string()),
+        json_content: None,
+        html_content: None,
+        text_content: None,
+        doctags_content: None,
+    }
+}
+
+// ============================================================================
+// markdown_normalized
+// ============================================================================
+
+#[test]
+fn markdown_normalized_default_is_identity() {
+    let md = "# Title  \n\n\n\nBody\n";
+    let doc = doc_with_markdown(md);
+    assert_eq!(
+        doc.markdown_normalized(MarkdownCleanup::default())
+            .as_deref(),
+        Some(md)
+    );
+}
+
+#[test]
+fn markdown_normalized_collapses_blank_lines() {
+    let doc = doc_with_markdown("# Title\n\n\n\nBody\n\n\nEnd");
+    let cleanup = MarkdownCleanup {
+        collapse_blank_lines: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        doc.markdown_normalized(cleanup).as_deref(),
+        Some("# Title\n\nBody\n\nEnd")
+    );
+}
+
+#[test]
+fn markdown_normalized_strips_page_break_placeholder() {
+    let doc = doc_with_markdown("Page one\n<!-- page-break -->\nPage two\n");
+    let cleanup = MarkdownCleanup {
+        strip_page_break: Some("<!-- page-break -->".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        doc.markdown_normalized(cleanup).as_deref(),
+        Some("Page one\n\nPage two\n")
+    );
+}
+
+#[test]
+fn markdown_normalized_trims_trailing_whitespace() {
+    let doc = doc_with_markdown("Line one   \nLine two\t\n");
+    let cleanup = MarkdownCleanup {
+        trim_trailing_whitespace: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        doc.markdown_normalized(cleanup).as_deref(),
+        Some("Line one\nLine two\n")
+    );
+}
+
+#[test]
+fn markdown_normalized_all_steps_combined() {
+    let doc = doc_with_markdown("Intro  \n\n<!-- page-break -->\n\nNext page \n");
+    let cleanup = MarkdownCleanup {
+        collapse_blank_lines: true,
+        strip_page_break: Some("<!-- page-break -->".to_string()),
+        trim_trailing_whitespace: true,
+    };
+    assert_eq!(
+        doc.markdown_normalized(cleanup).as_deref(),
+        Some("Intro\n\nNext page\n")
+    );
+}
+
+#[test]
+fn markdown_normalized_without_markdown_is_none() {
+    let mut doc = doc_with_markdown("");
+    doc.md_content = None;
+    assert!(
+        doc.markdown_normalized(MarkdownCleanup::default())
+            .is_none()
+    );
+}
+
+// ============================================================================
+// content / available_formats
+// ============================================================================
+
+#[test]
+fn content_selects_field_by_format() {
+    let doc = ExportDocumentResponse {
+        html_content: Some("<p>Hi</p>".to_string()),
+        text_content: Some("Hi".to_string()),
+        ..doc_with_markdown("# Hi")
+    };
+    assert_eq!(doc.content(OutputFormat::Md), Some("# Hi"));
+    assert_eq!(doc.content(OutputFormat::Html), Some("<p>Hi</p>"));
+    assert_eq!(doc.content(OutputFormat::HtmlSplitPage), Some("<p>Hi</p>"));
+    assert_eq!(doc.content(OutputFormat::Text), Some("Hi"));
+    assert_eq!(doc.content(OutputFormat::Doctags), None);
+    assert_eq!(doc.content(OutputFormat::Json), None);
+    assert_eq!(doc.content(OutputFormat::Yaml), None);
+}
+
+#[test]
+fn available_formats_lists_populated_fields() {
+    let doc = ExportDocumentResponse {
+        json_content: Some(serde_json::json!({})),
+        doctags_content: Some("<doc/>".to_string()),
+        ..doc_with_markdown("# Hi")
+    };
+    assert_eq!(
+        doc.available_formats(),
+        vec![OutputFormat::Md, OutputFormat::Json, OutputFormat::Doctags]
+    );
+}
+
+// ============================================================================
+// write_to_dir
+// ============================================================================
+
+#[test]
+fn write_to_dir_writes_only_populated_formats() {
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("nested/out");
+    let doc = ExportDocumentResponse {
+        json_content: Some(serde_json::json!({"name": "report"})),
+        text_content: Some("Hi".to_string()),
+        ..doc_with_markdown("# Hi")
+    };
+
+    let written = doc.write_to_dir(&out, "report").unwrap();
+
+    assert_eq!(
+        written,
+        vec![
+            out.join("report.md"),
+            out.join("report.json"),
+            out.join("report.txt")
+        ]
+    );
+    assert_eq!(
+        std::fs::read_to_string(out.join("report.md")).unwrap(),
+        "# Hi"
+    );
+    assert_eq!(
+        std::fs::read_to_string(out.join("report.json")).unwrap(),
+        "{\n  \"name\": \"report\"\n}"
+    );
+    assert!(!out.join("report.html").exists());
+    assert!(!out.join("report.doctags").exists());
+}
+
+// ============================================================================
+// markdown_pages
+// ============================================================================
+
+#[test]
+fn markdown_pages_splits_on_placeholder() {
+    let doc = doc_with_markdown(
+        "# Page 1\n\n<!-- page-break -->\n\n# Page 2\n<!-- page-break -->\n# Page 3\n",
+    );
+    let pages = doc.markdown_pages("<!-- page-break -->");
+    assert_eq!(pages, vec!["# Page 1", "# Page 2", "# Page 3"]);
+}
+
+#[test]
+fn markdown_pages_without_placeholder_returns_single_page() {
+    let doc = doc_with_markdown("# Only page\n");
+    let pages = doc.markdown_pages("<!-- page-break -->");
+    assert_eq!(pages, vec!["# Only page"]);
+}
+
+#[test]
+fn markdown_pages_without_markdown_is_empty() {
+    let mut doc = doc_with_markdown("");
+    doc.md_content = None;
+    assert!(doc.markdown_pages("<!-- page-break -->").is_empty());
+}
+
+// ============================================================================
+// timing_spans
+// ============================================================================
+
+#[cfg(feature = "datetime")]
+fn response_with_timings(timings: serde_json::Value) -> ConvertDocumentResponse {
+    serde_json::from_value(serde_json::json!({
+        "document": {"filename": "test.pdf"},
+        "status": "success",
+        "processing_time": 1.0,
+        "timings": timings,
+    }))
+    .unwrap()
+}
+
+#[cfg(feature = "datetime")]
+#[test]
+fn timing_spans_sorted_by_start() {
+    use std::time::Duration;
+
+    let response = response_with_timings(serde_json::json!({
+        "layout": {
+            "scope": "page",
+            "count": 2,
+            "times": [0.5, 0.25],
+            "start_timestamps": ["2025-01-01T12:00:01+00:00", "2025-01-01T12:00:03+00:00"]
+        },
+        "doc_build": {
+            "scope": "document",
+            "count": 1,
+            "times": [1.0],
+            "start_timestamps": ["2025-01-01T14:00:00+02:00"]
+        }
+    }));
+
+    let spans = response.timing_spans();
+    let names: Vec<&str> = spans.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["doc_build", "layout", "layout"]);
+    assert_eq!(spans[0].offset, Duration::ZERO);
+    assert_eq!(spans[0].duration, Duration::from_secs(1));
+    assert_eq!(spans[1].offset, Duration::from_secs(1));
+    assert_eq!(spans[2].offset, Duration::from_secs(3));
+    assert_eq!(spans[2].duration, Duration::from_millis(250));
+}
+
+#[cfg(feature = "datetime")]
+#[test]
+fn timing_spans_handles_mismatched_and_naive_timestamps() {
+    let response = response_with_timings(serde_json::json!({
+        "ocr": {
+            "scope": "page",
+            "count": 3,
+            "times": [0.1, 0.2, 0.3],
+            "start_timestamps": ["2025-01-01T12:00:00.500000", "not a timestamp"]
+        }
+    }));
+
+    let spans = response.timing_spans();
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].start.to_rfc3339(), "2025-01-01T12:00:00.500+00:00");
+}
+
+#[cfg(feature = "datetime")]
+#[test]
+fn timing_spans_empty_without_timings() {
+    let response = response_with_timings(serde_json::json!({}));
+    assert!(response.timing_spans().is_empty());
+}
+
+// ============================================================================
+// extract_embedded_images
+// ============================================================================
+
+#[test]
+fn extract_embedded_images_from_markdown_and_html() {
+    // "hi" and "png!" in base64
+    let mut doc = doc_with_markdown(
+        "Intro\n\n![Figure 1](data:image/png;base64,aGk=)\n\n![link](https://example.com/a.png)\n",
+    );
+    doc.html_content = Some(
+        r#"<p>x</p><img alt='Chart' src="data:image/jpeg;base64,cG5nIQ==" width="10">"#.to_string(),
+    );
+
+    let images = doc.extract_embedded_images();
+    assert_eq!(
+        images,
+        vec![
+            (
+                "Figure 1".to_string(),
+                b"hi".to_vec(),
+                "image/png".to_string()
+            ),
+            (
+                "Chart".to_string(),
+                b"png!".to_vec(),
+                "image/jpeg".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn extract_embedded_images_skips_malformed_uris() {
+    let doc = doc_with_markdown(
+        "![plain](data:text/plain,hello)\n\
+         ![bad](data:image/png;base64,@@@)\n\
+         ![ok](data:image/gif;base64,aGk=)",
+    );
+    let images = doc.extract_embedded_images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].0, "ok");
+    assert_eq!(images[0].2, "image/gif");
+}
+
+#[test]
+fn extract_embedded_images_empty_without_content() {
+    let mut doc = doc_with_markdown("no images");
+    doc.md_content = None;
+    assert!(doc.extract_embedded_images().is_empty());
+}
+
+// ============================================================================
+// status helpers
+// ============================================================================
+
+fn response_with_status(status: &str) -> ConvertDocumentResponse {
+    serde_json::from_value(serde_json::json!({
+        "document": {"filename": "test.pdf", "md_content": "# Partial"},
+        "status": status,
+        "errors": [{
+            "component_type": "model",
+            "module_name": "layout",
+            "error_message": "page 3 could not be parsed"
+        }],
+        "processing_time": 1.0,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn status_helpers_distinguish_partial_success() {
+    let success = response_with_status("success");
+    assert!(success.is_success());
+    assert!(success.is_ok_enough());
+
+    let partial = response_with_status("partial_success");
+    assert!(!partial.is_success());
+    assert!(partial.is_ok_enough());
+    assert_eq!(partial.warnings().len(), 1);
+    assert_eq!(partial.warnings()[0].module_name, "layout");
+
+    let failure = response_with_status("failure");
+    assert!(!failure.is_success());
+    assert!(!failure.is_ok_enough());
+}
+
+#[test]
+fn into_result_yields_document_unless_failed() {
+    let doc = response_with_status("partial_success")
+        .into_result()
+        .unwrap();
+    assert_eq!(doc.md_content.as_deref(), Some("# Partial"));
+
+    match response_with_status("failure").into_result() {
+        Err(docling_rs::DoclingError::ConversionFailed { filename, errors }) => {
+            assert_eq!(filename, "test.pdf");
+            assert_eq!(errors[0].error_message, "page 3 could not be parsed");
+        }
+        other => panic!("Expected ConversionFailed, got: {:?}", other),
+    }
+}
+
+#[test]
+fn processing_duration_matches_processing_time() {
+    let mut resp = response_with_status("success");
+    resp.processing_time = 2.567;
+    assert_eq!(
+        resp.processing_duration(),
+        std::time::Duration::from_secs_f64(2.567)
+    );
+
+    resp.processing_time = -1.0;
+    assert_eq!(resp.processing_duration(), std::time::Duration::ZERO);
+}
+
+#[test]
+fn error_item_displays_component_module_and_message() {
+    let resp = response_with_status("partial_success");
+    assert_eq!(
+        resp.warnings()[0].to_string(),
+        "model/layout: page 3 could not be parsed"
+    );
+}
+
+// ============================================================================
+// timing aggregates
+// ============================================================================
+
+fn profiling_item(times: Vec<f64>) -> ProfilingItem {
+    ProfilingItem {
+        scope: docling_rs::models::enums::ProfilingScope::Page,
+        count: times.len() as i64,
+        times,
+        start_timestamps: Vec::new(),
+    }
+}
+
+#[test]
+fn profiling_item_aggregates_times() {
+    let item = profiling_item(vec![0.5, 2.0, 1.5]);
+    assert_eq!(item.total(), 4.0);
+    assert_eq!(item.mean(), Some(4.0 / 3.0));
+    assert_eq!(item.max(), Some(2.0));
+
+    let empty = profiling_item(Vec::new());
+    assert_eq!(empty.total(), 0.0);
+    assert_eq!(empty.mean(), None);
+    assert_eq!(empty.max(), None);
+}
+
+#[test]
+fn timings_sorted_by_total_lists_slowest_first() {
+    let resp: ConvertDocumentResponse = serde_json::from_value(serde_json::json!({
+        "document": {"filename": "test.pdf"},
+        "status": "success",
+        "processing_time": 5.0,
+        "timings": {
+            "ocr": {"scope": "page", "times": [1.0, 1.5]},
+            "layout": {"scope": "page", "times": [3.0]},
+            "parse": {"scope": "document", "times": []}
+        },
+    }))
+    .unwrap();
+
+    assert_eq!(
+        resp.timings_sorted_by_total(),
+        vec![("layout", 3.0), ("ocr", 2.5), ("parse", 0.0)]
+    );
+}
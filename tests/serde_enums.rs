@@ -258,4 +258,49 @@ fn display_impls_match_serde() {
         format!("{}", ConversionStatus::PartialSuccess),
         "partial_success"
     );
+
+    // DoclingComponentType
+    assert_eq!(
+        format!("{}", DoclingComponentType::DocumentBackend),
+        "document_backend"
+    );
+}
+
+// ============================================================================
+// FromStr impls — accept the same snake_case strings as serde
+// ============================================================================
+
+fn assert_parses_back<T>(value: T)
+where
+    T: std::str::FromStr<Err = ParseEnumError> + std::fmt::Display + std::fmt::Debug + PartialEq,
+{
+    let text = value.to_string();
+    assert_eq!(text.parse::<T>().unwrap(), value, "parse of {:?}", text);
+}
+
+#[test]
+fn from_str_round_trips_display() {
+    assert_eq!(
+        "html_split_page".parse::<OutputFormat>().unwrap(),
+        OutputFormat::HtmlSplitPage
+    );
+    assert_parses_back(OutputFormat::HtmlSplitPage);
+    assert_parses_back(OutputFormat::Doctags);
+    assert_parses_back(InputFormat::XmlJats);
+    assert_parses_back(InputFormat::Pdf);
+    assert_parses_back(OcrEngine::Tesserocr);
+    assert_parses_back(PdfBackend::DlparseV4);
+    assert_parses_back(ProcessingPipeline::Asr);
+    assert_parses_back(TableFormerMode::Fast);
+}
+
+#[test]
+fn from_str_rejects_unknown_values_with_type_name() {
+    let err = "htmlsplitpage".parse::<OutputFormat>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unknown OutputFormat value: \"htmlsplitpage\""
+    );
+    assert!("PDF".parse::<InputFormat>().is_err());
+    assert!("".parse::<OcrEngine>().is_err());
 }
@@ -61,6 +61,28 @@ fn source_file_serialization() {
     assert_eq!(json["filename"], "test.pdf");
 }
 
+#[tokio::test]
+async fn source_from_path_encodes_file_and_takes_filename() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("hello.pdf");
+    std::fs::write(&path, b"Hello World").unwrap();
+
+    let source = Source::from_path(&path).await.unwrap();
+
+    let json = serde_json::to_value(&source).unwrap();
+    assert_eq!(json["kind"], "file");
+    assert_eq!(json["base64_string"], "SGVsbG8gV29ybGQ=");
+    assert_eq!(json["filename"], "hello.pdf");
+}
+
+#[tokio::test]
+async fn source_from_path_missing_file_is_io_error() {
+    let err = Source::from_path("/nonexistent/file.pdf")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, docling_rs::DoclingError::Io(_)));
+}
+
 #[test]
 fn source_deserialize_from_tagged_json() {
     // Simulates what the server would send or what we'd construct in JSON
@@ -97,6 +119,34 @@ fn target_zip_serialization() {
     assert_eq!(json, json!({"kind": "zip"}));
 }
 
+#[test]
+fn target_s3_serialization() {
+    let target =
+        Target::S3(S3Target::new("minio:9000", "docs", "AKIA", "s3cr3t").key_prefix("out/"));
+    let json = serde_json::to_value(&target).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "kind": "s3",
+            "endpoint": "minio:9000",
+            "verify_ssl": true,
+            "access_key": "AKIA",
+            "secret_key": "s3cr3t",
+            "bucket": "docs",
+            "key_prefix": "out/"
+        })
+    );
+}
+
+#[test]
+fn s3_target_debug_redacts_keys() {
+    let target = S3Target::new("minio:9000", "docs", "AKIA", "s3cr3t");
+    let debug = format!("{:?}", target);
+    assert!(debug.contains("docs"));
+    assert!(!debug.contains("AKIA"));
+    assert!(!debug.contains("s3cr3t"));
+}
+
 #[test]
 fn target_default_is_inbody() {
     let target = Target::default();
@@ -173,6 +223,92 @@ fn options_page_range_round_trip_large_value() {
     assert_eq!(opts.page_range, Some((1, i64::MAX)));
 }
 
+#[test]
+fn options_document_timeout_duration_serializes_as_seconds() {
+    let timeout = std::time::Duration::from_millis(90_500);
+    let opts = ConvertDocumentsRequestOptions::builder()
+        .document_timeout_duration(timeout)
+        .build()
+        .unwrap();
+
+    let json = serde_json::to_value(&opts).unwrap();
+    assert_eq!(json["document_timeout"], json!(90.5));
+
+    let back: ConvertDocumentsRequestOptions = serde_json::from_value(json).unwrap();
+    let secs = back.document_timeout.unwrap();
+    assert_eq!(std::time::Duration::from_secs_f64(secs), timeout);
+}
+
+// ============================================================================
+// Picture description and VLM model options
+// ============================================================================
+
+#[test]
+fn picture_description_api_serializes_set_fields_only() {
+    let api = PictureDescriptionApi::new("http://localhost:8000/v1/chat/completions")
+        .param("model", "granite3.2-vision:2b")
+        .header("Authorization", "Bearer secret")
+        .timeout(30.0);
+
+    assert_eq!(
+        serde_json::Value::from(api),
+        json!({
+            "url": "http://localhost:8000/v1/chat/completions",
+            "headers": {"Authorization": "Bearer secret"},
+            "params": {"model": "granite3.2-vision:2b"},
+            "timeout": 30.0,
+        })
+    );
+}
+
+#[test]
+fn model_options_debug_redacts_header_values() {
+    let api = VlmModelApi::new("http://vlm", "doctags").header("Authorization", "Bearer secret");
+    let debug = format!("{:?}", api);
+
+    assert!(debug.contains("Authorization"), "{debug}");
+    assert!(!debug.contains("secret"), "{debug}");
+}
+
+#[test]
+fn builder_accepts_typed_and_raw_model_options() {
+    let options = ConvertDocumentsRequestOptions::builder()
+        .picture_description_local(PictureDescriptionLocal::new(
+            "HuggingFaceTB/SmolVLM-256M-Instruct",
+        ))
+        .vlm_pipeline_model_local(
+            VlmModelLocal::new(
+                "ibm-granite/granite-docling-258M",
+                "doctags",
+                "transformers",
+            )
+            .prompt("Convert this page to docling."),
+        )
+        .build()
+        .unwrap();
+    let json = serde_json::to_value(&options).unwrap();
+
+    assert_eq!(
+        json["picture_description_local"],
+        json!({"repo_id": "HuggingFaceTB/SmolVLM-256M-Instruct"})
+    );
+    assert_eq!(
+        json["vlm_pipeline_model_local"],
+        json!({
+            "repo_id": "ibm-granite/granite-docling-258M",
+            "response_format": "doctags",
+            "inference_framework": "transformers",
+            "prompt": "Convert this page to docling.",
+        })
+    );
+
+    let raw = ConvertDocumentsRequestOptions::builder()
+        .picture_description_api(json!({"url": "http://vlm", "future_field": 1}))
+        .build()
+        .unwrap();
+    assert_eq!(raw.picture_description_api.unwrap()["future_field"], 1);
+}
+
 // ============================================================================
 // ConvertDocumentsRequest (full request body)
 // ============================================================================
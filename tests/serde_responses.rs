@@ -5,6 +5,7 @@
 
 use serde_json::json;
 
+use docling_rs::models::enums::TaskStatus;
 use docling_rs::models::responses::*;
 
 // ============================================================================
@@ -166,6 +167,64 @@ fn task_status_response_without_optional_fields() {
     assert!(resp.task_meta.is_none());
 }
 
+#[test]
+fn task_status_response_unknown_status_is_kept() {
+    let json = json!({
+        "task_id": "rev-001",
+        "task_type": "convert",
+        "task_status": "REVOKED"
+    });
+
+    let resp: TaskStatusResponse = serde_json::from_value(json).unwrap();
+    assert_eq!(resp.task_status, TaskStatus::Other("REVOKED".to_string()));
+    assert!(!resp.task_status.is_terminal());
+}
+
+#[test]
+fn task_status_response_meta_without_num_docs() {
+    let json = json!({
+        "task_id": "part-001",
+        "task_type": "convert",
+        "task_status": "STARTED",
+        "task_meta": {"num_processed": 1}
+    });
+
+    let resp: TaskStatusResponse = serde_json::from_value(json).unwrap();
+    let meta = resp.task_meta.unwrap();
+    assert_eq!(meta.num_docs, 0);
+    assert_eq!(meta.num_processed, 1);
+    assert!(meta.is_meaningful());
+
+    let empty: TaskProcessingMeta = serde_json::from_value(json!({})).unwrap();
+    assert!(!empty.is_meaningful());
+}
+
+#[test]
+fn task_meta_counts_with_skipped() {
+    let meta: TaskProcessingMeta = serde_json::from_value(json!({
+        "num_docs": 10,
+        "num_processed": 8,
+        "num_succeeded": 5,
+        "num_failed": 1,
+        "num_skipped": 2
+    }))
+    .unwrap();
+    assert_eq!(meta.num_skipped, 2);
+    assert_eq!(meta.num_pending(), 2);
+    assert_eq!(meta.num_unaccounted(), 0);
+
+    // Server without `num_skipped`: skipped documents show up as unaccounted.
+    let meta: TaskProcessingMeta = serde_json::from_value(json!({
+        "num_docs": 10,
+        "num_processed": 8,
+        "num_succeeded": 5,
+        "num_failed": 1
+    }))
+    .unwrap();
+    assert_eq!(meta.num_skipped, 0);
+    assert_eq!(meta.num_unaccounted(), 2);
+}
+
 // ============================================================================
 // HealthCheckResponse
 // ============================================================================
@@ -222,6 +281,18 @@ fn validation_error_empty_detail() {
     assert!(resp.detail.is_empty());
 }
 
+#[test]
+fn validation_error_display_summarizes_first_entries() {
+    let entry = |field: &str| json!({"loc": ["body", field, 0], "msg": "bad", "type": "x"});
+    let json = json!({"detail": [entry("a"), entry("b"), entry("c"), entry("d"), entry("e")]});
+
+    let resp: HttpValidationError = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        resp.to_string(),
+        "body.a.0: bad; body.b.0: bad; body.c.0: bad (and 2 more)"
+    );
+}
+
 // ============================================================================
 // ExportDocumentResponse
 // ============================================================================
@@ -265,3 +336,46 @@ fn presigned_url_response() {
     assert_eq!(resp.num_succeeded, 4);
     assert_eq!(resp.num_failed, 1);
 }
+
+// ============================================================================
+// Chunk
+// ============================================================================
+
+#[test]
+fn chunk_round_trip_with_headings_and_token_count() {
+    let json = json!({
+        "filename": "report.pdf",
+        "chunk_index": 3,
+        "text": "Revenue grew 12% year over year.",
+        "num_tokens": 9,
+        "headings": ["Annual Report", "Financials"],
+        "captions": null,
+        "page_numbers": [4, 5]
+    });
+
+    let chunk: Chunk = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(chunk.chunk_index, 3);
+    assert_eq!(chunk.token_count, Some(9));
+    assert_eq!(chunk.page_numbers, Some(vec![4, 5]));
+    assert_eq!(
+        chunk.serialize_for_embedding(true),
+        "Annual Report\nFinancials\nRevenue grew 12% year over year."
+    );
+    assert_eq!(
+        chunk.serialize_for_embedding(false),
+        "Revenue grew 12% year over year."
+    );
+
+    let back = serde_json::to_value(&chunk).unwrap();
+    assert_eq!(back["num_tokens"], 9);
+    assert_eq!(back["headings"], json["headings"]);
+    assert_eq!(back["text"], json["text"]);
+}
+
+#[test]
+fn chunk_without_optional_fields() {
+    let chunk: Chunk = serde_json::from_value(json!({"text": "Hello"})).unwrap();
+    assert_eq!(chunk.token_count, None);
+    assert!(chunk.headings.is_none());
+    assert_eq!(chunk.serialize_for_embedding(true), "Hello");
+}